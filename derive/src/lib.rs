@@ -0,0 +1,367 @@
+//! `#[derive(Vertex)]`, re-exported as `direct3d11::vertex::Vertex`. See
+//! the `vertex` module of direct3d11 for the attributes it accepts.
+//!
+//! Only structs with named fields are accepted, so the input is parsed
+//! straight from the token stream.
+
+extern crate proc_macro;
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span};
+use proc_macro::{TokenStream, TokenTree};
+
+use std::iter::Peekable;
+
+type Result<T> = std::result::Result<T, (Span, String)>;
+
+#[proc_macro_derive(Vertex, attributes(semantic, format, instance))]
+pub fn derive_vertex(input: TokenStream) -> TokenStream {
+    match VertexStruct::parse(input) {
+        Ok(vertex) => vertex
+            .expand()
+            .parse()
+            .expect("derive(Vertex) generated invalid tokens"),
+        Err((span, message)) => compile_error(span, &message),
+    }
+}
+
+struct VertexStruct {
+    name: String,
+    /// The step rate given by `#[instance(rate)]`.
+    step_rate: Option<u32>,
+    fields: Vec<Field>,
+}
+
+struct Field {
+    name: String,
+    ty: String,
+    semantic_name: String,
+    semantic_index: u32,
+    /// The variant of `VertexFormat` given by `#[format(...)]`.
+    format: Option<String>,
+}
+
+/// An outer attribute: its name and the tokens following the name.
+struct Attribute {
+    name: Ident,
+    args: Vec<TokenTree>,
+}
+
+impl VertexStruct {
+    fn parse(input: TokenStream) -> Result<VertexStruct> {
+        let mut tokens = input.into_iter().peekable();
+        let mut step_rate = None;
+        for attr in parse_attributes(&mut tokens)? {
+            if attr.name.to_string() == "instance" {
+                step_rate = Some(match &attr.args[..] {
+                    [] => 1,
+                    [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+                        match &group.stream().into_iter().collect::<Vec<_>>()[..] {
+                            [TokenTree::Literal(rate)] => parse_u32(rate)?,
+                            _ => return Err(expected(group.span(), "#[instance(step_rate)]")),
+                        }
+                    }
+                    _ => return Err(expected(attr.name.span(), "#[instance(step_rate)]")),
+                });
+            }
+        }
+        skip_visibility(&mut tokens);
+
+        match tokens.next() {
+            Some(TokenTree::Ident(ref ident)) if ident.to_string() == "struct" => (),
+            token => {
+                let span = token.map_or_else(Span::call_site, |t| t.span());
+                return Err((span, "Vertex can only be derived for structs".into()));
+            }
+        }
+        let name = match tokens.next() {
+            Some(TokenTree::Ident(name)) => name.to_string(),
+            _ => return Err((Span::call_site(), "expected the struct name".into())),
+        };
+        let body = match tokens.next() {
+            Some(TokenTree::Group(ref group)) if group.delimiter() == Delimiter::Brace => {
+                group.stream()
+            }
+            Some(TokenTree::Punct(ref punct)) if punct.as_char() == '<' => {
+                let message = "Vertex cannot be derived for generic structs";
+                return Err((punct.span(), message.into()));
+            }
+            token => {
+                let span = token.map_or_else(Span::call_site, |t| t.span());
+                let message = "Vertex can only be derived for structs with named fields";
+                return Err((span, message.into()));
+            }
+        };
+
+        let fields = split_fields(body)
+            .into_iter()
+            .map(Field::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if fields.is_empty() {
+            let message = "a vertex struct needs at least one field";
+            return Err((Span::call_site(), message.into()));
+        }
+        Ok(VertexStruct {
+            name,
+            step_rate,
+            fields,
+        })
+    }
+
+    fn expand(&self) -> String {
+        let (classification, step_rate) = match self.step_rate {
+            Some(rate) => ("PerInstance", rate),
+            None => ("PerVertex", 0),
+        };
+
+        let mut elements = String::new();
+        let mut checks = String::new();
+        for field in &self.fields {
+            let format = match &field.format {
+                Some(format) => {
+                    let format = format!("::direct3d11::vertex::VertexFormat::{}", format);
+                    checks += &format!(
+                        "assert!(::core::mem::size_of::<{ty}>() == {format}.size() as usize, \
+                         \"field `{field}` of `{name}` is not the size of its format\");",
+                        ty = field.ty,
+                        format = format,
+                        field = field.name,
+                        name = self.name,
+                    );
+                    format
+                }
+                None => format!(
+                    "<{} as ::direct3d11::vertex::VertexAttribute>::FORMAT",
+                    field.ty
+                ),
+            };
+            elements += &format!(
+                "::direct3d11::vertex::InputElement {{
+                    semantic_name: {semantic_name:?},
+                    semantic_index: {semantic_index},
+                    format: {format},
+                    input_slot: 0,
+                    offset: ::core::mem::offset_of!({name}, {field}) as u32,
+                    classification: ::direct3d11::vertex::InputClassification::{classification},
+                    instance_step_rate: {step_rate},
+                }},",
+                semantic_name = field.semantic_name,
+                semantic_index = field.semantic_index,
+                format = format,
+                name = self.name,
+                field = field.name,
+                classification = classification,
+                step_rate = step_rate,
+            );
+        }
+
+        format!(
+            "impl ::direct3d11::vertex::Vertex for {name} {{
+                fn elements() -> ::std::vec::Vec<::direct3d11::vertex::InputElement> {{
+                    ::std::vec![{elements}]
+                }}
+            }}
+            const _: () = {{ {checks} }};",
+            name = self.name,
+            elements = elements,
+            checks = checks,
+        )
+    }
+}
+
+impl Field {
+    fn parse(tokens: Vec<TokenTree>) -> Result<Field> {
+        let mut tokens = tokens.into_iter().peekable();
+        let mut semantic = None;
+        let mut format = None;
+        for attr in parse_attributes(&mut tokens)? {
+            match &attr.name.to_string()[..] {
+                "semantic" => semantic = Some(parse_semantic(&attr)?),
+                "format" => format = Some(parse_format(&attr)?),
+                _ => (),
+            }
+        }
+        skip_visibility(&mut tokens);
+
+        let name = match tokens.next() {
+            Some(TokenTree::Ident(name)) => name,
+            token => {
+                let span = token.map_or_else(Span::call_site, |t| t.span());
+                return Err((span, "expected a named field".into()));
+            }
+        };
+        match tokens.next() {
+            Some(TokenTree::Punct(ref colon)) if colon.as_char() == ':' => (),
+            _ => return Err(expected(name.span(), "a named field")),
+        }
+        let ty: TokenStream = tokens.collect();
+
+        let (semantic_name, semantic_index) =
+            semantic.unwrap_or_else(|| default_semantic(&name.to_string()));
+        Ok(Field {
+            name: name.to_string(),
+            ty: ty.to_string(),
+            semantic_name,
+            semantic_index,
+            format,
+        })
+    }
+}
+
+/// Parses `#[semantic("NAME")]` or `#[semantic("NAME", index)]`.
+fn parse_semantic(attr: &Attribute) -> Result<(String, u32)> {
+    const USAGE: &str = "#[semantic(\"NAME\", index)]";
+    let args = match &attr.args[..] {
+        [TokenTree::Group(group)] if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream().into_iter().collect::<Vec<_>>()
+        }
+        _ => return Err(expected(attr.name.span(), USAGE)),
+    };
+    let (name, index) = match &args[..] {
+        [TokenTree::Literal(name)] => (name, 0),
+        [TokenTree::Literal(name), TokenTree::Punct(comma), TokenTree::Literal(index)]
+            if comma.as_char() == ',' =>
+        {
+            (name, parse_u32(index)?)
+        }
+        _ => return Err(expected(attr.name.span(), USAGE)),
+    };
+
+    let literal = name.to_string();
+    let semantic = match literal.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        Some(semantic) => semantic.to_string(),
+        None => return Err(expected(name.span(), "a string")),
+    };
+    let mut chars = semantic.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        let message = format!("`{}` is not a valid semantic name", semantic);
+        return Err((name.span(), message));
+    }
+    if semantic.ends_with(|c: char| c.is_ascii_digit()) {
+        let message = format!(
+            "semantic names cannot end with a digit, give the index separately: \
+             #[semantic(\"{}\", {})]",
+            semantic.trim_end_matches(|c: char| c.is_ascii_digit()),
+            semantic.trim_start_matches(|c: char| !c.is_ascii_digit()),
+        );
+        return Err((name.span(), message));
+    }
+    Ok((semantic, index))
+}
+
+/// Parses `#[format(Variant)]`.
+fn parse_format(attr: &Attribute) -> Result<String> {
+    if let [TokenTree::Group(group)] = &attr.args[..] {
+        if let [TokenTree::Ident(format)] = &group.stream().into_iter().collect::<Vec<_>>()[..] {
+            return Ok(format.to_string());
+        }
+    }
+    Err(expected(attr.name.span(), "#[format(VertexFormatVariant)]"))
+}
+
+/// The semantic of a field without `#[semantic]`: its name in upper case,
+/// with trailing digits as the index, so `uv1` is `UV` index 1.
+fn default_semantic(field: &str) -> (String, u32) {
+    let name = field.trim_start_matches("r#").to_ascii_uppercase();
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0 && digits < name.len() {
+        if let Ok(index) = name[name.len() - digits..].parse() {
+            return (name[..name.len() - digits].to_string(), index);
+        }
+    }
+    (name, 0)
+}
+
+fn parse_attributes(
+    tokens: &mut Peekable<impl Iterator<Item = TokenTree>>,
+) -> Result<Vec<Attribute>> {
+    let mut attrs = Vec::new();
+    while let Some(TokenTree::Punct(punct)) = tokens.peek() {
+        if punct.as_char() != '#' {
+            break;
+        }
+        let span = punct.span();
+        tokens.next();
+        let group = match tokens.next() {
+            Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => group,
+            _ => return Err(expected(span, "an attribute")),
+        };
+        let mut inner = group.stream().into_iter();
+        if let Some(TokenTree::Ident(name)) = inner.next() {
+            attrs.push(Attribute {
+                name,
+                args: inner.collect(),
+            });
+        }
+    }
+    Ok(attrs)
+}
+
+fn skip_visibility(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) {
+    if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+        if ident.to_string() == "pub" {
+            tokens.next();
+            if let Some(TokenTree::Group(group)) = tokens.peek() {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    tokens.next();
+                }
+            }
+        }
+    }
+}
+
+/// Splits the body of a struct at the commas between fields. Commas inside
+/// groups are hidden in the group, but those between generic arguments are
+/// not.
+fn split_fields(body: TokenStream) -> Vec<Vec<TokenTree>> {
+    let mut fields = vec![Vec::new()];
+    let mut depth = 0usize;
+    for token in body {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    fields.push(Vec::new());
+                    continue;
+                }
+                _ => (),
+            }
+        }
+        fields.last_mut().unwrap().push(token);
+    }
+    fields.retain(|field| !field.is_empty());
+    fields
+}
+
+fn parse_u32(literal: &Literal) -> Result<u32> {
+    let text = literal.to_string();
+    text.trim_end_matches("u32")
+        .replace('_', "")
+        .parse()
+        .map_err(|_| expected(literal.span(), "an integer"))
+}
+
+fn expected(span: Span, what: &str) -> (Span, String) {
+    (span, format!("expected {}", what))
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut args = TokenTree::Literal(Literal::string(message));
+    args.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, args.into());
+    group.set_span(span);
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    vec![
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(bang),
+        TokenTree::Group(group),
+        TokenTree::Punct(Punct::new(';', Spacing::Alone)),
+    ]
+    .into_iter()
+    .collect()
+}
@@ -0,0 +1,59 @@
+//! Prints the listing of compiled shaders.
+
+use direct3d11::dxbc::{disassemble, Container, DxbcError, FourCC};
+
+use std::env;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "\
+Usage: dxbc-dis INPUT [OUTPUT]
+
+Disassembles the shader model 4 or 5 code of a compiled shader. The listing
+is written to OUTPUT, or printed if there is none.
+";
+
+fn listing(data: &[u8]) -> Result<String, DxbcError> {
+    let container = Container::parse(data)?;
+    container.verify_checksum()?;
+    match container.program()? {
+        Some(program) => disassemble(&program),
+        None => Err(DxbcError::MissingChunk(FourCC::SHEX)),
+    }
+}
+
+fn run(input: &str, output: Option<&str>) -> Result<(), String> {
+    let data = fs::read(input).map_err(|err| format!("{}: {}", input, err))?;
+    let listing = listing(&data).map_err(|err| format!("{}: {}", input, err))?;
+    match output {
+        Some(output) => fs::write(output, listing).map_err(|err| format!("{}: {}", output, err)),
+        None => {
+            print!("{}", listing);
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "-h" || arg == "--help") {
+        print!("{}", USAGE);
+        return;
+    }
+    let (input, output) = match &args[..] {
+        [input] => (input, None),
+        [input, output] if !output.starts_with('-') => (input, Some(&output[..])),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+    if input.starts_with('-') {
+        eprintln!("dxbc-dis: unknown option `{}`\n\n{}", input, USAGE);
+        process::exit(2);
+    }
+    if let Err(err) = run(input, output) {
+        eprintln!("dxbc-dis: {}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,98 @@
+//! Removes chunks from compiled shaders, for release builds.
+
+use direct3d11::dxbc::{Container, ContainerWriter, FourCC, StripFlags};
+
+use std::env;
+use std::fs;
+use std::process;
+
+const USAGE: &str = "\
+Usage: dxbc-strip [OPTIONS] INPUT [OUTPUT]
+
+Removes chunks from a compiled shader and fixes up its checksum. Without
+options, removes debug info, reflection data and private data. The output
+defaults to overwriting the input.
+
+Options:
+  --debug         remove debug info (SDBG, SPDB, ILDB, ILDN)
+  --reflection    remove reflection data (RDEF, STAT)
+  --private       remove private data (PRIV)
+  --chunk FOURCC  remove the chunks with the given code
+  -h, --help      print this message
+";
+
+struct Options {
+    fourccs: Vec<FourCC>,
+    input: String,
+    output: String,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let mut flags = StripFlags::NONE;
+    let mut fourccs = Vec::new();
+    let mut paths = Vec::new();
+    while let Some(arg) = args.next() {
+        match &arg[..] {
+            "--debug" => flags = flags | StripFlags::DEBUG_INFO,
+            "--reflection" => flags = flags | StripFlags::REFLECTION_DATA,
+            "--private" => flags = flags | StripFlags::PRIVATE_DATA,
+            "--chunk" => {
+                let code = args.next().ok_or("--chunk needs a four character code")?;
+                if code.len() != 4 || !code.is_ascii() {
+                    return Err(format!("`{}` is not a four character code", code));
+                }
+                let mut fourcc = [0; 4];
+                fourcc.copy_from_slice(code.as_bytes());
+                fourccs.push(FourCC(fourcc));
+            }
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => paths.push(arg),
+        }
+    }
+
+    if flags == StripFlags::NONE && fourccs.is_empty() {
+        flags = StripFlags::DEBUG_INFO | StripFlags::REFLECTION_DATA | StripFlags::PRIVATE_DATA;
+    }
+    fourccs.extend(flags.fourccs());
+
+    let mut paths = paths.into_iter();
+    let input = paths.next().ok_or("missing the input file")?;
+    let output = paths.next().unwrap_or_else(|| input.clone());
+    if paths.next().is_some() {
+        return Err("too many arguments".into());
+    }
+    Ok(Options {
+        fourccs,
+        input,
+        output,
+    })
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let data = fs::read(&options.input).map_err(|err| format!("{}: {}", options.input, err))?;
+    let container = Container::parse(&data)
+        .and_then(|container| container.verify_checksum().map(|_| container))
+        .map_err(|err| format!("{}: {}", options.input, err))?;
+    let stripped = ContainerWriter::from_container(&container)
+        .retain(|fourcc| !options.fourccs.contains(&fourcc))
+        .write();
+    fs::write(&options.output, stripped).map_err(|err| format!("{}: {}", options.output, err))
+}
+
+fn main() {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("dxbc-strip: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(err) = run(&options) {
+        eprintln!("dxbc-strip: {}", err);
+        process::exit(1);
+    }
+}
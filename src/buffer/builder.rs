@@ -0,0 +1,106 @@
+use crate::buffer::desc::BufferDesc;
+use crate::buffer::Buffer;
+use crate::device::IDevice;
+use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::BuildError;
+
+use std::mem;
+use std::ptr;
+use std::slice;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use winapi::um::d3d11::{D3D11_BUFFER_DESC, D3D11_SUBRESOURCE_DATA};
+
+pub struct BufferBuilder<'a, 'b> {
+    device: &'a dyn IDevice,
+    desc: BufferDesc,
+    initial_data: Option<&'b [u8]>,
+}
+
+impl<'a, 'b> BufferBuilder<'a, 'b> {
+    pub fn new(device: &'a dyn IDevice) -> Self {
+        BufferBuilder {
+            device,
+            desc: Default::default(),
+            initial_data: None,
+        }
+    }
+
+    /// Sets the size of the buffer in bytes. If initial data is specified,
+    /// the size defaults to the size of that data.
+    pub fn with_size(mut self, byte_width: u32) -> Self {
+        self.desc.byte_width = byte_width;
+        self
+    }
+
+    pub fn with_usage(mut self, usage: Usage) -> Self {
+        self.desc.usage = usage;
+        self
+    }
+
+    pub fn with_bind_flags(mut self, bind_flags: BindFlags) -> Self {
+        self.desc.bind_flags = bind_flags;
+        self
+    }
+
+    pub fn with_cpu_access(mut self, cpu_access_flags: CpuAccessFlags) -> Self {
+        self.desc.cpu_access_flags = cpu_access_flags;
+        self
+    }
+
+    pub fn with_misc_flags(mut self, misc_flags: ResourceMiscFlags) -> Self {
+        self.desc.misc_flags = misc_flags;
+        self
+    }
+
+    /// The size of each element when the buffer is a structured buffer.
+    pub fn with_structure_stride(mut self, stride: u32) -> Self {
+        self.desc.structure_byte_stride = stride;
+        self
+    }
+
+    /// Fills the buffer with the contents of `data`.
+    pub fn with_initial_data<T: Copy>(mut self, data: &'b [T]) -> Self {
+        let bytes =
+            unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, mem::size_of_val(data)) };
+        self.initial_data = Some(bytes);
+        self
+    }
+
+    /// The description the buffer will be created with.
+    pub fn desc(&self) -> BufferDesc {
+        let mut desc = self.desc;
+        if let (0, Some(data)) = (desc.byte_width, self.initial_data) {
+            desc.byte_width = data.len() as u32;
+        }
+        desc
+    }
+
+    pub fn build(self) -> Result<Buffer, BuildError> {
+        let desc = self.desc();
+        desc.validate(self.initial_data.is_some())?;
+        if let Some(bytes) = self.initial_data {
+            desc.validate_initial_data(bytes.len())?;
+        }
+
+        let initial_data = self.initial_data.map(|bytes| D3D11_SUBRESOURCE_DATA {
+            pSysMem: bytes.as_ptr() as *const _,
+            SysMemPitch: 0,
+            SysMemSlicePitch: 0,
+        });
+
+        unsafe {
+            let raw_desc: D3D11_BUFFER_DESC = desc.into();
+            let p_initial_data = initial_data
+                .as_ref()
+                .map(|data| data as *const _)
+                .unwrap_or(ptr::null());
+
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateBuffer(&raw_desc, p_initial_data, &mut ptr);
+
+            Ok(Error::map_if(hr, || Buffer::from_raw(ptr))?)
+        }
+    }
+}
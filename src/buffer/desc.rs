@@ -0,0 +1,111 @@
+use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::DescError;
+use crate::validation;
+
+#[cfg(windows)]
+use winapi::um::d3d11::D3D11_BUFFER_DESC;
+
+/// Describes a buffer resource.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BufferDesc {
+    pub byte_width: u32,
+    pub usage: Usage,
+    pub bind_flags: BindFlags,
+    pub cpu_access_flags: CpuAccessFlags,
+    pub misc_flags: ResourceMiscFlags,
+    pub structure_byte_stride: u32,
+}
+
+impl Default for BufferDesc {
+    fn default() -> Self {
+        BufferDesc {
+            byte_width: 0,
+            usage: Usage::Default,
+            bind_flags: BindFlags::NONE,
+            cpu_access_flags: CpuAccessFlags::NONE,
+            misc_flags: ResourceMiscFlags::NONE,
+            structure_byte_stride: 0,
+        }
+    }
+}
+
+impl BufferDesc {
+    /// Checks the rules for buffer descriptions that can be verified without
+    /// a device.
+    pub fn validate(&self, has_initial_data: bool) -> Result<(), DescError> {
+        if self.byte_width == 0 {
+            return Err(DescError::ZeroSize);
+        }
+
+        validation::validate_usage(
+            self.usage,
+            self.bind_flags,
+            self.cpu_access_flags,
+            has_initial_data,
+        )?;
+
+        if self.bind_flags.is_set(BindFlags::CONSTANT_BUFFER) {
+            if self.bind_flags != BindFlags::CONSTANT_BUFFER {
+                return Err(DescError::ConstantBufferBindFlags {
+                    bind_flags: self.bind_flags,
+                });
+            }
+            if !self.byte_width.is_multiple_of(16) {
+                return Err(DescError::ConstantBufferSize {
+                    byte_width: self.byte_width,
+                });
+            }
+        }
+
+        if self.misc_flags.is_set(ResourceMiscFlags::BUFFER_STRUCTURED) {
+            let stride = self.structure_byte_stride;
+            if self
+                .misc_flags
+                .is_set(ResourceMiscFlags::BUFFER_ALLOW_RAW_VIEWS)
+            {
+                return Err(DescError::StructuredRawViews);
+            }
+            if stride == 0 {
+                return Err(DescError::MissingStructureStride);
+            }
+            if !stride.is_multiple_of(4) || stride > 2048 {
+                return Err(DescError::InvalidStructureStride { stride });
+            }
+            if !self.byte_width.is_multiple_of(stride) {
+                return Err(DescError::StructureStrideMismatch {
+                    byte_width: self.byte_width,
+                    stride,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that `len` bytes of initial data are enough to fill the buffer.
+    pub fn validate_initial_data(&self, len: usize) -> Result<(), DescError> {
+        if len < self.byte_width as usize {
+            return Err(DescError::InitialDataTooSmall {
+                expected: self.byte_width as usize,
+                actual: len,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(windows)]
+impl From<BufferDesc> for D3D11_BUFFER_DESC {
+    fn from(desc: BufferDesc) -> Self {
+        D3D11_BUFFER_DESC {
+            ByteWidth: desc.byte_width,
+            Usage: desc.usage as u32,
+            BindFlags: desc.bind_flags.0,
+            CPUAccessFlags: desc.cpu_access_flags.0,
+            MiscFlags: desc.misc_flags.0,
+            StructureByteStride: desc.structure_byte_stride,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use self::desc::BufferDesc;
+#[cfg(windows)]
+pub use self::wrapper::Buffer;
+
+#[cfg(windows)]
+pub mod builder;
+pub mod desc;
+#[cfg(windows)]
+mod wrapper;
@@ -0,0 +1,55 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::resource::{IResource, Resource};
+use crate::error::ViewError;
+use crate::view::{ShaderResourceView, UnorderedAccessView};
+use crate::buffer::builder;
+
+use com_wrapper::ComWrapper;
+use winapi::um::d3d11::{ID3D11Buffer, ID3D11DeviceChild, ID3D11Resource};
+use wio::com::ComPtr;
+
+#[derive(ComWrapper, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct Buffer {
+    ptr: ComPtr<ID3D11Buffer>,
+}
+
+impl Buffer {
+    #[inline]
+    pub fn create(device: &dyn IDevice) -> builder::BufferBuilder<'_, '_> {
+        builder::BufferBuilder::new(device)
+    }
+
+    #[inline]
+    pub fn as_resource(&self) -> Resource {
+        unsafe { Resource::from_ptr(self.ptr.cast::<ID3D11Resource>().unwrap()) }
+    }
+
+    /// Creates a shader resource view of the whole buffer on
+    /// `device`, which must be the device that created it. The buffer
+    /// must be structured, since no format is given.
+    pub fn create_srv(&self, device: &dyn IDevice) -> Result<ShaderResourceView, ViewError> {
+        ShaderResourceView::create(device, self).build()
+    }
+
+    /// Creates an unordered access view of the whole buffer on
+    /// `device`, which must be the device that created it. The buffer
+    /// must be structured, since no format is given.
+    pub fn create_uav(&self, device: &dyn IDevice) -> Result<UnorderedAccessView, ViewError> {
+        UnorderedAccessView::create(device, self).build()
+    }
+}
+
+unsafe impl IDeviceChild for Buffer {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+unsafe impl IResource for Buffer {
+    unsafe fn raw_res(&self) -> &ID3D11Resource {
+        &self.ptr
+    }
+}
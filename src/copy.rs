@@ -0,0 +1,199 @@
+//! The checks done before the device context updates or copies part of a
+//! resource. Called by `IDeviceContext`.
+
+use crate::enums::{BindFlags, Usage};
+use crate::error::CopyError;
+use crate::image_view::ImageView;
+use crate::mapped_subresource;
+use crate::resource::ResourceInfo;
+use crate::subresource::Box3D;
+use crate::validation;
+
+use std::convert::TryFrom;
+use std::ptr;
+
+use dxgi::enums::Format;
+use winapi::um::d3d11::{ID3D11DeviceContext, ID3D11Resource, D3D11_BOX};
+
+fn check_subresource(info: &ResourceInfo, subresource: u32) -> Result<(), CopyError> {
+    let count = info.subresource_count();
+    if subresource >= count {
+        return Err(CopyError::SubresourceOutOfRange { subresource, count });
+    }
+    Ok(())
+}
+
+/// Multisampled and depth-stencil resources can only be written as a whole.
+fn requires_whole_subresource(info: &ResourceInfo) -> bool {
+    info.sample_count > 1 || info.bind_flags.is_set(BindFlags::DEPTH_STENCIL)
+}
+
+/// The device context cannot write to or read from memory lent out by a
+/// `MappedSubresource`.
+fn check_unmapped(resource: &ID3D11Resource, subresource: Option<u32>) -> Result<(), CopyError> {
+    if mapped_subresource::is_mapped(resource, subresource) {
+        return Err(CopyError::Mapped);
+    }
+    Ok(())
+}
+
+fn box_ptr(region: &Option<D3D11_BOX>) -> *const D3D11_BOX {
+    region.as_ref().map(|b| b as *const _).unwrap_or(ptr::null())
+}
+
+pub(crate) unsafe fn update_subresource(
+    ctx: &ID3D11DeviceContext,
+    resource: &ID3D11Resource,
+    subresource: u32,
+    dst_box: Option<Box3D>,
+    image: &ImageView,
+) -> Result<(), CopyError> {
+    let info = ResourceInfo::of(resource)?;
+    if info.is_buffer() {
+        return Err(CopyError::ExpectedTexture);
+    }
+    if info.usage != Usage::Default {
+        return Err(CopyError::DestinationUsage { usage: info.usage });
+    }
+    if requires_whole_subresource(&info) {
+        return Err(CopyError::WholeSubresourceRequired);
+    }
+    check_subresource(&info, subresource)?;
+    check_unmapped(resource, Some(subresource))?;
+    validation::validate_update(
+        info.format,
+        info.subresource_size(subresource),
+        dst_box,
+        image.format(),
+        (image.width(), image.height(), image.depth()),
+    )?;
+
+    let dst_box = dst_box.map(D3D11_BOX::from);
+    ctx.UpdateSubresource(
+        resource as *const _ as *mut _,
+        subresource,
+        box_ptr(&dst_box),
+        image.data().as_ptr() as *const _,
+        image.row_pitch(),
+        image.slice_pitch(),
+    );
+    Ok(())
+}
+
+pub(crate) unsafe fn update_buffer(
+    ctx: &ID3D11DeviceContext,
+    resource: &ID3D11Resource,
+    offset: u32,
+    data: &[u8],
+) -> Result<(), CopyError> {
+    let info = ResourceInfo::of(resource)?;
+    if info.usage != Usage::Default {
+        return Err(CopyError::DestinationUsage { usage: info.usage });
+    }
+
+    let byte_width = info.extent.width;
+    let len = u32::try_from(data.len()).map_err(|_| CopyError::DataTooLarge {
+        len: data.len(),
+        byte_width,
+    })?;
+    let region = Box3D::new(offset, 0, 0, offset.saturating_add(len), 1, 1);
+    validation::validate_box(&region, Format::Unknown, (byte_width, 1, 1))?;
+
+    let whole = offset == 0 && data.len() == byte_width as usize;
+    if info.bind_flags.is_set(BindFlags::CONSTANT_BUFFER) && !whole {
+        return Err(CopyError::PartialConstantBufferUpdate);
+    }
+    check_unmapped(resource, Some(0))?;
+
+    // Constant buffers must be updated without a box.
+    let dst_box = if whole { None } else { Some(region.into()) };
+    ctx.UpdateSubresource(
+        resource as *const _ as *mut _,
+        0,
+        box_ptr(&dst_box),
+        data.as_ptr() as *const _,
+        0,
+        0,
+    );
+    Ok(())
+}
+
+pub(crate) unsafe fn copy_subresource_region(
+    ctx: &ID3D11DeviceContext,
+    dst: &ID3D11Resource,
+    dst_subresource: u32,
+    dst_offset: (u32, u32, u32),
+    src: &ID3D11Resource,
+    src_subresource: u32,
+    src_box: Option<Box3D>,
+) -> Result<(), CopyError> {
+    if ptr::eq(src, dst) && src_subresource == dst_subresource {
+        return Err(CopyError::SameSubresource {
+            subresource: src_subresource,
+        });
+    }
+    let dst_info = ResourceInfo::of(dst)?;
+    let src_info = ResourceInfo::of(src)?;
+    if dst_info.dimension != src_info.dimension {
+        return Err(CopyError::DimensionMismatch);
+    }
+    if dst_info.usage == Usage::Immutable {
+        return Err(CopyError::DestinationUsage {
+            usage: dst_info.usage,
+        });
+    }
+    check_subresource(&dst_info, dst_subresource)?;
+    check_subresource(&src_info, src_subresource)?;
+    check_unmapped(dst, Some(dst_subresource))?;
+    check_unmapped(src, Some(src_subresource))?;
+
+    if requires_whole_subresource(&dst_info) || requires_whole_subresource(&src_info) {
+        if src_info.sample_count != dst_info.sample_count {
+            return Err(CopyError::SampleCountMismatch {
+                src: src_info.sample_count,
+                dst: dst_info.sample_count,
+            });
+        }
+        if src_box.is_some() || dst_offset != (0, 0, 0) {
+            return Err(CopyError::WholeSubresourceRequired);
+        }
+    }
+
+    validation::validate_copy_region(
+        src_info.format,
+        src_info.subresource_size(src_subresource),
+        src_box,
+        dst_info.format,
+        dst_info.subresource_size(dst_subresource),
+        dst_offset,
+    )?;
+
+    let src_box = src_box.map(D3D11_BOX::from);
+    ctx.CopySubresourceRegion(
+        dst as *const _ as *mut _,
+        dst_subresource,
+        dst_offset.0,
+        dst_offset.1,
+        dst_offset.2,
+        src as *const _ as *mut _,
+        src_subresource,
+        box_ptr(&src_box),
+    );
+    Ok(())
+}
+
+pub(crate) unsafe fn copy_resource(
+    ctx: &ID3D11DeviceContext,
+    src: &ID3D11Resource,
+    dst: &ID3D11Resource,
+) -> Result<(), CopyError> {
+    if ptr::eq(src, dst) {
+        return Err(CopyError::SameResource);
+    }
+    validation::validate_copy_resource(&ResourceInfo::of(src)?, &ResourceInfo::of(dst)?)?;
+    check_unmapped(dst, None)?;
+    check_unmapped(src, None)?;
+
+    ctx.CopyResource(dst as *const _ as *mut _, src as *const _ as *mut _);
+    Ok(())
+}
@@ -1,6 +1,7 @@
-use crate::device::Device;
-use crate::device_context::DeviceContext;
+use crate::device::{Device, SingleThreadedDevice};
+use crate::device_context::{DeviceContext, SingleThreadedDeviceContext};
 use crate::enums::{CreateDeviceFlags, DriverType, FeatureLevel};
+use crate::error::DeviceBuilderError;
 
 use std::ptr;
 
@@ -9,16 +10,40 @@ use dcommon::error::Error;
 use dxgi::adapter::Adapter;
 use winapi::shared::dxgi::IDXGIAdapter;
 use winapi::shared::minwindef::HMODULE;
-use winapi::um::d3d11::{D3D11CreateDevice, D3D11_SDK_VERSION};
+use winapi::um::d3d11::{D3D11CreateDevice, ID3D11Device, ID3D11DeviceContext};
+use winapi::um::d3d11::D3D11_SDK_VERSION;
+
+/// The feature levels Direct3D tries when none are specified.
+pub const DEFAULT_FEATURE_LEVELS: &[FeatureLevel] = &[
+    FeatureLevel::LEVEL_11_0,
+    FeatureLevel::LEVEL_10_1,
+    FeatureLevel::LEVEL_10_0,
+    FeatureLevel::LEVEL_9_3,
+    FeatureLevel::LEVEL_9_2,
+    FeatureLevel::LEVEL_9_1,
+];
 
 pub struct DeviceBuilder<'a> {
     adapter: Option<&'a Adapter>,
     driver_type: Option<DriverType>,
     flags: CreateDeviceFlags,
     feature_levels: &'a [FeatureLevel],
+    min_feature_level: Option<FeatureLevel>,
     software_module: HMODULE,
 }
 
+/// The parameters passed to `D3D11CreateDevice` once the builder options
+/// have been checked against each other.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeviceConfig {
+    pub driver_type: DriverType,
+    pub flags: CreateDeviceFlags,
+    /// The feature levels to request, in order of preference. Empty to use
+    /// the Direct3D defaults.
+    pub feature_levels: Vec<FeatureLevel>,
+}
+
 impl<'a> Default for DeviceBuilder<'a> {
     fn default() -> Self {
         DeviceBuilder {
@@ -26,6 +51,7 @@ impl<'a> Default for DeviceBuilder<'a> {
             driver_type: None,
             flags: CreateDeviceFlags::NONE,
             feature_levels: &[],
+            min_feature_level: None,
             software_module: ptr::null_mut(),
         }
     }
@@ -33,49 +59,29 @@ impl<'a> Default for DeviceBuilder<'a> {
 
 impl<'a> DeviceBuilder<'a> {
     /// Explicitly set the driver_type. This is not necessary except to trigger
-    /// the other sanity checks in the builder methods if you *really* want a
-    /// specific DriverType to be passed.
+    /// the other sanity checks in the builder if you *really* want a specific
+    /// DriverType to be passed.
     ///
-    /// ### panics
-    /// Panics if adapter or software_module has already been specified and the
-    /// passed driver_type is not the required value for those configurations.
+    /// `build()` fails if an adapter or software_module is specified and
+    /// driver_type is not the value required by those configurations.
     pub fn with_driver_type(mut self, driver_type: DriverType) -> Self {
-        assert!(
-            (driver_type == DriverType::Unknown || self.adapter.is_none())
-                && (driver_type == DriverType::Software || self.software_module.is_null()),
-            "If an adapter is specified, driver_type must be `Unknown`"
-        );
         self.driver_type = Some(driver_type);
         self
     }
 
     /// Instructs Direct3D to create the device on the specified adapter. This
-    /// implicitly sets driver_type to Unknown if it hasn't been set yet.
-    ///
-    /// ### panics
-    /// Panics if driver_type has already been set to a value other than Unknown
+    /// implies a driver_type of Unknown.
     pub fn with_adapter(mut self, adapter: &'a Adapter) -> Self {
-        assert!(
-            self.driver_type.is_none() || self.driver_type == Some(DriverType::Unknown),
-            "Cannot specify Adapter if driver_type is not `Unknown`"
-        );
-        self.driver_type = Some(DriverType::Unknown);
         self.adapter = Some(adapter);
         self
     }
 
     /// Pass additional flags to CreateDevice. None are specified by default.
     ///
-    /// ### panics
-    /// Panics if CreateDeviceFlags::SINGLETHREADED is set. This is not supported
-    /// at this time. You can open a pull request if this feature is important to
-    /// you.
+    /// `build()` fails if the flags are invalid, or if
+    /// CreateDeviceFlags::SINGLETHREADED is set; single threaded devices are
+    /// created with `build_single_threaded()`.
     pub fn with_flags(mut self, flags: CreateDeviceFlags) -> Self {
-        assert!(
-            !flags.is_set(CreateDeviceFlags::SINGLETHREADED),
-            "SINGLETHREADED is not supported right now. If this usecase is important, please open an issue."
-        );
-        assert!(flags.validate(), "Invalid flags passed");
         self.flags = flags;
         self
     }
@@ -85,55 +91,174 @@ impl<'a> DeviceBuilder<'a> {
         self
     }
 
+    /// Removes every feature level below `level` from the requested levels,
+    /// or from the default levels if none were specified.
+    pub fn with_min_feature_level(mut self, level: FeatureLevel) -> Self {
+        self.min_feature_level = Some(level);
+        self
+    }
+
+    /// Creates a device using a software rasterizer. This implies a
+    /// driver_type of Software.
     pub unsafe fn with_software_module(mut self, software: HMODULE) -> Self {
-        assert!(
-            self.driver_type.is_none() || self.driver_type == Some(DriverType::Software),
-            "Cannot specify software_module if driver_type is not `Software`"
-        );
-        self.driver_type = Some(DriverType::Software);
         self.software_module = software;
         self
     }
 
-    pub fn build(self) -> Result<(FeatureLevel, Device, DeviceContext), Error> {
+    /// Checks the options against each other and determines what will be
+    /// passed to `D3D11CreateDevice`, without creating a device.
+    pub fn config(&self) -> Result<DeviceConfig, DeviceBuilderError> {
+        let has_adapter = self.adapter.is_some();
+        let has_software = !self.software_module.is_null();
+
+        let driver_type = match (has_adapter, has_software) {
+            (true, true) => return Err(DeviceBuilderError::AdapterAndSoftwareModule),
+            (true, false) => required_driver_type(self.driver_type, DriverType::Unknown)?,
+            (false, true) => required_driver_type(self.driver_type, DriverType::Software)?,
+            (false, false) => match self.driver_type {
+                Some(DriverType::Unknown) => return Err(DeviceBuilderError::MissingAdapter),
+                Some(DriverType::Software) => {
+                    return Err(DeviceBuilderError::MissingSoftwareModule)
+                }
+                Some(driver_type) => driver_type,
+                None => DriverType::Hardware,
+            },
+        };
+
+        let flags = self.flags;
+        if !flags.validate() {
+            return Err(DeviceBuilderError::InvalidFlags(flags));
+        }
+        if flags.is_set(CreateDeviceFlags::SWITCH_TO_REF) {
+            return Err(DeviceBuilderError::UnsupportedFlags(
+                CreateDeviceFlags::SWITCH_TO_REF,
+            ));
+        }
+
+        let feature_levels = match self.min_feature_level {
+            None => self.feature_levels.to_vec(),
+            Some(min) => {
+                let levels = match self.feature_levels {
+                    [] => DEFAULT_FEATURE_LEVELS,
+                    levels => levels,
+                };
+                let levels: Vec<_> = levels.iter().cloned().filter(|&l| l >= min).collect();
+                if levels.is_empty() {
+                    return Err(DeviceBuilderError::NoFeatureLevels);
+                }
+                levels
+            }
+        };
+
+        Ok(DeviceConfig {
+            driver_type,
+            flags,
+            feature_levels,
+        })
+    }
+
+    pub fn build(self) -> Result<(FeatureLevel, Device, DeviceContext), DeviceBuilderError> {
+        let config = self.config()?;
+        if config.flags.is_set(CreateDeviceFlags::SINGLETHREADED) {
+            return Err(DeviceBuilderError::SingleThreaded);
+        }
+
         unsafe {
-            let mut dev_ptr = ptr::null_mut();
-            let mut feature_level = 0;
-            let mut devctx_ptr = ptr::null_mut();
-
-            let hr = D3D11CreateDevice(
-                // pAdapter:
-                self.adapter
-                    .map(|a| a.get_raw() as *mut IDXGIAdapter)
-                    .unwrap_or(ptr::null_mut()),
-                // DriverType:
-                self.driver_type.unwrap_or(DriverType::Hardware) as u32,
-                // Software:
-                self.software_module,
-                // Flags:
-                self.flags.0,
-                // pFeatureLevels:
-                if !self.feature_levels.is_empty() {
-                    &self.feature_levels[0].0
-                } else {
-                    ptr::null()
-                },
-                // FeatureLevels:
-                self.feature_levels.len() as u32,
-                // SDKVersion
-                D3D11_SDK_VERSION,
-                // ppDevice:
-                &mut dev_ptr,
-                // pFeatureLevel:
-                &mut feature_level,
-                // ppImmediateContext:
-                &mut devctx_ptr,
-            );
-
-            let dev = Error::map_if(hr, || Device::from_raw(dev_ptr))?;
-            let ctx = Error::map_if(hr, || DeviceContext::from_raw(devctx_ptr))?;
-            let features = FeatureLevel(feature_level);
-            Ok((features, dev, ctx))
+            let (features, dev_ptr, devctx_ptr) = self.create_raw(&config)?;
+            Ok((
+                features,
+                Device::from_raw(dev_ptr),
+                DeviceContext::from_raw(devctx_ptr),
+            ))
+        }
+    }
+
+    /// Creates a device with `CreateDeviceFlags::SINGLETHREADED` added to the
+    /// flags. Direct3D skips its internal locking for such a device, so the
+    /// returned device and context are neither `Send` nor `Sync`.
+    pub fn build_single_threaded(
+        mut self,
+    ) -> Result<
+        (
+            FeatureLevel,
+            SingleThreadedDevice,
+            SingleThreadedDeviceContext,
+        ),
+        DeviceBuilderError,
+    > {
+        self.flags |= CreateDeviceFlags::SINGLETHREADED;
+        let config = self.config()?;
+
+        unsafe {
+            let (features, dev_ptr, devctx_ptr) = self.create_raw(&config)?;
+            Ok((
+                features,
+                SingleThreadedDevice::from_raw(dev_ptr),
+                SingleThreadedDeviceContext::from_raw(devctx_ptr),
+            ))
+        }
+    }
+
+    unsafe fn create_raw(
+        &self,
+        config: &DeviceConfig,
+    ) -> Result<
+        (
+            FeatureLevel,
+            *mut ID3D11Device,
+            *mut ID3D11DeviceContext,
+        ),
+        DeviceBuilderError,
+    > {
+        let mut dev_ptr = ptr::null_mut();
+        let mut feature_level = 0;
+        let mut devctx_ptr = ptr::null_mut();
+
+        let hr = D3D11CreateDevice(
+            // pAdapter:
+            self.adapter
+                .map(|a| a.get_raw() as *mut IDXGIAdapter)
+                .unwrap_or(ptr::null_mut()),
+            // DriverType:
+            config.driver_type as u32,
+            // Software:
+            self.software_module,
+            // Flags:
+            config.flags.0,
+            // pFeatureLevels:
+            if !config.feature_levels.is_empty() {
+                &config.feature_levels[0].0
+            } else {
+                ptr::null()
+            },
+            // FeatureLevels:
+            config.feature_levels.len() as u32,
+            // SDKVersion
+            D3D11_SDK_VERSION,
+            // ppDevice:
+            &mut dev_ptr,
+            // pFeatureLevel:
+            &mut feature_level,
+            // ppImmediateContext:
+            &mut devctx_ptr,
+        );
+
+        Error::map_if(hr, || ())?;
+        Ok((FeatureLevel(feature_level), dev_ptr, devctx_ptr))
+    }
+}
+
+fn required_driver_type(
+    requested: Option<DriverType>,
+    required: DriverType,
+) -> Result<DriverType, DeviceBuilderError> {
+    match requested {
+        Some(requested) if requested != required => {
+            Err(DeviceBuilderError::ConflictingDriverType {
+                requested,
+                required,
+            })
         }
+        _ => Ok(required),
     }
 }
@@ -1,4 +1,11 @@
+use crate::enums::FeatureLevel;
+use crate::state::{BlendDesc, DepthStencilDesc, RasterizerDesc, SamplerDesc};
+use crate::state::{BlendState, DepthStencilState, RasterizerState, SamplerState};
+
+use std::ptr;
+
 use com_wrapper::ComWrapper;
+use dcommon::error::Error;
 use dxgi::device::Device as DxgiDevice;
 use winapi::shared::dxgi::IDXGIDevice;
 use winapi::um::d3d11::ID3D11Device;
@@ -22,3 +29,107 @@ impl Device {
         unsafe { DxgiDevice::from_raw(self.ptr.cast::<IDXGIDevice>().unwrap().into_raw()) }
     }
 }
+
+/// A device created with `CreateDeviceFlags::SINGLETHREADED`. Direct3D does
+/// not synchronize access to such a device, so it can neither be sent to nor
+/// shared with another thread.
+///
+#[cfg_attr(windows, doc = "```compile_fail")]
+#[cfg_attr(not(windows), doc = "```ignore")]
+/// fn assert_send<T: Send>() {}
+/// assert_send::<direct3d11::device::SingleThreadedDevice>();
+/// ```
+///
+#[cfg_attr(windows, doc = "```compile_fail")]
+#[cfg_attr(not(windows), doc = "```ignore")]
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<direct3d11::device::SingleThreadedDevice>();
+/// ```
+#[derive(Clone, PartialEq, ComWrapper)]
+#[com(debug)]
+#[repr(transparent)]
+pub struct SingleThreadedDevice {
+    ptr: ComPtr<ID3D11Device>,
+}
+
+/// Gives the resource builders access to the underlying device, whichever
+/// threading model it was created with.
+///
+/// # Safety
+///
+/// `raw_dev` must return the device wrapped by the implementing type, and
+/// the type must only be `Send` or `Sync` if that device was created without
+/// `CreateDeviceFlags::SINGLETHREADED`.
+pub unsafe trait IDevice {
+    /// Creates a blend state. Direct3D returns the existing object when a
+    /// state with the same description was already created, so states can
+    /// be created freely.
+    fn create_blend_state(&self, desc: &BlendDesc) -> Result<BlendState, Error> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.raw_dev().CreateBlendState(&desc.into(), &mut ptr);
+            Error::map_if(hr, || BlendState::from_raw(ptr))
+        }
+    }
+
+    /// Creates a depth-stencil state, reusing an existing object like
+    /// `create_blend_state`.
+    fn create_depth_stencil_state(
+        &self,
+        desc: &DepthStencilDesc,
+    ) -> Result<DepthStencilState, Error> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self
+                .raw_dev()
+                .CreateDepthStencilState(&desc.into(), &mut ptr);
+            Error::map_if(hr, || DepthStencilState::from_raw(ptr))
+        }
+    }
+
+    /// Creates a rasterizer state, reusing an existing object like
+    /// `create_blend_state`.
+    fn create_rasterizer_state(&self, desc: &RasterizerDesc) -> Result<RasterizerState, Error> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.raw_dev().CreateRasterizerState(&desc.into(), &mut ptr);
+            Error::map_if(hr, || RasterizerState::from_raw(ptr))
+        }
+    }
+
+    /// Creates a sampler state, reusing an existing object like
+    /// `create_blend_state`.
+    fn create_sampler_state(&self, desc: &SamplerDesc) -> Result<SamplerState, Error> {
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.raw_dev().CreateSamplerState(&desc.into(), &mut ptr);
+            Error::map_if(hr, || SamplerState::from_raw(ptr))
+        }
+    }
+
+    /// The feature level the device was created with, which decides the
+    /// limits the resource and view builders check against.
+    fn feature_level(&self) -> FeatureLevel {
+        unsafe { FeatureLevel(self.raw_dev().GetFeatureLevel()) }
+    }
+
+    /// The underlying Direct3D device.
+    ///
+    /// # Safety
+    ///
+    /// Calls made on the device bypass the checks of this crate, and must
+    /// respect the threading model the device was created with.
+    unsafe fn raw_dev(&self) -> &ID3D11Device;
+}
+
+unsafe impl IDevice for Device {
+    unsafe fn raw_dev(&self) -> &ID3D11Device {
+        &self.ptr
+    }
+}
+
+unsafe impl IDevice for SingleThreadedDevice {
+    unsafe fn raw_dev(&self) -> &ID3D11Device {
+        &self.ptr
+    }
+}
@@ -1,7 +1,20 @@
 use com_wrapper::ComWrapper;
 use winapi::um::d3d11::ID3D11DeviceContext;
 use wio::com::ComPtr;
-use crate::resource::Resource;
+use crate::buffer::Buffer;
+use crate::copy;
+use crate::enums::Map;
+use crate::error::{CopyError, MapError};
+use crate::image_view::ImageView;
+use crate::input_layout::InputLayout;
+use crate::mapped_subresource::{self, MappedSubresource};
+use crate::resource::IResource;
+use crate::shader::{ShaderStage, CONSTANT_BUFFER_SLOTS, SAMPLER_SLOTS, SHADER_RESOURCE_SLOTS};
+use crate::state::{BlendState, DepthStencilState, RasterizerState, SamplerState};
+use crate::subresource::Box3D;
+use crate::view::ShaderResourceView;
+
+use std::ptr;
 
 #[derive(PartialEq, ComWrapper)]
 #[com(send, debug)]
@@ -11,18 +24,214 @@ pub struct DeviceContext {
 }
 
 pub unsafe trait IDeviceContext {
-    /// Copies one resource into another.
+    /// Copies one resource into another. The resources must be distinct,
+    /// of the same kind and size, with formats in the same typeless family,
+    /// and the destination cannot be immutable.
     // NOTE the order of parameters is reversed (src -> dest seems more idiomatic in rust).
-    unsafe fn copy_resource(&self, src: &Resource, dst: &Resource)  {
-        self.raw_ctx().CopyResource(dst.get_raw(), src.get_raw());
+    fn copy_resource(&self, src: &dyn IResource, dst: &dyn IResource) -> Result<(), CopyError> {
+        unsafe { copy::copy_resource(self.raw_ctx(), src.raw_res(), dst.raw_res()) }
+    }
+
+    /// Maps a subresource into CPU memory. The mode is checked against the
+    /// usage and CPU access flags the resource was created with before
+    /// anything is passed to Direct3D.
+    ///
+    /// Use `Map::Read` to read back staging resources, and `Map::Discard` or
+    /// `Map::WriteNoOverwrite` to update dynamic ones.
+    fn map<'a>(
+        &'a self,
+        resource: &dyn IResource,
+        subresource: u32,
+        map: Map,
+    ) -> Result<MappedSubresource<'a>, MapError> {
+        unsafe { mapped_subresource::map(self.raw_ctx(), resource.raw_res(), subresource, map) }
+    }
+
+    /// Unmaps a subresource mapped by `map`. Dropping the guard does the same.
+    fn unmap(&self, mapped: MappedSubresource) {
+        mapped.unmap()
+    }
+
+    /// Writes `image` to a region of a texture subresource, or to the whole
+    /// subresource if `dst_box` is `None`. The image must be the size of the
+    /// region and have a format compatible with the texture.
+    fn update_subresource(
+        &self,
+        resource: &dyn IResource,
+        subresource: u32,
+        dst_box: Option<Box3D>,
+        image: &ImageView,
+    ) -> Result<(), CopyError> {
+        unsafe {
+            copy::update_subresource(self.raw_ctx(), resource.raw_res(), subresource, dst_box, image)
+        }
+    }
+
+    /// Writes `data` to a buffer, starting `offset` bytes in. Constant buffers
+    /// can only be updated as a whole.
+    fn update_buffer(&self, buffer: &Buffer, offset: u32, data: &[u8]) -> Result<(), CopyError> {
+        unsafe { copy::update_buffer(self.raw_ctx(), buffer.raw_res(), offset, data) }
+    }
+
+    /// Copies `src_box` of a source subresource, or all of it if `src_box` is
+    /// `None`, to `dst_offset` in a destination subresource. For buffers,
+    /// the box and offset are in bytes. The source and destination can be
+    /// subresources of the same resource, but not the same subresource.
+    // NOTE the destination comes first to match the order of the offset
+    // and box in the Direct3D call.
+    fn copy_subresource_region(
+        &self,
+        dst: &dyn IResource,
+        dst_subresource: u32,
+        dst_offset: (u32, u32, u32),
+        src: &dyn IResource,
+        src_subresource: u32,
+        src_box: Option<Box3D>,
+    ) -> Result<(), CopyError> {
+        unsafe {
+            copy::copy_subresource_region(
+                self.raw_ctx(),
+                dst.raw_res(),
+                dst_subresource,
+                dst_offset,
+                src.raw_res(),
+                src_subresource,
+                src_box,
+            )
+        }
+    }
+
+    /// Binds a shader to the stage it runs in, or unbinds the shader of
+    /// stage `S` if `shader` is `None`.
+    fn set_shader<S: ShaderStage>(&self, shader: Option<&S>) {
+        unsafe { S::set_shader(self.raw_ctx(), shader) }
+    }
+
+    /// Binds constant buffers to consecutive slots of stage `S`, starting at
+    /// `start_slot`. `None` unbinds a slot.
+    ///
+    /// ### panics
+    /// Panics if the slots go past `shader::CONSTANT_BUFFER_SLOTS`.
+    fn set_constant_buffers<S: ShaderStage>(&self, start_slot: u32, buffers: &[Option<&Buffer>]) {
+        let buffers = raw_slots::<S, _>(
+            "constant buffer",
+            start_slot,
+            buffers,
+            CONSTANT_BUFFER_SLOTS,
+        );
+        unsafe { S::set_constant_buffers(self.raw_ctx(), start_slot, &buffers) }
+    }
+
+    /// Binds shader resource views to consecutive slots of stage `S`,
+    /// starting at `start_slot`. `None` unbinds a slot.
+    ///
+    /// ### panics
+    /// Panics if the slots go past `shader::SHADER_RESOURCE_SLOTS`.
+    fn set_shader_resources<S: ShaderStage>(
+        &self,
+        start_slot: u32,
+        views: &[Option<&ShaderResourceView>],
+    ) {
+        let views = raw_slots::<S, _>("shader resource", start_slot, views, SHADER_RESOURCE_SLOTS);
+        unsafe { S::set_shader_resources(self.raw_ctx(), start_slot, &views) }
+    }
+
+    /// Binds sampler states to consecutive slots of stage `S`, starting at
+    /// `start_slot`. `None` unbinds a slot, which then samples like
+    /// `SamplerDesc::default()`.
+    ///
+    /// ### panics
+    /// Panics if the slots go past `shader::SAMPLER_SLOTS`.
+    fn set_samplers<S: ShaderStage>(&self, start_slot: u32, samplers: &[Option<&SamplerState>]) {
+        let samplers = raw_slots::<S, _>("sampler", start_slot, samplers, SAMPLER_SLOTS);
+        unsafe { S::set_samplers(self.raw_ctx(), start_slot, &samplers) }
+    }
+
+    /// Sets the blend state of the output merger, or the default state if
+    /// `state` is `None`. `blend_factor` is used by `Blend::BlendFactor`,
+    /// and only the samples set in `sample_mask` are written; pass
+    /// `0xFFFF_FFFF` to write all of them.
+    fn set_blend_state(
+        &self,
+        state: Option<&BlendState>,
+        blend_factor: [f32; 4],
+        sample_mask: u32,
+    ) {
+        let state = state.map_or(ptr::null_mut(), |state| unsafe { state.get_raw() });
+        unsafe {
+            self.raw_ctx()
+                .OMSetBlendState(state, &blend_factor, sample_mask)
+        }
+    }
+
+    /// Sets the depth-stencil state of the output merger, or the default
+    /// state if `state` is `None`. `stencil_ref` is the reference the
+    /// stencil test compares with.
+    fn set_depth_stencil_state(&self, state: Option<&DepthStencilState>, stencil_ref: u32) {
+        let state = state.map_or(ptr::null_mut(), |state| unsafe { state.get_raw() });
+        unsafe { self.raw_ctx().OMSetDepthStencilState(state, stencil_ref) }
+    }
+
+    /// Sets the rasterizer state, or the default state if `state` is `None`.
+    fn set_rasterizer_state(&self, state: Option<&RasterizerState>) {
+        let state = state.map_or(ptr::null_mut(), |state| unsafe { state.get_raw() });
+        unsafe { self.raw_ctx().RSSetState(state) }
+    }
+
+    /// Sets the layout the input assembler reads vertex buffers with, or
+    /// unsets it if `layout` is `None`.
+    fn set_input_layout(&self, layout: Option<&InputLayout>) {
+        let layout = layout.map_or(ptr::null_mut(), |layout| unsafe { layout.get_raw() });
+        unsafe { self.raw_ctx().IASetInputLayout(layout) }
     }
 
     unsafe fn raw_ctx(&self) -> &ID3D11DeviceContext;
 }
 
+fn raw_slots<S: ShaderStage, T: ComWrapper>(
+    kind: &str,
+    start_slot: u32,
+    items: &[Option<&T>],
+    slot_count: u32,
+) -> Vec<*mut T::Interface> {
+    assert!(
+        start_slot as usize + items.len() <= slot_count as usize,
+        "{} {} slots {}..{} are out of range, the stage has {}",
+        S::NAME,
+        kind,
+        start_slot,
+        start_slot as usize + items.len(),
+        slot_count,
+    );
+    items
+        .iter()
+        .map(|item| item.map_or(ptr::null_mut(), |item| unsafe { item.get_raw() }))
+        .collect()
+}
+
 unsafe impl IDeviceContext for DeviceContext {
     unsafe fn raw_ctx(&self) -> &ID3D11DeviceContext {
         &self.ptr
     }
 }
 
+/// The immediate context of a `SingleThreadedDevice`. Like its device, it is
+/// neither `Send` nor `Sync`.
+///
+#[cfg_attr(windows, doc = "```compile_fail")]
+#[cfg_attr(not(windows), doc = "```ignore")]
+/// fn assert_send<T: Send>() {}
+/// assert_send::<direct3d11::device_context::SingleThreadedDeviceContext>();
+/// ```
+#[derive(PartialEq, ComWrapper)]
+#[com(debug)]
+#[repr(transparent)]
+pub struct SingleThreadedDeviceContext {
+    ptr: ComPtr<ID3D11DeviceContext>,
+}
+
+unsafe impl IDeviceContext for SingleThreadedDeviceContext {
+    unsafe fn raw_ctx(&self) -> &ID3D11DeviceContext {
+        &self.ptr
+    }
+}
@@ -0,0 +1,93 @@
+//! The checksum in the container header, which the runtime verifies before
+//! creating a shader.
+//!
+//! It is MD5 over everything after the checksum field, with a nonstandard
+//! final block: the bit count is placed before the leftover bytes rather
+//! than after them, and the last word holds `(bits >> 2) | 1`.
+
+/// The offset of the first byte covered by the checksum.
+pub(crate) const CHECKSUM_START: usize = 20;
+
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+const SINES: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// Computes the checksum of a whole container. The first 20 bytes, the
+/// magic and the checksum itself, are not covered, so the result can be
+/// compared with the stored checksum or written over it.
+pub fn checksum(container: &[u8]) -> [u8; 16] {
+    let data = container.get(CHECKSUM_START..).unwrap_or(&[]);
+    let mut state = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+    let full = data.len() & !63;
+    for block in data[..full].chunks_exact(64) {
+        transform(&mut state, block);
+    }
+
+    let leftover = &data[full..];
+    let bits = (data.len() as u32).wrapping_mul(8);
+    let mut block = [0; 64];
+    if leftover.len() >= 56 {
+        block[..leftover.len()].copy_from_slice(leftover);
+        block[leftover.len()] = 0x80;
+        transform(&mut state, &block);
+        block = [0; 64];
+        block[..4].copy_from_slice(&bits.to_le_bytes());
+    } else {
+        block[..4].copy_from_slice(&bits.to_le_bytes());
+        block[4..4 + leftover.len()].copy_from_slice(leftover);
+        block[4 + leftover.len()] = 0x80;
+    }
+    block[60..].copy_from_slice(&((bits >> 2) | 1).to_le_bytes());
+    transform(&mut state, &block);
+
+    let mut digest = [0; 16];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(&state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// The MD5 compression function.
+fn transform(state: &mut [u32; 4], block: &[u8]) {
+    let mut words = [0u32; 16];
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+    for i in 0..64 {
+        let (f, g) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), (7 * i) % 16),
+        };
+        let f = f
+            .wrapping_add(a)
+            .wrapping_add(SINES[i])
+            .wrapping_add(words[g]);
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(f.rotate_left(SHIFTS[i]));
+    }
+
+    state[0] = state[0].wrapping_add(a);
+    state[1] = state[1].wrapping_add(b);
+    state[2] = state[2].wrapping_add(c);
+    state[3] = state[3].wrapping_add(d);
+}
@@ -0,0 +1,956 @@
+//! A disassembler for shader model 4 and 5 code, producing the listing
+//! format of `fxc /dumpbin`: a `dcl_*` line per declaration and a line per
+//! instruction, indented inside flow control blocks.
+
+use crate::dxbc::program::OPCODE_CUSTOMDATA;
+use crate::dxbc::{DxbcError, Program};
+
+use std::fmt::Write;
+
+/// How immediate operands of an instruction are printed.
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Literal {
+    Float,
+    Int,
+    /// Integers printed in hex once they are large, for bitwise operations.
+    Bits,
+    Double,
+    /// Floats if they look like one, integers otherwise, for moves.
+    Untyped,
+}
+
+/// The mnemonic and literal type of the instructions that are not
+/// declarations.
+fn opcode(op: u32) -> Option<(&'static str, Literal)> {
+    use self::Literal::*;
+    Some(match op {
+        0 => ("add", Float),
+        1 => ("and", Bits),
+        2 => ("break", Int),
+        3 => ("breakc", Int),
+        4 => ("call", Int),
+        5 => ("callc", Int),
+        6 => ("case", Int),
+        7 => ("continue", Int),
+        8 => ("continuec", Int),
+        9 => ("cut", Int),
+        10 => ("default", Int),
+        11 => ("deriv_rtx", Float),
+        12 => ("deriv_rty", Float),
+        13 => ("discard", Int),
+        14 => ("div", Float),
+        15 => ("dp2", Float),
+        16 => ("dp3", Float),
+        17 => ("dp4", Float),
+        18 => ("else", Int),
+        19 => ("emit", Int),
+        20 => ("emitThenCut", Int),
+        21 => ("endif", Int),
+        22 => ("endloop", Int),
+        23 => ("endswitch", Int),
+        24 => ("eq", Float),
+        25 => ("exp", Float),
+        26 => ("frc", Float),
+        27 => ("ftoi", Float),
+        28 => ("ftou", Float),
+        29 => ("ge", Float),
+        30 => ("iadd", Int),
+        31 => ("if", Int),
+        32 => ("ieq", Int),
+        33 => ("ige", Int),
+        34 => ("ilt", Int),
+        35 => ("imad", Int),
+        36 => ("imax", Int),
+        37 => ("imin", Int),
+        38 => ("imul", Int),
+        39 => ("ine", Int),
+        40 => ("ineg", Int),
+        41 => ("ishl", Int),
+        42 => ("ishr", Int),
+        43 => ("itof", Int),
+        44 => ("label", Int),
+        45 => ("ld", Int),
+        46 => ("ld_ms", Int),
+        47 => ("log", Float),
+        48 => ("loop", Int),
+        49 => ("lt", Float),
+        50 => ("mad", Float),
+        51 => ("min", Float),
+        52 => ("max", Float),
+        54 => ("mov", Untyped),
+        55 => ("movc", Untyped),
+        56 => ("mul", Float),
+        57 => ("ne", Float),
+        58 => ("nop", Int),
+        59 => ("not", Bits),
+        60 => ("or", Bits),
+        61 => ("resinfo", Int),
+        62 => ("ret", Int),
+        63 => ("retc", Int),
+        64 => ("round_ne", Float),
+        65 => ("round_ni", Float),
+        66 => ("round_pi", Float),
+        67 => ("round_z", Float),
+        68 => ("rsq", Float),
+        69 => ("sample", Float),
+        70 => ("sample_c", Float),
+        71 => ("sample_c_lz", Float),
+        72 => ("sample_l", Float),
+        73 => ("sample_d", Float),
+        74 => ("sample_b", Float),
+        75 => ("sqrt", Float),
+        76 => ("switch", Int),
+        77 => ("sincos", Float),
+        78 => ("udiv", Int),
+        79 => ("ult", Int),
+        80 => ("uge", Int),
+        81 => ("umul", Int),
+        82 => ("umad", Int),
+        83 => ("umax", Int),
+        84 => ("umin", Int),
+        85 => ("ushr", Int),
+        86 => ("utof", Int),
+        87 => ("xor", Bits),
+        108 => ("lod", Float),
+        109 => ("gather4", Float),
+        110 => ("sample_pos", Int),
+        111 => ("sample_info", Int),
+        113 => ("hs_decls", Int),
+        114 => ("hs_control_point_phase", Int),
+        115 => ("hs_fork_phase", Int),
+        116 => ("hs_join_phase", Int),
+        117 => ("emit_stream", Int),
+        118 => ("cut_stream", Int),
+        119 => ("emitThenCut_stream", Int),
+        120 => ("fcall", Int),
+        121 => ("bufinfo", Int),
+        122 => ("deriv_rtx_coarse", Float),
+        123 => ("deriv_rtx_fine", Float),
+        124 => ("deriv_rty_coarse", Float),
+        125 => ("deriv_rty_fine", Float),
+        126 => ("gather4_c", Float),
+        127 => ("gather4_po", Float),
+        128 => ("gather4_po_c", Float),
+        129 => ("rcp", Float),
+        130 => ("f32tof16", Float),
+        131 => ("f16tof32", Int),
+        132 => ("uaddc", Int),
+        133 => ("usubb", Int),
+        134 => ("countbits", Int),
+        135 => ("firstbit_hi", Int),
+        136 => ("firstbit_lo", Int),
+        137 => ("firstbit_shi", Int),
+        138 => ("ubfe", Int),
+        139 => ("ibfe", Int),
+        140 => ("bfi", Int),
+        141 => ("bfrev", Int),
+        142 => ("swapc", Untyped),
+        163 => ("ld_uav_typed", Int),
+        164 => ("store_uav_typed", Untyped),
+        165 => ("ld_raw", Int),
+        166 => ("store_raw", Untyped),
+        167 => ("ld_structured", Int),
+        168 => ("store_structured", Untyped),
+        169 => ("atomic_and", Bits),
+        170 => ("atomic_or", Bits),
+        171 => ("atomic_xor", Bits),
+        172 => ("atomic_cmp_store", Int),
+        173 => ("atomic_iadd", Int),
+        174 => ("atomic_imax", Int),
+        175 => ("atomic_imin", Int),
+        176 => ("atomic_umax", Int),
+        177 => ("atomic_umin", Int),
+        178 => ("imm_atomic_alloc", Int),
+        179 => ("imm_atomic_consume", Int),
+        180 => ("imm_atomic_iadd", Int),
+        181 => ("imm_atomic_and", Bits),
+        182 => ("imm_atomic_or", Bits),
+        183 => ("imm_atomic_xor", Bits),
+        184 => ("imm_atomic_exch", Untyped),
+        185 => ("imm_atomic_cmp_exch", Untyped),
+        186 => ("imm_atomic_imax", Int),
+        187 => ("imm_atomic_imin", Int),
+        188 => ("imm_atomic_umax", Int),
+        189 => ("imm_atomic_umin", Int),
+        190 => ("sync", Int),
+        191 => ("dadd", Double),
+        192 => ("dmax", Double),
+        193 => ("dmin", Double),
+        194 => ("dmul", Double),
+        195 => ("deq", Double),
+        196 => ("dge", Double),
+        197 => ("dlt", Double),
+        198 => ("dne", Double),
+        199 => ("dmov", Double),
+        200 => ("dmovc", Double),
+        201 => ("dtof", Double),
+        202 => ("ftod", Float),
+        203 => ("eval_snapped", Int),
+        204 => ("eval_sample_index", Int),
+        205 => ("eval_centroid", Int),
+        207 => ("abort", Int),
+        208 => ("debugbreak", Int),
+        210 => ("ddiv", Double),
+        211 => ("dfma", Double),
+        212 => ("drcp", Double),
+        213 => ("msad", Int),
+        214 => ("dtoi", Double),
+        215 => ("dtou", Double),
+        216 => ("itod", Int),
+        217 => ("utod", Int),
+        _ => return None,
+    })
+}
+
+/// Opcodes whose instruction is true when its condition is nonzero or zero,
+/// printed with a `_nz` or `_z` suffix.
+const CONDITIONAL: &[u32] = &[3, 5, 8, 13, 31, 63];
+
+const RESOURCE_DIMENSIONS: &[&str] = &[
+    "unknown",
+    "buffer",
+    "texture1d",
+    "texture2d",
+    "texture2dms",
+    "texture3d",
+    "texturecube",
+    "texture1darray",
+    "texture2darray",
+    "texture2dmsarray",
+    "texturecubearray",
+    "raw_buffer",
+    "structured_buffer",
+];
+
+const RETURN_TYPES: &[&str] = &[
+    "unknown",
+    "unorm",
+    "snorm",
+    "sint",
+    "uint",
+    "float",
+    "mixed",
+    "double",
+    "continued",
+    "unused",
+];
+
+const SYSTEM_VALUES: &[&str] = &[
+    "undefined",
+    "position",
+    "clip_distance",
+    "cull_distance",
+    "rendertarget_array_index",
+    "viewport_array_index",
+    "vertex_id",
+    "primitive_id",
+    "instance_id",
+    "is_front_face",
+    "sampleIndex",
+    "finalQuadUeq0EdgeTessFactor",
+    "finalQuadVeq0EdgeTessFactor",
+    "finalQuadUeq1EdgeTessFactor",
+    "finalQuadVeq1EdgeTessFactor",
+    "finalQuadUInsideTessFactor",
+    "finalQuadVInsideTessFactor",
+    "finalTriUeq0EdgeTessFactor",
+    "finalTriVeq0EdgeTessFactor",
+    "finalTriWeq0EdgeTessFactor",
+    "finalTriInsideTessFactor",
+    "finalLineDetailTessFactor",
+    "finalLineDensityTessFactor",
+];
+
+const INTERPOLATION_MODES: &[&str] = &[
+    "",
+    "constant",
+    "linear",
+    "linear centroid",
+    "linear noperspective",
+    "linear noperspective centroid",
+    "linear sample",
+    "linear noperspective sample",
+];
+
+const GLOBAL_FLAGS: &[&str] = &[
+    "refactoringAllowed",
+    "enableDoublePrecisionFloatOps",
+    "forceEarlyDepthStencil",
+    "enableRawAndStructuredBuffers",
+    "skipOptimization",
+    "enableMinimumPrecision",
+    "enable11_1DoubleExtensions",
+    "enable11_1ShaderExtensions",
+];
+
+/// Disassembles the code of a shader. The listing starts with the target
+/// profile and has one declaration or instruction per line.
+pub fn disassemble(program: &Program) -> Result<String, DxbcError> {
+    let mut out = String::new();
+    writeln!(out, "{}", program.version).unwrap();
+    let mut depth = 0usize;
+    let mut offset = 0;
+    for instruction in program.instructions() {
+        let instruction = instruction?;
+        let op = instruction[0] & 0x7FF;
+        if let 18 | 21 | 22 | 23 = op {
+            depth = depth.saturating_sub(1);
+        }
+        let mut decoder = Decoder {
+            tokens: instruction,
+            pos: 1,
+            offset,
+        };
+        if let Some(line) = decoder.instruction()? {
+            for _ in 0..depth {
+                out.push_str("  ");
+            }
+            out.push_str(&line);
+            out.push('\n');
+        }
+        if let 18 | 31 | 48 | 76 = op {
+            depth += 1;
+        }
+        offset += instruction.len();
+    }
+    Ok(out)
+}
+
+/// Reads the tokens of one instruction.
+struct Decoder<'p> {
+    tokens: &'p [u32],
+    pos: usize,
+    /// The offset of the instruction in the program, for errors.
+    offset: usize,
+}
+
+/// A decoded operand.
+struct Operand {
+    ty: u32,
+    components: Components,
+    indices: Vec<Index>,
+    modifier: u32,
+    immediates: Vec<u32>,
+}
+
+enum Components {
+    None,
+    Mask(u32),
+    Swizzle([u32; 4]),
+    Select(u32),
+}
+
+enum Index {
+    Immediate(u64),
+    Relative(Box<Operand>, u64),
+}
+
+impl<'p> Decoder<'p> {
+    fn error(&self, reason: &'static str) -> DxbcError {
+        DxbcError::InvalidInstruction {
+            offset: self.offset,
+            reason,
+        }
+    }
+
+    fn token(&mut self) -> Result<u32, DxbcError> {
+        let token = *self
+            .tokens
+            .get(self.pos)
+            .ok_or(self.error("the operands run past the end of the instruction"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn controls(&self) -> u32 {
+        self.tokens[0] >> 11 & 0x1FFF
+    }
+
+    fn operand(&mut self) -> Result<Operand, DxbcError> {
+        let token = self.token()?;
+        let num_components = token & 3;
+        let components = match (num_components, token >> 2 & 3) {
+            (2, 0) => Components::Mask(token >> 4 & 0xF),
+            (2, 1) => Components::Swizzle([
+                token >> 4 & 3,
+                token >> 6 & 3,
+                token >> 8 & 3,
+                token >> 10 & 3,
+            ]),
+            (2, 2) => Components::Select(token >> 4 & 3),
+            (2, _) => return Err(self.error("unknown component selection mode")),
+            (3, _) => return Err(self.error("operands with N components are not supported")),
+            _ => Components::None,
+        };
+        let ty = token >> 12 & 0xFF;
+        let dimension = (token >> 20 & 3) as usize;
+
+        let mut modifier = 0;
+        let mut extended = token & 0x8000_0000 != 0;
+        while extended {
+            let token = self.token()?;
+            if token & 0x3F == 1 {
+                modifier = token >> 6 & 0xFF;
+            }
+            extended = token & 0x8000_0000 != 0;
+        }
+
+        let mut immediates = Vec::new();
+        if ty == 4 || ty == 5 {
+            let count = match num_components {
+                1 => 1,
+                2 => 4,
+                _ => return Err(self.error("immediates need 1 or 4 components")),
+            };
+            let words = if ty == 5 { 2 } else { 1 };
+            for _ in 0..count * words {
+                immediates.push(self.token()?);
+            }
+        }
+
+        let mut indices = Vec::with_capacity(dimension);
+        for i in 0..dimension {
+            let representation = token >> (22 + 3 * i) & 7;
+            let immediate = match representation {
+                0 | 3 => self.token()? as u64,
+                1 | 4 => (self.token()? as u64) << 32 | self.token()? as u64,
+                2 => 0,
+                _ => return Err(self.error("unknown index representation")),
+            };
+            indices.push(match representation {
+                0 | 1 => Index::Immediate(immediate),
+                _ => Index::Relative(Box::new(self.operand()?), immediate),
+            });
+        }
+
+        Ok(Operand {
+            ty,
+            components,
+            indices,
+            modifier,
+            immediates,
+        })
+    }
+
+    /// Decodes the instruction into a line of the listing, or `None` for
+    /// custom data the listing does not show.
+    fn instruction(&mut self) -> Result<Option<String>, DxbcError> {
+        let op = self.tokens[0] & 0x7FF;
+        let controls = self.controls();
+        let mut line = String::new();
+        match op {
+            OPCODE_CUSTOMDATA => return self.custom_data(),
+            88 | 156 => {
+                let dimension = controls & 0x1F;
+                let name = if op == 88 {
+                    "dcl_resource"
+                } else {
+                    "dcl_uav_typed"
+                };
+                write!(line, "{}_{}", name, table(RESOURCE_DIMENSIONS, dimension)).unwrap();
+                if op == 88 && (dimension == 4 || dimension == 9) {
+                    write!(line, "({})", controls >> 5 & 0x7F).unwrap();
+                }
+                if op == 156 && controls & 0x20 != 0 {
+                    line.push_str("_glc");
+                }
+                let operand = self.operand()?;
+                let return_type = self.token()?;
+                write!(line, " {} ", return_types(return_type)).unwrap();
+                self.write_operand(&mut line, &operand, Literal::Int);
+            }
+            89 => {
+                line.push_str("dcl_constantbuffer ");
+                let operand = self.operand()?;
+                match operand.indices[..] {
+                    [Index::Immediate(slot), Index::Immediate(size)] => {
+                        write!(line, "CB{}[{}]", slot, size).unwrap()
+                    }
+                    _ => self.write_operand(&mut line, &operand, Literal::Int),
+                }
+                line.push_str(if controls & 1 != 0 {
+                    ", dynamicIndexed"
+                } else {
+                    ", immediateIndexed"
+                });
+            }
+            90 => {
+                line.push_str("dcl_sampler ");
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+                let mode = ["mode_default", "mode_comparison", "mode_mono"];
+                write!(line, ", {}", table(&mode, controls & 0xF)).unwrap();
+            }
+            91 => {
+                line.push_str("dcl_indexrange ");
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+                write!(line, " {}", self.token()?).unwrap();
+            }
+            92 => {
+                let topologies = [
+                    "undefined",
+                    "pointlist",
+                    "linelist",
+                    "linestrip",
+                    "trianglelist",
+                    "trianglestrip",
+                    "undefined",
+                    "undefined",
+                    "undefined",
+                    "undefined",
+                    "linelist_adj",
+                    "linestrip_adj",
+                    "trianglelist_adj",
+                    "trianglestrip_adj",
+                ];
+                let topology = table(&topologies, controls & 0x7F);
+                write!(line, "dcl_outputtopology {}", topology).unwrap();
+            }
+            93 => {
+                let primitive = controls & 0x3F;
+                let name = match primitive {
+                    1 => "point".to_string(),
+                    2 => "line".to_string(),
+                    3 => "triangle".to_string(),
+                    6 => "lineadj".to_string(),
+                    7 => "triangleadj".to_string(),
+                    8..=39 => format!("patch{}", primitive - 7),
+                    _ => "undefined".to_string(),
+                };
+                write!(line, "dcl_inputprimitive {}", name).unwrap();
+            }
+            94 => write!(line, "dcl_maxout {}", self.token()?).unwrap(),
+            95..=103 => {
+                let names = [
+                    "dcl_input",
+                    "dcl_input_sgv",
+                    "dcl_input_siv",
+                    "dcl_input_ps",
+                    "dcl_input_ps_sgv",
+                    "dcl_input_ps_siv",
+                    "dcl_output",
+                    "dcl_output_sgv",
+                    "dcl_output_siv",
+                ];
+                line.push_str(names[(op - 95) as usize]);
+                if let 98..=100 = op {
+                    let mode = table(INTERPOLATION_MODES, controls & 0xF);
+                    if !mode.is_empty() {
+                        write!(line, " {}", mode).unwrap();
+                    }
+                }
+                line.push(' ');
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+                if let 96 | 97 | 99 | 100 | 102 | 103 = op {
+                    let name = self.token()?;
+                    write!(line, ", {}", table(SYSTEM_VALUES, name & 0xFFFF)).unwrap();
+                }
+            }
+            104 => write!(line, "dcl_temps {}", self.token()?).unwrap(),
+            105 => {
+                let (register, size, components) = (self.token()?, self.token()?, self.token()?);
+                write!(
+                    line,
+                    "dcl_indexableTemp x{}[{}], {}",
+                    register, size, components
+                )
+                .unwrap();
+            }
+            106 => {
+                line.push_str("dcl_globalFlags ");
+                let flags: Vec<_> = GLOBAL_FLAGS
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| controls & 1 << i != 0)
+                    .map(|(_, &name)| name)
+                    .collect();
+                line.push_str(&flags.join(" | "));
+            }
+            143 => {
+                line.push_str("dcl_stream ");
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+            }
+            144 => write!(line, "dcl_function_body fb{}", self.token()?).unwrap(),
+            145 => {
+                let (table, count) = (self.token()?, self.token()?);
+                let bodies = (0..count)
+                    .map(|_| Ok(format!("fb{}", self.token()?)))
+                    .collect::<Result<Vec<_>, DxbcError>>()?;
+                write!(
+                    line,
+                    "dcl_function_table ft{} = {{{}}}",
+                    table,
+                    bodies.join(", ")
+                )
+                .unwrap();
+            }
+            146 => {
+                let interface = self.token()?;
+                let calls = self.token()?;
+                let counts = self.token()?;
+                let tables = (0..counts & 0xFFFF)
+                    .map(|_| Ok(format!("ft{}", self.token()?)))
+                    .collect::<Result<Vec<_>, DxbcError>>()?;
+                let name = if controls & 1 != 0 {
+                    "dcl_interface_dynamicindexed"
+                } else {
+                    "dcl_interface"
+                };
+                write!(
+                    line,
+                    "{} fp{}[{}][{}] = {{{}}}",
+                    name,
+                    interface,
+                    counts >> 16,
+                    calls,
+                    tables.join(", ")
+                )
+                .unwrap();
+            }
+            147 => write!(line, "dcl_input_control_point_count {}", controls & 0x3F).unwrap(),
+            148 => write!(line, "dcl_output_control_point_count {}", controls & 0x3F).unwrap(),
+            149 => {
+                let domains = ["undefined", "domain_isoline", "domain_tri", "domain_quad"];
+                let domain = table(&domains, controls & 3);
+                write!(line, "dcl_tessellator_domain {}", domain).unwrap();
+            }
+            150 => {
+                let partitionings = [
+                    "undefined",
+                    "partitioning_integer",
+                    "partitioning_pow2",
+                    "partitioning_fractional_odd",
+                    "partitioning_fractional_even",
+                ];
+                let partitioning = table(&partitionings, controls & 7);
+                write!(line, "dcl_tessellator_partitioning {}", partitioning).unwrap();
+            }
+            151 => {
+                let primitives = [
+                    "undefined",
+                    "output_point",
+                    "output_line",
+                    "output_triangle_cw",
+                    "output_triangle_ccw",
+                ];
+                let primitive = table(&primitives, controls & 7);
+                write!(line, "dcl_tessellator_output_primitive {}", primitive).unwrap();
+            }
+            152 => {
+                let factor = f32::from_bits(self.token()?);
+                write!(line, "dcl_hs_max_tessfactor l({:.6})", factor).unwrap();
+            }
+            153 => write!(line, "dcl_hs_fork_phase_instance_count {}", self.token()?).unwrap(),
+            154 => write!(line, "dcl_hs_join_phase_instance_count {}", self.token()?).unwrap(),
+            155 => {
+                let (x, y, z) = (self.token()?, self.token()?, self.token()?);
+                write!(line, "dcl_thread_group {}, {}, {}", x, y, z).unwrap();
+            }
+            157 | 158 => {
+                line.push_str(if op == 157 {
+                    "dcl_uav_raw"
+                } else {
+                    "dcl_uav_structured"
+                });
+                if controls & 0x20 != 0 {
+                    line.push_str("_glc");
+                }
+                if controls & 0x1000 != 0 {
+                    line.push_str("_opc");
+                }
+                line.push(' ');
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+                if op == 158 {
+                    write!(line, ", {}", self.token()?).unwrap();
+                }
+            }
+            159..=162 => {
+                let names = [
+                    "dcl_tgsm_raw",
+                    "dcl_tgsm_structured",
+                    "dcl_resource_raw",
+                    "dcl_resource_structured",
+                ];
+                write!(line, "{} ", names[(op - 159) as usize]).unwrap();
+                let operand = self.operand()?;
+                self.write_operand(&mut line, &operand, Literal::Int);
+                let extra = match op {
+                    159 | 162 => 1,
+                    160 => 2,
+                    _ => 0,
+                };
+                for _ in 0..extra {
+                    write!(line, ", {}", self.token()?).unwrap();
+                }
+            }
+            206 => write!(line, "dcl_gsinstances {}", self.token()?).unwrap(),
+            _ => return self.operation(op).map(Some),
+        }
+        if self.pos != self.tokens.len() {
+            return Err(self.error("the declaration has trailing tokens"));
+        }
+        Ok(Some(line))
+    }
+
+    /// Decodes an instruction that is not a declaration.
+    fn operation(&mut self, op: u32) -> Result<String, DxbcError> {
+        let (name, literal) = opcode(op).ok_or(self.error("unknown opcode"))?;
+        let controls = self.controls();
+        let mut line = String::from(name);
+        match op {
+            61 => line.push_str(table(&["", "_rcpFloat", "_uint"], controls & 3)),
+            111 if controls & 1 != 0 => line.push_str("_uint"),
+            190 => {
+                if controls & 8 != 0 {
+                    line.push_str("_uglobal");
+                } else if controls & 4 != 0 {
+                    line.push_str("_ugroup");
+                }
+                if controls & 2 != 0 {
+                    line.push_str("_g");
+                }
+                if controls & 1 != 0 {
+                    line.push_str("_t");
+                }
+            }
+            _ if CONDITIONAL.contains(&op) => {
+                line.push_str(if controls & 0x80 != 0 { "_nz" } else { "_z" })
+            }
+            _ if controls & 4 != 0 => line.push_str("_sat"),
+            _ => (),
+        }
+
+        let mut offsets = None;
+        let mut dimension = None;
+        let mut return_type = None;
+        let mut extended = self.tokens[0] & 0x8000_0000 != 0;
+        while extended {
+            let token = self.token()?;
+            match token & 0x3F {
+                1 => {
+                    let offset = |shift: u32| ((token >> shift & 0xF) as i32) << 28 >> 28;
+                    offsets = Some((offset(9), offset(13), offset(17)));
+                }
+                2 => dimension = Some((token >> 6 & 0x1F, token >> 11 & 0xFFF)),
+                3 => return_type = Some(token >> 6),
+                _ => return Err(self.error("unknown extended opcode")),
+            }
+            extended = token & 0x8000_0000 != 0;
+        }
+        if let Some((u, v, w)) = offsets {
+            line.push_str("_aoffimmi");
+            if dimension.is_some() {
+                line.push_str("_indexable");
+            }
+            write!(line, "({},{},{})", u, v, w).unwrap();
+        } else if dimension.is_some() {
+            line.push_str("_indexable");
+        }
+        if let Some((dimension, stride)) = dimension {
+            line.push('(');
+            line.push_str(table(RESOURCE_DIMENSIONS, dimension));
+            if dimension == 12 {
+                write!(line, ", stride={}", stride).unwrap();
+            }
+            line.push(')');
+        }
+        if let Some(return_type) = return_type {
+            line.push_str(&return_types(return_type));
+        }
+
+        if op == 120 {
+            let call_site = self.token()?;
+            let operand = self.operand()?;
+            line.push(' ');
+            self.write_operand(&mut line, &operand, literal);
+            write!(line, ", {}", call_site).unwrap();
+            return Ok(line);
+        }
+
+        let mut first = true;
+        while self.pos < self.tokens.len() {
+            let operand = self.operand()?;
+            line.push_str(if first { " " } else { ", " });
+            first = false;
+            self.write_operand(&mut line, &operand, literal);
+        }
+        Ok(line)
+    }
+
+    /// Custom data blocks: only immediate constant buffers are listed.
+    fn custom_data(&mut self) -> Result<Option<String>, DxbcError> {
+        if self.tokens[0] >> 11 != 3 {
+            return Ok(None);
+        }
+        let values = self
+            .tokens
+            .get(2..)
+            .ok_or(self.error("the custom data is too short"))?;
+        let vectors: Vec<_> = values
+            .chunks(4)
+            .map(|vector| {
+                let values: Vec<_> = vector
+                    .iter()
+                    .map(|&value| literal(value, Literal::Untyped))
+                    .collect();
+                format!("{{ {} }}", values.join(", "))
+            })
+            .collect();
+        Ok(Some(format!(
+            "dcl_immediateConstantBuffer {{ {} }}",
+            vectors.join(",\n                              ")
+        )))
+    }
+
+    fn write_operand(&self, out: &mut String, operand: &Operand, literal_type: Literal) {
+        let (prefix, suffix) = match operand.modifier {
+            1 => ("-", ""),
+            2 => ("|", "|"),
+            3 => ("-|", "|"),
+            _ => ("", ""),
+        };
+        out.push_str(prefix);
+
+        match operand.ty {
+            4 => {
+                let values: Vec<_> = operand
+                    .immediates
+                    .iter()
+                    .map(|&value| literal(value, literal_type))
+                    .collect();
+                write!(out, "l({})", values.join(", ")).unwrap();
+            }
+            5 => {
+                let values: Vec<_> = operand
+                    .immediates
+                    .chunks(2)
+                    .map(|pair| {
+                        let value = f64::from_bits(pair[0] as u64 | (pair[1] as u64) << 32);
+                        format!("{:.6}", value)
+                    })
+                    .collect();
+                write!(out, "d({})", values.join(", ")).unwrap();
+            }
+            ty => {
+                out.push_str(register_prefix(ty));
+                let all_bracketed = match ty {
+                    9 => true,
+                    1 | 2 | 25 | 26 | 27 => operand.indices.len() == 2,
+                    _ => false,
+                };
+                for (i, index) in operand.indices.iter().enumerate() {
+                    match *index {
+                        Index::Immediate(value) if i == 0 && !all_bracketed => {
+                            write!(out, "{}", value).unwrap()
+                        }
+                        Index::Immediate(value) => write!(out, "[{}]", value).unwrap(),
+                        Index::Relative(ref register, value) => {
+                            out.push('[');
+                            self.write_operand(out, register, Literal::Int);
+                            write!(out, " + {}]", value).unwrap();
+                        }
+                    }
+                }
+            }
+        }
+
+        const NAMES: [char; 4] = ['x', 'y', 'z', 'w'];
+        match operand.components {
+            Components::Mask(mask) if mask != 0 => {
+                out.push('.');
+                for (i, &name) in NAMES.iter().enumerate() {
+                    if mask & 1 << i != 0 {
+                        out.push(name);
+                    }
+                }
+            }
+            Components::Swizzle(swizzle) if operand.ty != 4 && operand.ty != 5 => {
+                out.push('.');
+                for &component in &swizzle {
+                    out.push(NAMES[component as usize]);
+                }
+            }
+            Components::Select(component) if operand.ty != 4 && operand.ty != 5 => {
+                out.push('.');
+                out.push(NAMES[component as usize]);
+            }
+            _ => (),
+        }
+        out.push_str(suffix);
+    }
+}
+
+fn register_prefix(ty: u32) -> &'static str {
+    match ty {
+        0 => "r",
+        1 => "v",
+        2 => "o",
+        3 => "x",
+        6 => "s",
+        7 => "t",
+        8 => "cb",
+        9 => "icb",
+        10 => "l",
+        11 => "vPrim",
+        12 => "oDepth",
+        13 => "null",
+        14 => "rasterizer",
+        15 => "oMask",
+        16 => "m",
+        17 => "fb",
+        18 => "ft",
+        19 => "fp",
+        20 => "fi",
+        21 => "fo",
+        22 => "vOutputControlPointID",
+        23 => "vForkInstanceID",
+        24 => "vJoinInstanceID",
+        25 => "vicp",
+        26 => "vocp",
+        27 => "vpc",
+        28 => "vDomain",
+        29 => "this",
+        30 => "u",
+        31 => "g",
+        32 => "vThreadID",
+        33 => "vThreadGroupID",
+        34 => "vThreadIDInGroup",
+        35 => "vCoverage",
+        36 => "vThreadIDInGroupFlattened",
+        37 => "vGSInstanceID",
+        38 => "oDepthGE",
+        39 => "oDepthLE",
+        40 => "vCycleCounter",
+        41 => "oStencilRef",
+        42 => "vInnerCoverage",
+        _ => "unknown",
+    }
+}
+
+fn table<'t>(names: &[&'t str], value: u32) -> &'t str {
+    names.get(value as usize).cloned().unwrap_or("unknown")
+}
+
+/// The four return types packed in a token, e.g. `(float,float,float,float)`.
+fn return_types(token: u32) -> String {
+    let types: Vec<_> = (0..4)
+        .map(|i| table(RETURN_TYPES, token >> (4 * i) & 0xF))
+        .collect();
+    format!("({})", types.join(","))
+}
+
+fn literal(value: u32, literal_type: Literal) -> String {
+    let exponent = value >> 23 & 0xFF;
+    match literal_type {
+        Literal::Float | Literal::Double => format!("{:.6}", f32::from_bits(value)),
+        Literal::Untyped if exponent != 0 && exponent != 0xFF => {
+            format!("{:.6}", f32::from_bits(value))
+        }
+        Literal::Bits if value > 0xFFFF => format!("0x{:08x}", value),
+        _ => format!("{}", value as i32),
+    }
+}
@@ -0,0 +1,144 @@
+use crate::dxbc::FourCC;
+
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Describes why a DXBC container could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DxbcError {
+    /// The data does not start with `DXBC`.
+    BadMagic,
+
+    /// The container version is not 1.
+    UnsupportedVersion(u32),
+
+    /// The size in the header does not match the size of the data.
+    SizeMismatch { header: u32, actual: usize },
+
+    /// The checksum in the header does not match the contents, e.g.
+    /// because the container was modified or truncated.
+    ChecksumMismatch {
+        stored: [u8; 16],
+        computed: [u8; 16],
+    },
+
+    /// The header or contents of a chunk extend past the end of the
+    /// container.
+    ChunkOutOfBounds { index: u32, offset: u32 },
+
+    /// A read past the end of the container header, or of a chunk.
+    UnexpectedEnd {
+        chunk: Option<FourCC>,
+        offset: usize,
+    },
+
+    /// A string is not null-terminated or not valid UTF-8.
+    InvalidString {
+        chunk: Option<FourCC>,
+        offset: usize,
+    },
+
+    /// A field holds a value that does not correspond to any variant of
+    /// the parser's enums.
+    InvalidValue {
+        chunk: Option<FourCC>,
+        field: &'static str,
+        value: u32,
+    },
+
+    /// The contents of a chunk are inconsistent.
+    Malformed {
+        chunk: Option<FourCC>,
+        reason: &'static str,
+    },
+
+    /// The container lacks a chunk required for the operation.
+    MissingChunk(FourCC),
+
+    /// An instruction of the shader code could not be decoded. The offset
+    /// counts tokens from the first instruction.
+    InvalidInstruction { offset: usize, reason: &'static str },
+}
+
+struct Location(Option<FourCC>);
+
+impl fmt::Display for Location {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Some(fourcc) => write!(fmt, "{} chunk", fourcc),
+            None => write!(fmt, "container header"),
+        }
+    }
+}
+
+struct Hex<'a>(&'a [u8]);
+
+impl<'a> fmt::Display for Hex<'a> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for byte in self.0 {
+            write!(fmt, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for DxbcError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DxbcError::BadMagic => write!(fmt, "the data is not a DXBC container"),
+            DxbcError::UnsupportedVersion(version) => {
+                write!(fmt, "unsupported DXBC container version {}", version)
+            }
+            DxbcError::SizeMismatch { header, actual } => write!(
+                fmt,
+                "the container header gives a size of {} bytes, but the data is {} bytes",
+                header, actual
+            ),
+            DxbcError::ChecksumMismatch { stored, computed } => write!(
+                fmt,
+                "the container checksum is {} but the contents hash to {}",
+                Hex(&stored),
+                Hex(&computed)
+            ),
+            DxbcError::ChunkOutOfBounds { index, offset } => write!(
+                fmt,
+                "chunk {} at offset {} extends past the end of the container",
+                index, offset
+            ),
+            DxbcError::UnexpectedEnd { chunk, offset } => write!(
+                fmt,
+                "unexpected end of the {} reading offset {}",
+                Location(chunk),
+                offset
+            ),
+            DxbcError::InvalidString { chunk, offset } => write!(
+                fmt,
+                "the string at offset {} of the {} is unterminated or not UTF-8",
+                offset,
+                Location(chunk)
+            ),
+            DxbcError::InvalidValue {
+                chunk,
+                field,
+                value,
+            } => write!(
+                fmt,
+                "invalid {} {} in the {}",
+                field,
+                value,
+                Location(chunk)
+            ),
+            DxbcError::Malformed { chunk, reason } => {
+                write!(fmt, "malformed {}: {}", Location(chunk), reason)
+            }
+            DxbcError::MissingChunk(fourcc) => {
+                write!(fmt, "the container has no {} chunk", fourcc)
+            }
+            DxbcError::InvalidInstruction { offset, reason } => {
+                write!(fmt, "invalid instruction at token {}: {}", offset, reason)
+            }
+        }
+    }
+}
+
+impl StdError for DxbcError {}
@@ -0,0 +1,43 @@
+use crate::dxbc::{Chunk, DxbcError};
+
+use std::ops::BitOr;
+
+/// The optional features a shader requires of the device, from the `SFI0`
+/// chunk. Mirrors the `D3D_SHADER_REQUIRES_*` flags.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ShaderFeatures(pub u64);
+
+impl ShaderFeatures {
+    pub const NONE: ShaderFeatures = ShaderFeatures(0);
+    pub const DOUBLES: ShaderFeatures = ShaderFeatures(0x1);
+    pub const EARLY_DEPTH_STENCIL: ShaderFeatures = ShaderFeatures(0x2);
+    pub const UAVS_AT_EVERY_STAGE: ShaderFeatures = ShaderFeatures(0x4);
+    pub const UAVS_64: ShaderFeatures = ShaderFeatures(0x8);
+    pub const MINIMUM_PRECISION: ShaderFeatures = ShaderFeatures(0x10);
+    pub const DOUBLE_EXTENSIONS_11_1: ShaderFeatures = ShaderFeatures(0x20);
+    pub const SHADER_EXTENSIONS_11_1: ShaderFeatures = ShaderFeatures(0x40);
+    pub const LEVEL_9_COMPARISON_FILTERING: ShaderFeatures = ShaderFeatures(0x80);
+    pub const TILED_RESOURCES: ShaderFeatures = ShaderFeatures(0x100);
+    pub const STENCIL_REF: ShaderFeatures = ShaderFeatures(0x200);
+    pub const INNER_COVERAGE: ShaderFeatures = ShaderFeatures(0x400);
+    pub const TYPED_UAV_LOAD_ADDITIONAL_FORMATS: ShaderFeatures = ShaderFeatures(0x800);
+    pub const ROVS: ShaderFeatures = ShaderFeatures(0x1000);
+    pub const VIEWPORT_AND_RT_ARRAY_INDEX_FROM_ANY_SHADER: ShaderFeatures = ShaderFeatures(0x2000);
+
+    /// Whether all of the features in `flag` are required.
+    pub fn is_set(&self, flag: ShaderFeatures) -> bool {
+        self.0 & flag.0 == flag.0
+    }
+
+    pub fn parse(chunk: &Chunk) -> Result<ShaderFeatures, DxbcError> {
+        Ok(ShaderFeatures(chunk.reader().u64(0)?))
+    }
+}
+
+impl BitOr for ShaderFeatures {
+    type Output = ShaderFeatures;
+
+    fn bitor(self, rhs: ShaderFeatures) -> ShaderFeatures {
+        ShaderFeatures(self.0 | rhs.0)
+    }
+}
@@ -0,0 +1,272 @@
+//! Parsing of DXBC containers, the format of compiled shader bytecode.
+//!
+//! A container is a header followed by chunks identified by a four character
+//! code. The chunks describing the shader's resources (`RDEF`), signatures
+//! (`ISGN`, `OSGN`, `PCSG` and their variants), code (`SHEX`/`SHDR`),
+//! statistics (`STAT`) and required features (`SFI0`) can be parsed into
+//! typed structures; other chunks are exposed as raw bytes.
+//! [`ShaderReflection`] combines them into what `ID3D11ShaderReflection`
+//! reports, [`ContainerWriter`] rewrites containers with chunks removed or
+//! replaced, and [`disassemble`] lists the shader code.
+//!
+//! Unlike the rest of the crate, this module does not depend on Direct3D and
+//! is available on every platform.
+
+pub use self::checksum::checksum;
+pub use self::disasm::disassemble;
+pub use self::error::DxbcError;
+pub use self::features::ShaderFeatures;
+pub use self::program::{Instructions, Program, ProgramType, ShaderVersion};
+pub use self::rdef::{
+    ConstantBuffer, Member, ResourceBinding, ResourceDefinitions, Type, Variable,
+};
+pub use self::reflection::ShaderReflection;
+pub use self::signature::{ComponentType, Signature, SignatureElement, SystemValue};
+pub use self::stat::Statistics;
+pub use self::writer::{strip, ContainerWriter, StripFlags};
+
+pub mod checksum;
+pub mod disasm;
+pub mod error;
+pub mod features;
+pub mod program;
+pub mod rdef;
+pub mod reflection;
+pub mod signature;
+pub mod stat;
+pub mod writer;
+
+mod reader;
+
+use self::reader::Reader;
+
+use std::fmt;
+
+/// The four character code identifying a chunk.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FourCC(pub [u8; 4]);
+
+impl FourCC {
+    /// Resource definitions, used for reflection.
+    pub const RDEF: FourCC = FourCC(*b"RDEF");
+    /// Input signature.
+    pub const ISGN: FourCC = FourCC(*b"ISGN");
+    /// Input signature with streams and minimum precision.
+    pub const ISG1: FourCC = FourCC(*b"ISG1");
+    /// Output signature.
+    pub const OSGN: FourCC = FourCC(*b"OSGN");
+    /// Output signature with streams, used by geometry shaders.
+    pub const OSG5: FourCC = FourCC(*b"OSG5");
+    /// Output signature with streams and minimum precision.
+    pub const OSG1: FourCC = FourCC(*b"OSG1");
+    /// Patch constant signature of hull and domain shaders.
+    pub const PCSG: FourCC = FourCC(*b"PCSG");
+    /// Patch constant signature with streams and minimum precision.
+    pub const PSG1: FourCC = FourCC(*b"PSG1");
+    /// Shader model 4 code.
+    pub const SHDR: FourCC = FourCC(*b"SHDR");
+    /// Shader model 5 code.
+    pub const SHEX: FourCC = FourCC(*b"SHEX");
+    /// Statistics.
+    pub const STAT: FourCC = FourCC(*b"STAT");
+    /// Features the shader requires of the device.
+    pub const SFI0: FourCC = FourCC(*b"SFI0");
+    /// Interfaces, for shaders using dynamic linkage.
+    pub const IFCE: FourCC = FourCC(*b"IFCE");
+    /// Debug information.
+    pub const SDBG: FourCC = FourCC(*b"SDBG");
+    /// Debug information in PDB format.
+    pub const SPDB: FourCC = FourCC(*b"SPDB");
+    /// Debug information as DXIL.
+    pub const ILDB: FourCC = FourCC(*b"ILDB");
+    /// The name of the PDB for the debug information.
+    pub const ILDN: FourCC = FourCC(*b"ILDN");
+    /// Private data added with `D3DSetBlobPart`.
+    pub const PRIV: FourCC = FourCC(*b"PRIV");
+    /// Shader model 2 code for feature level 9 devices.
+    pub const AON9: FourCC = FourCC(*b"Aon9");
+}
+
+impl fmt::Display for FourCC {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        for &byte in &self.0 {
+            if byte.is_ascii_graphic() {
+                write!(fmt, "{}", byte as char)?;
+            } else {
+                write!(fmt, "\\x{:02x}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FourCC {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "FourCC(\"{}\")", self)
+    }
+}
+
+/// One chunk of a container.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Chunk<'a> {
+    pub fourcc: FourCC,
+    /// The contents of the chunk, without its header.
+    pub data: &'a [u8],
+}
+
+impl<'a> Chunk<'a> {
+    pub(crate) fn reader(&self) -> Reader<'a> {
+        Reader::new(self.data, Some(self.fourcc))
+    }
+}
+
+/// A parsed DXBC container, borrowing the bytecode it was parsed from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Container<'a> {
+    data: &'a [u8],
+    checksum: [u8; 16],
+    chunks: Vec<Chunk<'a>>,
+}
+
+/// The size of the header before the chunk offsets.
+const HEADER_SIZE: usize = 32;
+
+impl<'a> Container<'a> {
+    /// Parses the header and splits the container into chunks. The chunks
+    /// themselves are parsed on access, and the checksum is only checked by
+    /// `verify_checksum`.
+    pub fn parse(data: &'a [u8]) -> Result<Container<'a>, DxbcError> {
+        let reader = Reader::new(data, None);
+        if reader.bytes(0, 4)? != b"DXBC" {
+            return Err(DxbcError::BadMagic);
+        }
+        let mut checksum = [0; 16];
+        checksum.copy_from_slice(reader.bytes(4, 16)?);
+        let version = reader.u32(20)?;
+        if version != 1 {
+            return Err(DxbcError::UnsupportedVersion(version));
+        }
+        let size = reader.u32(24)?;
+        if size as usize != data.len() {
+            return Err(DxbcError::SizeMismatch {
+                header: size,
+                actual: data.len(),
+            });
+        }
+
+        let count = reader.u32(28)?;
+        let offsets = reader.u32s(HEADER_SIZE, count as usize)?;
+        let mut chunks = Vec::with_capacity(offsets.len());
+        for (index, &offset) in offsets.iter().enumerate() {
+            let out_of_bounds = || DxbcError::ChunkOutOfBounds {
+                index: index as u32,
+                offset,
+            };
+            let offset = offset as usize;
+            let mut fourcc = [0; 4];
+            fourcc.copy_from_slice(reader.bytes(offset, 4).map_err(|_| out_of_bounds())?);
+            let len = reader.u32(offset + 4).map_err(|_| out_of_bounds())?;
+            let data = reader
+                .bytes(offset + 8, len as usize)
+                .map_err(|_| out_of_bounds())?;
+            chunks.push(Chunk {
+                fourcc: FourCC(fourcc),
+                data,
+            });
+        }
+
+        Ok(Container {
+            data,
+            checksum,
+            chunks,
+        })
+    }
+
+    /// The whole container, as passed to `parse`.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The checksum stored in the header.
+    pub fn checksum(&self) -> [u8; 16] {
+        self.checksum
+    }
+
+    /// Checks the stored checksum against the contents of the container.
+    /// The runtime refuses to create shaders from containers that fail
+    /// this check.
+    pub fn verify_checksum(&self) -> Result<(), DxbcError> {
+        let computed = checksum(self.data);
+        if computed != self.checksum {
+            return Err(DxbcError::ChecksumMismatch {
+                stored: self.checksum,
+                computed,
+            });
+        }
+        Ok(())
+    }
+
+    /// The chunks in the order they appear in the container.
+    pub fn chunks(&self) -> &[Chunk<'a>] {
+        &self.chunks
+    }
+
+    /// The first chunk with the given code.
+    pub fn chunk(&self, fourcc: FourCC) -> Option<&Chunk<'a>> {
+        self.chunks.iter().find(|chunk| chunk.fourcc == fourcc)
+    }
+
+    /// The first chunk with any of the given codes, in order of preference.
+    fn any_chunk(&self, fourccs: &[FourCC]) -> Option<&Chunk<'a>> {
+        fourccs
+            .iter()
+            .filter_map(|&fourcc| self.chunk(fourcc))
+            .next()
+    }
+
+    /// The resource definitions, if the container has not been stripped of
+    /// them.
+    pub fn resource_definitions(&self) -> Result<Option<ResourceDefinitions<'a>>, DxbcError> {
+        self.chunk(FourCC::RDEF)
+            .map(ResourceDefinitions::parse)
+            .transpose()
+    }
+
+    pub fn input_signature(&self) -> Result<Option<Signature<'a>>, DxbcError> {
+        self.any_chunk(&[FourCC::ISG1, FourCC::ISGN])
+            .map(Signature::parse)
+            .transpose()
+    }
+
+    pub fn output_signature(&self) -> Result<Option<Signature<'a>>, DxbcError> {
+        self.any_chunk(&[FourCC::OSG1, FourCC::OSG5, FourCC::OSGN])
+            .map(Signature::parse)
+            .transpose()
+    }
+
+    pub fn patch_constant_signature(&self) -> Result<Option<Signature<'a>>, DxbcError> {
+        self.any_chunk(&[FourCC::PSG1, FourCC::PCSG])
+            .map(Signature::parse)
+            .transpose()
+    }
+
+    /// The shader code, from the `SHEX` or `SHDR` chunk.
+    pub fn program(&self) -> Result<Option<Program>, DxbcError> {
+        self.any_chunk(&[FourCC::SHEX, FourCC::SHDR])
+            .map(Program::parse)
+            .transpose()
+    }
+
+    pub fn statistics(&self) -> Result<Option<Statistics>, DxbcError> {
+        self.chunk(FourCC::STAT).map(Statistics::parse).transpose()
+    }
+
+    /// The features required of the device. Containers without an `SFI0`
+    /// chunk require none.
+    pub fn features(&self) -> Result<ShaderFeatures, DxbcError> {
+        match self.chunk(FourCC::SFI0) {
+            Some(chunk) => ShaderFeatures::parse(chunk),
+            None => Ok(ShaderFeatures::NONE),
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use crate::dxbc::{Chunk, DxbcError};
+
+use std::fmt;
+
+/// The pipeline stage a shader was compiled for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ProgramType {
+    Pixel,
+    Vertex,
+    Geometry,
+    Hull,
+    Domain,
+    Compute,
+}
+
+impl ProgramType {
+    /// The program type in the version token of the shader code.
+    pub fn from_raw(value: u32) -> Option<ProgramType> {
+        Some(match value {
+            0 => ProgramType::Pixel,
+            1 => ProgramType::Vertex,
+            2 => ProgramType::Geometry,
+            3 => ProgramType::Hull,
+            4 => ProgramType::Domain,
+            5 => ProgramType::Compute,
+            _ => return None,
+        })
+    }
+
+    /// The program type in the version of the resource definitions, which
+    /// follows the Direct3D 9 convention for vertex and pixel shaders.
+    pub fn from_rdef(value: u16) -> Option<ProgramType> {
+        Some(match value {
+            0xFFFF => ProgramType::Pixel,
+            0xFFFE => ProgramType::Vertex,
+            0x4753 => ProgramType::Geometry,
+            0x4853 => ProgramType::Hull,
+            0x4453 => ProgramType::Domain,
+            0x4353 => ProgramType::Compute,
+            _ => return None,
+        })
+    }
+
+    /// The prefix of the target profile, e.g. `vs` for vertex shaders.
+    pub fn prefix(self) -> &'static str {
+        match self {
+            ProgramType::Pixel => "ps",
+            ProgramType::Vertex => "vs",
+            ProgramType::Geometry => "gs",
+            ProgramType::Hull => "hs",
+            ProgramType::Domain => "ds",
+            ProgramType::Compute => "cs",
+        }
+    }
+}
+
+/// The target profile a shader was compiled for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ShaderVersion {
+    pub program_type: ProgramType,
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl ShaderVersion {
+    /// Decodes the version token at the start of the shader code.
+    pub fn from_token(token: u32) -> Option<ShaderVersion> {
+        Some(ShaderVersion {
+            program_type: ProgramType::from_raw(token >> 16)?,
+            major: (token >> 4 & 0xF) as u8,
+            minor: (token & 0xF) as u8,
+        })
+    }
+
+    /// Decodes the version in the header of the resource definitions.
+    pub fn from_rdef(value: u32) -> Option<ShaderVersion> {
+        Some(ShaderVersion {
+            program_type: ProgramType::from_rdef((value >> 16) as u16)?,
+            major: (value >> 8) as u8,
+            minor: value as u8,
+        })
+    }
+}
+
+/// Formats the version as a target profile, e.g. `vs_5_0`.
+impl fmt::Display for ShaderVersion {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "{}_{}_{}",
+            self.program_type.prefix(),
+            self.major,
+            self.minor
+        )
+    }
+}
+
+/// The code of a shader, from a `SHEX` or `SHDR` chunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Program {
+    pub version: ShaderVersion,
+    /// The instruction tokens following the version and length tokens.
+    pub tokens: Vec<u32>,
+}
+
+impl Program {
+    pub fn parse(chunk: &Chunk) -> Result<Program, DxbcError> {
+        let reader = chunk.reader();
+        let token = reader.u32(0)?;
+        let version = ShaderVersion::from_token(token)
+            .ok_or(reader.invalid_value("program type", token >> 16))?;
+        let len = reader.u32(4)? as usize;
+        if len < 2 {
+            return Err(reader.malformed("the program is shorter than its header"));
+        }
+        if len.saturating_mul(4) > reader.len() {
+            return Err(reader.malformed("the program is longer than its chunk"));
+        }
+        let tokens = reader.u32s(8, len - 2)?;
+        Ok(Program { version, tokens })
+    }
+
+    /// Splits the tokens into instructions, each starting with its opcode
+    /// token.
+    pub fn instructions(&self) -> Instructions<'_> {
+        Instructions {
+            tokens: &self.tokens,
+            offset: 0,
+        }
+    }
+}
+
+/// The opcode of the instructions holding their length in the second token.
+pub(crate) const OPCODE_CUSTOMDATA: u32 = 53;
+
+/// The instructions of a program, as slices of tokens. Yields an error and
+/// stops at an instruction with an invalid length.
+#[derive(Clone, Debug)]
+pub struct Instructions<'p> {
+    tokens: &'p [u32],
+    offset: usize,
+}
+
+impl<'p> Iterator for Instructions<'p> {
+    type Item = Result<&'p [u32], DxbcError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &token = self.tokens.first()?;
+        let len = if token & 0x7FF == OPCODE_CUSTOMDATA {
+            self.tokens.get(1).cloned().unwrap_or(0) as usize
+        } else {
+            (token >> 24 & 0x7F) as usize
+        };
+        if len == 0 || len > self.tokens.len() {
+            let offset = self.offset;
+            self.tokens = &[];
+            return Some(Err(DxbcError::InvalidInstruction {
+                offset,
+                reason: "the instruction length is zero or runs past the end of the program",
+            }));
+        }
+        let (instruction, rest) = self.tokens.split_at(len);
+        self.tokens = rest;
+        self.offset += len;
+        Some(Ok(instruction))
+    }
+}
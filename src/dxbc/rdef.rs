@@ -0,0 +1,315 @@
+//! The `RDEF` chunk, describing the constant buffers and resources a shader
+//! uses.
+//!
+//! Enumeration-like fields hold the raw values of the `D3D_*` enums.
+
+use crate::dxbc::reader::Reader;
+use crate::dxbc::{Chunk, DxbcError, ShaderVersion};
+
+/// How deeply struct types may nest before the chunk is considered
+/// malformed. Guards against cycles in the type offsets.
+const MAX_TYPE_DEPTH: u32 = 64;
+
+/// The sizes of the records in the chunk, which grew in shader model 5.
+#[derive(Copy, Clone)]
+struct Layout {
+    constant_buffer: usize,
+    binding: usize,
+    variable: usize,
+    ty: usize,
+    member: usize,
+}
+
+const SM4_LAYOUT: Layout = Layout {
+    constant_buffer: 24,
+    binding: 32,
+    variable: 24,
+    ty: 16,
+    member: 12,
+};
+
+/// The contents of an `RDEF` chunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceDefinitions<'a> {
+    pub version: ShaderVersion,
+    /// The `D3DCOMPILE_*` flags the shader was compiled with.
+    pub flags: u32,
+    /// The name of the compiler.
+    pub creator: &'a str,
+    pub constant_buffers: Vec<ConstantBuffer<'a>>,
+    pub bindings: Vec<ResourceBinding<'a>>,
+}
+
+/// A constant buffer, or a texture buffer, and its variables.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstantBuffer<'a> {
+    pub name: &'a str,
+    /// The raw `D3D_CBUFFER_TYPE`.
+    pub kind: u32,
+    /// The size in bytes.
+    pub size: u32,
+    /// The raw `D3D_SHADER_CBUFFER_FLAGS`.
+    pub flags: u32,
+    pub variables: Vec<Variable<'a>>,
+}
+
+/// A variable of a constant buffer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Variable<'a> {
+    pub name: &'a str,
+    /// The offset in bytes from the start of the constant buffer.
+    pub offset: u32,
+    /// The size in bytes.
+    pub size: u32,
+    /// The raw `D3D_SHADER_VARIABLE_FLAGS`.
+    pub flags: u32,
+    pub ty: Type<'a>,
+    pub default_value: Option<&'a [u8]>,
+    /// The first texture slot used by the variable, or `u32::MAX` if it
+    /// uses none. Only recorded from shader model 5.
+    pub start_texture: u32,
+    pub texture_size: u32,
+    /// The first sampler slot used by the variable, or `u32::MAX` if it
+    /// uses none. Only recorded from shader model 5.
+    pub start_sampler: u32,
+    pub sampler_size: u32,
+}
+
+/// The type of a variable or struct member.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Type<'a> {
+    /// The raw `D3D_SHADER_VARIABLE_CLASS`.
+    pub class: u16,
+    /// The raw `D3D_SHADER_VARIABLE_TYPE`.
+    pub base: u16,
+    pub rows: u16,
+    pub columns: u16,
+    /// The number of array elements, or 0 if the type is not an array.
+    pub elements: u16,
+    /// The name of the type. Only recorded from shader model 5.
+    pub name: Option<&'a str>,
+    pub members: Vec<Member<'a>>,
+}
+
+/// A member of a struct type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member<'a> {
+    pub name: &'a str,
+    /// The offset in bytes from the start of the struct.
+    pub offset: u32,
+    pub ty: Type<'a>,
+}
+
+/// A resource bound to the shader: a constant buffer, texture, sampler or
+/// unordered access view.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ResourceBinding<'a> {
+    pub name: &'a str,
+    /// The raw `D3D_SHADER_INPUT_TYPE`.
+    pub input_type: u32,
+    /// The raw `D3D_RESOURCE_RETURN_TYPE`, or 0 if the resource has none.
+    pub return_type: u32,
+    /// The raw `D3D_SRV_DIMENSION`.
+    pub dimension: u32,
+    /// The sample count of multisampled textures, `u32::MAX` for other
+    /// textures and 0 for other resources.
+    pub sample_count: u32,
+    pub bind_point: u32,
+    pub bind_count: u32,
+    /// The raw `D3D_SHADER_INPUT_FLAGS`.
+    pub flags: u32,
+    /// The register space. Only recorded from shader model 5.1.
+    pub space: u32,
+}
+
+impl<'a> ResourceDefinitions<'a> {
+    pub fn parse(chunk: &Chunk<'a>) -> Result<ResourceDefinitions<'a>, DxbcError> {
+        let reader = chunk.reader();
+        let cb_count = reader.u32(0)? as usize;
+        let cb_offset = reader.u32(4)? as usize;
+        let binding_count = reader.u32(8)? as usize;
+        let binding_offset = reader.u32(12)? as usize;
+        let raw_version = reader.u32(16)?;
+        let version = ShaderVersion::from_rdef(raw_version)
+            .ok_or(reader.invalid_value("program type", raw_version >> 16))?;
+        let flags = reader.u32(20)?;
+        let creator = reader.str(reader.u32(24)? as usize)?;
+
+        let layout = if version.major >= 5 {
+            if reader.bytes(28, 4)? != b"RD11" {
+                return Err(reader.malformed("missing the shader model 5 header"));
+            }
+            Layout {
+                constant_buffer: reader.u32(36)? as usize,
+                binding: reader.u32(40)? as usize,
+                variable: reader.u32(44)? as usize,
+                ty: reader.u32(48)? as usize,
+                member: reader.u32(52)? as usize,
+            }
+        } else {
+            SM4_LAYOUT
+        };
+        if layout.constant_buffer < SM4_LAYOUT.constant_buffer
+            || layout.binding < SM4_LAYOUT.binding
+            || layout.variable < SM4_LAYOUT.variable
+            || layout.ty < SM4_LAYOUT.ty
+            || layout.member < SM4_LAYOUT.member
+        {
+            return Err(reader.malformed("record sizes are smaller than in shader model 4"));
+        }
+        let parser = Parser { reader, layout };
+
+        reader.bytes(cb_offset, cb_count.saturating_mul(layout.constant_buffer))?;
+        let constant_buffers = (0..cb_count)
+            .map(|i| parser.constant_buffer(cb_offset + i * layout.constant_buffer))
+            .collect::<Result<_, _>>()?;
+
+        reader.bytes(binding_offset, binding_count.saturating_mul(layout.binding))?;
+        let bindings = (0..binding_count)
+            .map(|i| parser.binding(binding_offset + i * layout.binding))
+            .collect::<Result<_, _>>()?;
+
+        Ok(ResourceDefinitions {
+            version,
+            flags,
+            creator,
+            constant_buffers,
+            bindings,
+        })
+    }
+
+    pub fn constant_buffer(&self, name: &str) -> Option<&ConstantBuffer<'a>> {
+        self.constant_buffers.iter().find(|cb| cb.name == name)
+    }
+
+    pub fn binding(&self, name: &str) -> Option<&ResourceBinding<'a>> {
+        self.bindings.iter().find(|b| b.name == name)
+    }
+}
+
+impl<'a> ConstantBuffer<'a> {
+    pub fn variable(&self, name: &str) -> Option<&Variable<'a>> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+}
+
+struct Parser<'a> {
+    reader: Reader<'a>,
+    layout: Layout,
+}
+
+impl<'a> Parser<'a> {
+    fn name(&self, offset: usize) -> Result<&'a str, DxbcError> {
+        self.reader.str(self.reader.u32(offset)? as usize)
+    }
+
+    fn constant_buffer(&self, offset: usize) -> Result<ConstantBuffer<'a>, DxbcError> {
+        let r = &self.reader;
+        let count = r.u32(offset + 4)? as usize;
+        let first = r.u32(offset + 8)? as usize;
+        r.bytes(first, count.saturating_mul(self.layout.variable))?;
+        let variables = (0..count)
+            .map(|i| self.variable(first + i * self.layout.variable))
+            .collect::<Result<_, _>>()?;
+        Ok(ConstantBuffer {
+            name: self.name(offset)?,
+            size: r.u32(offset + 12)?,
+            flags: r.u32(offset + 16)?,
+            kind: r.u32(offset + 20)?,
+            variables,
+        })
+    }
+
+    fn variable(&self, offset: usize) -> Result<Variable<'a>, DxbcError> {
+        let r = &self.reader;
+        let size = r.u32(offset + 8)?;
+        let default_offset = r.u32(offset + 20)? as usize;
+        let default_value = match default_offset {
+            0 => None,
+            _ => Some(r.bytes(default_offset, size as usize)?),
+        };
+        // Shader model 4 does not record the slots used by the variable.
+        let (start_texture, texture_size, start_sampler, sampler_size) =
+            if self.layout.variable >= 40 {
+                (
+                    r.u32(offset + 24)?,
+                    r.u32(offset + 28)?,
+                    r.u32(offset + 32)?,
+                    r.u32(offset + 36)?,
+                )
+            } else {
+                (u32::MAX, 0, u32::MAX, 0)
+            };
+        Ok(Variable {
+            name: self.name(offset)?,
+            offset: r.u32(offset + 4)?,
+            size,
+            flags: r.u32(offset + 12)?,
+            ty: self.ty(r.u32(offset + 16)? as usize, 0)?,
+            default_value,
+            start_texture,
+            texture_size,
+            start_sampler,
+            sampler_size,
+        })
+    }
+
+    fn ty(&self, offset: usize, depth: u32) -> Result<Type<'a>, DxbcError> {
+        if depth > MAX_TYPE_DEPTH {
+            return Err(self.reader.malformed("struct types are nested too deeply"));
+        }
+        let r = &self.reader;
+        let member_count = r.u16(offset + 10)? as usize;
+        let member_offset = r.u32(offset + 12)? as usize;
+        let name = if self.layout.ty >= 36 {
+            match r.u32(offset + 32)? {
+                0 => None,
+                name => Some(r.str(name as usize)?),
+            }
+        } else {
+            None
+        };
+
+        let size = self.layout.member;
+        r.bytes(member_offset, member_count.saturating_mul(size))?;
+        let members = (0..member_count)
+            .map(|i| {
+                let offset = member_offset + i * size;
+                Ok(Member {
+                    name: self.name(offset)?,
+                    ty: self.ty(r.u32(offset + 4)? as usize, depth + 1)?,
+                    offset: r.u32(offset + 8)?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Type {
+            class: r.u16(offset)?,
+            base: r.u16(offset + 2)?,
+            rows: r.u16(offset + 4)?,
+            columns: r.u16(offset + 6)?,
+            elements: r.u16(offset + 8)?,
+            name,
+            members,
+        })
+    }
+
+    fn binding(&self, offset: usize) -> Result<ResourceBinding<'a>, DxbcError> {
+        let r = &self.reader;
+        Ok(ResourceBinding {
+            name: self.name(offset)?,
+            input_type: r.u32(offset + 4)?,
+            return_type: r.u32(offset + 8)?,
+            dimension: r.u32(offset + 12)?,
+            sample_count: r.u32(offset + 16)?,
+            bind_point: r.u32(offset + 20)?,
+            bind_count: r.u32(offset + 24)?,
+            flags: r.u32(offset + 28)?,
+            space: if self.layout.binding >= 40 {
+                r.u32(offset + 32)?
+            } else {
+                0
+            },
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::dxbc::{DxbcError, FourCC};
+
+use std::str;
+
+/// Bounds-checked little-endian reads from a container or chunk.
+#[derive(Copy, Clone)]
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    chunk: Option<FourCC>,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8], chunk: Option<FourCC>) -> Self {
+        Reader { data, chunk }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], DxbcError> {
+        offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or(DxbcError::UnexpectedEnd {
+                chunk: self.chunk,
+                offset,
+            })
+    }
+
+    pub fn u8(&self, offset: usize) -> Result<u8, DxbcError> {
+        Ok(self.bytes(offset, 1)?[0])
+    }
+
+    pub fn u16(&self, offset: usize) -> Result<u16, DxbcError> {
+        let b = self.bytes(offset, 2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    pub fn u32(&self, offset: usize) -> Result<u32, DxbcError> {
+        let b = self.bytes(offset, 4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    pub fn u64(&self, offset: usize) -> Result<u64, DxbcError> {
+        Ok(self.u32(offset)? as u64 | (self.u32(offset + 4)? as u64) << 32)
+    }
+
+    /// `count` consecutive u32s. The whole range is checked before anything
+    /// is allocated.
+    pub fn u32s(&self, offset: usize, count: usize) -> Result<Vec<u32>, DxbcError> {
+        let len = count.checked_mul(4).ok_or(DxbcError::UnexpectedEnd {
+            chunk: self.chunk,
+            offset,
+        })?;
+        let bytes = self.bytes(offset, len)?;
+        Ok(bytes
+            .chunks_exact(4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+
+    /// A null-terminated UTF-8 string.
+    pub fn str(&self, offset: usize) -> Result<&'a str, DxbcError> {
+        let invalid = DxbcError::InvalidString {
+            chunk: self.chunk,
+            offset,
+        };
+        let rest = self.data.get(offset..).ok_or(invalid.clone())?;
+        let len = rest.iter().position(|&b| b == 0).ok_or(invalid.clone())?;
+        str::from_utf8(&rest[..len]).map_err(|_| invalid)
+    }
+
+    /// The error for a field holding a value the parser does not know.
+    pub fn invalid_value(&self, field: &'static str, value: u32) -> DxbcError {
+        DxbcError::InvalidValue {
+            chunk: self.chunk,
+            field,
+            value,
+        }
+    }
+
+    pub fn malformed(&self, reason: &'static str) -> DxbcError {
+        DxbcError::Malformed {
+            chunk: self.chunk,
+            reason,
+        }
+    }
+}
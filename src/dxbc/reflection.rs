@@ -0,0 +1,527 @@
+//! Shader reflection built from the chunks of a container, reporting what
+//! `ID3D11ShaderReflection` does without needing `D3DReflect`.
+//!
+//! The raw values of the resource definitions are decoded into the enums
+//! below when the reflection is created, so that a malformed container is
+//! rejected up front rather than on access.
+
+use crate::dxbc::{
+    Container, DxbcError, FourCC, ProgramType, ResourceDefinitions, ShaderFeatures, ShaderVersion,
+    Signature, SignatureElement, Statistics, SystemValue,
+};
+
+/// The opcode of `dcl_thread_group`.
+const OPCODE_DCL_THREAD_GROUP: u32 = 155;
+
+macro_rules! raw_enum {
+    ($(#[$attr:meta])* pub enum $name:ident { $($variant:ident = $value:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            pub fn from_raw(value: u32) -> Option<$name> {
+                Some(match value {
+                    $($value => $name::$variant,)*
+                    _ => return None,
+                })
+            }
+
+            pub fn to_raw(self) -> u32 {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+    };
+}
+
+raw_enum! {
+    /// The kind of a constant buffer. Mirrors `D3D_CBUFFER_TYPE`.
+    pub enum CBufferType {
+        CBuffer = 0,
+        TBuffer = 1,
+        InterfacePointers = 2,
+        ResourceBindInfo = 3,
+    }
+}
+
+raw_enum! {
+    /// How a type is laid out. Mirrors `D3D_SHADER_VARIABLE_CLASS`.
+    pub enum VariableClass {
+        Scalar = 0,
+        Vector = 1,
+        MatrixRows = 2,
+        MatrixColumns = 3,
+        Object = 4,
+        Struct = 5,
+        InterfaceClass = 6,
+        InterfacePointer = 7,
+    }
+}
+
+raw_enum! {
+    /// The base type of a variable. Mirrors `D3D_SHADER_VARIABLE_TYPE`.
+    pub enum VariableType {
+        Void = 0,
+        Bool = 1,
+        Int = 2,
+        Float = 3,
+        String = 4,
+        Texture = 5,
+        Texture1D = 6,
+        Texture2D = 7,
+        Texture3D = 8,
+        TextureCube = 9,
+        Sampler = 10,
+        Sampler1D = 11,
+        Sampler2D = 12,
+        Sampler3D = 13,
+        SamplerCube = 14,
+        PixelShader = 15,
+        VertexShader = 16,
+        PixelFragment = 17,
+        VertexFragment = 18,
+        UInt = 19,
+        UInt8 = 20,
+        GeometryShader = 21,
+        Rasterizer = 22,
+        DepthStencil = 23,
+        Blend = 24,
+        Buffer = 25,
+        CBuffer = 26,
+        TBuffer = 27,
+        Texture1DArray = 28,
+        Texture2DArray = 29,
+        RenderTargetView = 30,
+        DepthStencilView = 31,
+        Texture2DMS = 32,
+        Texture2DMSArray = 33,
+        TextureCubeArray = 34,
+        HullShader = 35,
+        DomainShader = 36,
+        InterfacePointer = 37,
+        ComputeShader = 38,
+        Double = 39,
+        RWTexture1D = 40,
+        RWTexture1DArray = 41,
+        RWTexture2D = 42,
+        RWTexture2DArray = 43,
+        RWTexture3D = 44,
+        RWBuffer = 45,
+        ByteAddressBuffer = 46,
+        RWByteAddressBuffer = 47,
+        StructuredBuffer = 48,
+        RWStructuredBuffer = 49,
+        AppendStructuredBuffer = 50,
+        ConsumeStructuredBuffer = 51,
+        Min8Float = 52,
+        Min10Float = 53,
+        Min16Float = 54,
+        Min12Int = 55,
+        Min16Int = 56,
+        Min16UInt = 57,
+    }
+}
+
+raw_enum! {
+    /// The kind of a bound resource. Mirrors `D3D_SHADER_INPUT_TYPE`.
+    pub enum ShaderInputType {
+        CBuffer = 0,
+        TBuffer = 1,
+        Texture = 2,
+        Sampler = 3,
+        UavRwTyped = 4,
+        Structured = 5,
+        UavRwStructured = 6,
+        ByteAddress = 7,
+        UavRwByteAddress = 8,
+        UavAppendStructured = 9,
+        UavConsumeStructured = 10,
+        UavRwStructuredWithCounter = 11,
+    }
+}
+
+raw_enum! {
+    /// The type returned when reading a typed resource. Mirrors
+    /// `D3D_RESOURCE_RETURN_TYPE`.
+    pub enum ResourceReturnType {
+        Unorm = 1,
+        Snorm = 2,
+        SInt = 3,
+        UInt = 4,
+        Float = 5,
+        Mixed = 6,
+        Double = 7,
+        Continued = 8,
+    }
+}
+
+raw_enum! {
+    /// The dimension of a bound resource. Mirrors `D3D_SRV_DIMENSION`, which
+    /// reflection also uses for unordered access views.
+    pub enum SrvDimension {
+        Unknown = 0,
+        Buffer = 1,
+        Texture1D = 2,
+        Texture1DArray = 3,
+        Texture2D = 4,
+        Texture2DArray = 5,
+        Texture2DMS = 6,
+        Texture2DMSArray = 7,
+        Texture3D = 8,
+        TextureCube = 9,
+        TextureCubeArray = 10,
+        BufferEx = 11,
+    }
+}
+
+/// The summary of a shader. Mirrors `D3D11_SHADER_DESC`, with the
+/// instruction counts grouped in `statistics`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShaderDesc<'a> {
+    pub version: ShaderVersion,
+    pub creator: &'a str,
+    /// The `D3DCOMPILE_*` flags the shader was compiled with.
+    pub flags: u32,
+    pub constant_buffers: u32,
+    pub bound_resources: u32,
+    pub input_parameters: u32,
+    pub output_parameters: u32,
+    pub patch_constant_parameters: u32,
+    /// All zero if the container has no `STAT` chunk.
+    pub statistics: Statistics,
+}
+
+/// A type of a variable or struct member. Mirrors `D3D11_SHADER_TYPE_DESC`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShaderType<'a> {
+    pub class: VariableClass,
+    pub ty: VariableType,
+    pub rows: u32,
+    pub columns: u32,
+    /// The number of array elements, or 0 if the type is not an array.
+    pub elements: u32,
+    /// The offset in bytes from the start of the parent struct, or 0 for
+    /// the type of a variable.
+    pub offset: u32,
+    /// The name of the type. Only recorded from shader model 5.
+    pub name: Option<&'a str>,
+    pub members: Vec<ShaderTypeMember<'a>>,
+}
+
+/// A named member of a struct type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShaderTypeMember<'a> {
+    pub name: &'a str,
+    pub ty: ShaderType<'a>,
+}
+
+/// A variable of a constant buffer. Mirrors `D3D11_SHADER_VARIABLE_DESC`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VariableReflection<'a> {
+    pub name: &'a str,
+    /// The offset in bytes from the start of the constant buffer.
+    pub start_offset: u32,
+    /// The size in bytes.
+    pub size: u32,
+    /// The raw `D3D_SHADER_VARIABLE_FLAGS`.
+    pub flags: u32,
+    pub default_value: Option<&'a [u8]>,
+    /// The first texture slot used by the variable, or `u32::MAX` if it
+    /// uses none.
+    pub start_texture: u32,
+    pub texture_size: u32,
+    /// The first sampler slot used by the variable, or `u32::MAX` if it
+    /// uses none.
+    pub start_sampler: u32,
+    pub sampler_size: u32,
+    pub ty: ShaderType<'a>,
+}
+
+/// A constant buffer and its variables. Mirrors `D3D11_SHADER_BUFFER_DESC`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConstantBufferReflection<'a> {
+    pub name: &'a str,
+    pub kind: CBufferType,
+    /// The size in bytes.
+    pub size: u32,
+    /// The raw `D3D_SHADER_CBUFFER_FLAGS`.
+    pub flags: u32,
+    pub variables: Vec<VariableReflection<'a>>,
+}
+
+impl<'a> ConstantBufferReflection<'a> {
+    pub fn variable(&self, name: &str) -> Option<&VariableReflection<'a>> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+}
+
+/// A resource bound to the shader. Mirrors `D3D11_SHADER_INPUT_BIND_DESC`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InputBindDesc<'a> {
+    pub name: &'a str,
+    pub input_type: ShaderInputType,
+    pub bind_point: u32,
+    pub bind_count: u32,
+    /// The raw `D3D_SHADER_INPUT_FLAGS`.
+    pub flags: u32,
+    /// `None` for resources that are not read as typed values, such as
+    /// constant buffers and samplers.
+    pub return_type: Option<ResourceReturnType>,
+    pub dimension: SrvDimension,
+    /// The sample count of multisampled textures. `None` for other
+    /// resources.
+    pub num_samples: Option<u32>,
+}
+
+/// The reflection of a shader, borrowing its bytecode.
+#[derive(Clone, Debug)]
+pub struct ShaderReflection<'a> {
+    container: Container<'a>,
+    rdef: ResourceDefinitions<'a>,
+    constant_buffers: Vec<ConstantBufferReflection<'a>>,
+    bindings: Vec<InputBindDesc<'a>>,
+    inputs: Vec<SignatureElement<'a>>,
+    outputs: Vec<SignatureElement<'a>>,
+    patch_constants: Vec<SignatureElement<'a>>,
+    statistics: Statistics,
+    features: ShaderFeatures,
+    thread_group_size: Option<[u32; 3]>,
+}
+
+impl<'a> ShaderReflection<'a> {
+    /// Parses the chunks used for reflection. Fails if the container has
+    /// been stripped of its resource definitions.
+    pub fn new(bytecode: &'a [u8]) -> Result<ShaderReflection<'a>, DxbcError> {
+        let container = Container::parse(bytecode)?;
+        let rdef = container
+            .resource_definitions()?
+            .ok_or(DxbcError::MissingChunk(FourCC::RDEF))?;
+        let program_type = rdef.version.program_type;
+
+        let constant_buffers = rdef
+            .constant_buffers
+            .iter()
+            .map(|cb| {
+                Ok(ConstantBufferReflection {
+                    name: cb.name,
+                    kind: CBufferType::from_raw(cb.kind)
+                        .ok_or(invalid_value("constant buffer type", cb.kind))?,
+                    size: cb.size,
+                    flags: cb.flags,
+                    variables: cb
+                        .variables
+                        .iter()
+                        .map(|v| {
+                            Ok(VariableReflection {
+                                name: v.name,
+                                start_offset: v.offset,
+                                size: v.size,
+                                flags: v.flags,
+                                default_value: v.default_value,
+                                start_texture: v.start_texture,
+                                texture_size: v.texture_size,
+                                start_sampler: v.start_sampler,
+                                sampler_size: v.sampler_size,
+                                ty: shader_type(&v.ty, 0)?,
+                            })
+                        })
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let bindings = rdef
+            .bindings
+            .iter()
+            .map(|b| {
+                Ok(InputBindDesc {
+                    name: b.name,
+                    input_type: ShaderInputType::from_raw(b.input_type)
+                        .ok_or(invalid_value("shader input type", b.input_type))?,
+                    bind_point: b.bind_point,
+                    bind_count: b.bind_count,
+                    flags: b.flags,
+                    return_type: match b.return_type {
+                        0 => None,
+                        raw => Some(
+                            ResourceReturnType::from_raw(raw)
+                                .ok_or(invalid_value("resource return type", raw))?,
+                        ),
+                    },
+                    dimension: SrvDimension::from_raw(b.dimension)
+                        .ok_or(invalid_value("resource dimension", b.dimension))?,
+                    num_samples: match b.sample_count {
+                        0 | u32::MAX => None,
+                        count => Some(count),
+                    },
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let elements = |signature: Option<Signature<'a>>| {
+            let mut elements = signature.map(|s| s.elements).unwrap_or_default();
+            if program_type == ProgramType::Pixel {
+                for element in &mut elements {
+                    infer_system_value(element);
+                }
+            }
+            elements
+        };
+        let inputs = elements(container.input_signature()?);
+        let outputs = elements(container.output_signature()?);
+        let patch_constants = elements(container.patch_constant_signature()?);
+
+        let mut thread_group_size = None;
+        if let Some(program) = container.program()? {
+            if program.version.program_type == ProgramType::Compute {
+                for instruction in program.instructions() {
+                    let instruction = instruction?;
+                    if instruction[0] & 0x7FF == OPCODE_DCL_THREAD_GROUP && instruction.len() == 4 {
+                        thread_group_size = Some([instruction[1], instruction[2], instruction[3]]);
+                        break;
+                    }
+                }
+            }
+        }
+
+        Ok(ShaderReflection {
+            statistics: container.statistics()?.unwrap_or_default(),
+            features: container.features()?,
+            container,
+            rdef,
+            constant_buffers,
+            bindings,
+            inputs,
+            outputs,
+            patch_constants,
+            thread_group_size,
+        })
+    }
+
+    /// The container the reflection was built from.
+    pub fn container(&self) -> &Container<'a> {
+        &self.container
+    }
+
+    pub fn desc(&self) -> ShaderDesc<'a> {
+        ShaderDesc {
+            version: self.rdef.version,
+            creator: self.rdef.creator,
+            flags: self.rdef.flags,
+            constant_buffers: self.constant_buffers.len() as u32,
+            bound_resources: self.bindings.len() as u32,
+            input_parameters: self.inputs.len() as u32,
+            output_parameters: self.outputs.len() as u32,
+            patch_constant_parameters: self.patch_constants.len() as u32,
+            statistics: self.statistics,
+        }
+    }
+
+    pub fn constant_buffers(&self) -> &[ConstantBufferReflection<'a>] {
+        &self.constant_buffers
+    }
+
+    pub fn constant_buffer_by_name(&self, name: &str) -> Option<&ConstantBufferReflection<'a>> {
+        self.constant_buffers.iter().find(|cb| cb.name == name)
+    }
+
+    /// Finds a variable in any of the constant buffers.
+    pub fn variable_by_name(&self, name: &str) -> Option<&VariableReflection<'a>> {
+        self.constant_buffers
+            .iter()
+            .filter_map(|cb| cb.variable(name))
+            .next()
+    }
+
+    pub fn resource_bindings(&self) -> &[InputBindDesc<'a>] {
+        &self.bindings
+    }
+
+    pub fn resource_binding_by_name(&self, name: &str) -> Option<&InputBindDesc<'a>> {
+        self.bindings.iter().find(|b| b.name == name)
+    }
+
+    /// The input signature. Pixel shader system values stored as
+    /// `Undefined`, such as `SV_Target`, are inferred from the semantic
+    /// name, as `ID3D11ShaderReflection` does.
+    pub fn input_parameters(&self) -> &[SignatureElement<'a>] {
+        &self.inputs
+    }
+
+    pub fn output_parameters(&self) -> &[SignatureElement<'a>] {
+        &self.outputs
+    }
+
+    pub fn patch_constant_parameters(&self) -> &[SignatureElement<'a>] {
+        &self.patch_constants
+    }
+
+    /// The features the shader requires of the device.
+    pub fn requires_flags(&self) -> ShaderFeatures {
+        self.features
+    }
+
+    /// The number of threads in a group of a compute shader. `None` for
+    /// other shaders, or if the container has been stripped of its code.
+    pub fn thread_group_size(&self) -> Option<[u32; 3]> {
+        self.thread_group_size
+    }
+}
+
+fn invalid_value(field: &'static str, value: u32) -> DxbcError {
+    DxbcError::InvalidValue {
+        chunk: Some(FourCC::RDEF),
+        field,
+        value,
+    }
+}
+
+fn shader_type<'a>(ty: &crate::dxbc::Type<'a>, offset: u32) -> Result<ShaderType<'a>, DxbcError> {
+    Ok(ShaderType {
+        class: VariableClass::from_raw(ty.class.into())
+            .ok_or(invalid_value("variable class", ty.class.into()))?,
+        ty: VariableType::from_raw(ty.base.into())
+            .ok_or(invalid_value("variable type", ty.base.into()))?,
+        rows: ty.rows.into(),
+        columns: ty.columns.into(),
+        elements: ty.elements.into(),
+        offset,
+        name: ty.name,
+        members: ty
+            .members
+            .iter()
+            .map(|member| {
+                Ok(ShaderTypeMember {
+                    name: member.name,
+                    ty: shader_type(&member.ty, member.offset)?,
+                })
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Fills in the system values of pixel shader elements that are only
+/// identified by their semantic name in the container.
+fn infer_system_value(element: &mut SignatureElement) {
+    if element.system_value != SystemValue::Undefined {
+        return;
+    }
+    let names = [
+        ("SV_Target", SystemValue::Target),
+        ("SV_Depth", SystemValue::Depth),
+        ("SV_Coverage", SystemValue::Coverage),
+        ("SV_DepthGreaterEqual", SystemValue::DepthGreaterEqual),
+        ("SV_DepthLessEqual", SystemValue::DepthLessEqual),
+        ("SV_StencilRef", SystemValue::StencilRef),
+        ("SV_InnerCoverage", SystemValue::InnerCoverage),
+    ];
+    if let Some(&(_, value)) = names
+        .iter()
+        .find(|(name, _)| element.semantic_name.eq_ignore_ascii_case(name))
+    {
+        element.system_value = value;
+    }
+}
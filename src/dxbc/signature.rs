@@ -0,0 +1,182 @@
+use crate::dxbc::{Chunk, DxbcError, FourCC};
+
+/// The type of the components of a signature element.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ComponentType {
+    Unknown,
+    UInt32,
+    SInt32,
+    Float32,
+}
+
+impl ComponentType {
+    pub fn from_raw(value: u32) -> Option<ComponentType> {
+        Some(match value {
+            0 => ComponentType::Unknown,
+            1 => ComponentType::UInt32,
+            2 => ComponentType::SInt32,
+            3 => ComponentType::Float32,
+            _ => return None,
+        })
+    }
+}
+
+/// The system value a signature element carries, if any. Mirrors
+/// `D3D_NAME`.
+///
+/// Pixel shader outputs such as `SV_Target` and `SV_Depth` are stored as
+/// `Undefined` in the container; reflection infers them from the semantic
+/// name.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SystemValue {
+    Undefined,
+    Position,
+    ClipDistance,
+    CullDistance,
+    RenderTargetArrayIndex,
+    ViewportArrayIndex,
+    VertexId,
+    PrimitiveId,
+    InstanceId,
+    IsFrontFace,
+    SampleIndex,
+    FinalQuadEdgeTessfactor,
+    FinalQuadInsideTessfactor,
+    FinalTriEdgeTessfactor,
+    FinalTriInsideTessfactor,
+    FinalLineDetailTessfactor,
+    FinalLineDensityTessfactor,
+    Target,
+    Depth,
+    Coverage,
+    DepthGreaterEqual,
+    DepthLessEqual,
+    StencilRef,
+    InnerCoverage,
+}
+
+impl SystemValue {
+    pub fn from_raw(value: u32) -> Option<SystemValue> {
+        Some(match value {
+            0 => SystemValue::Undefined,
+            1 => SystemValue::Position,
+            2 => SystemValue::ClipDistance,
+            3 => SystemValue::CullDistance,
+            4 => SystemValue::RenderTargetArrayIndex,
+            5 => SystemValue::ViewportArrayIndex,
+            6 => SystemValue::VertexId,
+            7 => SystemValue::PrimitiveId,
+            8 => SystemValue::InstanceId,
+            9 => SystemValue::IsFrontFace,
+            10 => SystemValue::SampleIndex,
+            11 => SystemValue::FinalQuadEdgeTessfactor,
+            12 => SystemValue::FinalQuadInsideTessfactor,
+            13 => SystemValue::FinalTriEdgeTessfactor,
+            14 => SystemValue::FinalTriInsideTessfactor,
+            15 => SystemValue::FinalLineDetailTessfactor,
+            16 => SystemValue::FinalLineDensityTessfactor,
+            64 => SystemValue::Target,
+            65 => SystemValue::Depth,
+            66 => SystemValue::Coverage,
+            67 => SystemValue::DepthGreaterEqual,
+            68 => SystemValue::DepthLessEqual,
+            69 => SystemValue::StencilRef,
+            70 => SystemValue::InnerCoverage,
+            _ => return None,
+        })
+    }
+}
+
+/// One element of an input, output or patch constant signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SignatureElement<'a> {
+    pub semantic_name: &'a str,
+    pub semantic_index: u32,
+    pub system_value: SystemValue,
+    pub component_type: ComponentType,
+    pub register: u32,
+    /// The components of the register the element occupies.
+    pub mask: u8,
+    /// For inputs, the components the shader always reads. For outputs,
+    /// the components it never writes.
+    pub rw_mask: u8,
+    /// The geometry shader output stream. 0 for other signatures.
+    pub stream: u32,
+    /// The raw `D3D_MIN_PRECISION`. 0 for signatures without it.
+    pub min_precision: u32,
+}
+
+impl<'a> SignatureElement<'a> {
+    /// The number of components in the mask, e.g. 3 for a `float3` input.
+    pub fn component_count(&self) -> u32 {
+        (self.mask & 0xF).count_ones()
+    }
+
+    /// Whether the element has the given semantic. Semantic names are
+    /// case-insensitive.
+    pub fn is_semantic(&self, name: &str, index: u32) -> bool {
+        self.semantic_index == index && self.semantic_name.eq_ignore_ascii_case(name)
+    }
+}
+
+/// The elements of a signature chunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature<'a> {
+    pub elements: Vec<SignatureElement<'a>>,
+}
+
+impl<'a> Signature<'a> {
+    /// Parses any of the signature chunks: `ISGN`, `OSGN` and `PCSG`, the
+    /// `OSG5` variant with streams and the `ISG1`, `OSG1` and `PSG1`
+    /// variants with minimum precision.
+    pub fn parse(chunk: &Chunk<'a>) -> Result<Signature<'a>, DxbcError> {
+        let reader = chunk.reader();
+        let (size, has_stream, has_precision) = match chunk.fourcc {
+            FourCC::ISGN | FourCC::OSGN | FourCC::PCSG => (24, false, false),
+            FourCC::OSG5 => (28, true, false),
+            FourCC::ISG1 | FourCC::OSG1 | FourCC::PSG1 => (32, true, true),
+            _ => return Err(reader.malformed("not a signature chunk")),
+        };
+
+        let count = reader.u32(0)? as usize;
+        let first = reader.u32(4)? as usize;
+        // Check the whole table before allocating for it.
+        reader.bytes(first, count.saturating_mul(size))?;
+
+        let mut elements = Vec::with_capacity(count);
+        for i in 0..count {
+            let mut offset = first + i * size;
+            let stream = if has_stream {
+                offset += 4;
+                reader.u32(offset - 4)?
+            } else {
+                0
+            };
+            let system_value = reader.u32(offset + 8)?;
+            let component_type = reader.u32(offset + 12)?;
+            elements.push(SignatureElement {
+                semantic_name: reader.str(reader.u32(offset)? as usize)?,
+                semantic_index: reader.u32(offset + 4)?,
+                system_value: SystemValue::from_raw(system_value)
+                    .ok_or(reader.invalid_value("system value", system_value))?,
+                component_type: ComponentType::from_raw(component_type)
+                    .ok_or(reader.invalid_value("component type", component_type))?,
+                register: reader.u32(offset + 16)?,
+                mask: reader.u8(offset + 20)?,
+                rw_mask: reader.u8(offset + 21)?,
+                stream,
+                min_precision: if has_precision {
+                    reader.u32(offset + 24)?
+                } else {
+                    0
+                },
+            });
+        }
+        Ok(Signature { elements })
+    }
+
+    /// The element with the given semantic.
+    pub fn find(&self, name: &str, index: u32) -> Option<&SignatureElement<'a>> {
+        self.elements.iter().find(|e| e.is_semantic(name, index))
+    }
+}
@@ -0,0 +1,95 @@
+use crate::dxbc::{Chunk, DxbcError};
+
+/// The instruction counts and other statistics in a `STAT` chunk, as
+/// reported by `D3D11_SHADER_DESC`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Statistics {
+    pub instruction_count: u32,
+    pub temp_register_count: u32,
+    pub def_count: u32,
+    pub dcl_count: u32,
+    pub float_instruction_count: u32,
+    pub int_instruction_count: u32,
+    pub uint_instruction_count: u32,
+    pub static_flow_control_count: u32,
+    pub dynamic_flow_control_count: u32,
+    pub macro_instruction_count: u32,
+    pub temp_array_count: u32,
+    pub array_instruction_count: u32,
+    pub cut_instruction_count: u32,
+    pub emit_instruction_count: u32,
+    pub texture_normal_instructions: u32,
+    pub texture_load_instructions: u32,
+    pub texture_comp_instructions: u32,
+    pub texture_bias_instructions: u32,
+    pub texture_gradient_instructions: u32,
+    pub mov_instruction_count: u32,
+    pub movc_instruction_count: u32,
+    pub conversion_instruction_count: u32,
+    /// The raw `D3D_PRIMITIVE` of a geometry shader's input.
+    pub gs_input_primitive: u32,
+    /// The raw `D3D_PRIMITIVE_TOPOLOGY` of a geometry shader's output.
+    pub gs_output_topology: u32,
+    pub gs_max_output_vertex_count: u32,
+    pub control_points: u32,
+    /// The raw `D3D_TESSELLATOR_OUTPUT_PRIMITIVE` of a hull shader.
+    pub hs_output_primitive: u32,
+    /// The raw `D3D_TESSELLATOR_PARTITIONING` of a hull shader.
+    pub hs_partitioning: u32,
+    /// The raw `D3D_TESSELLATOR_DOMAIN` of a hull or domain shader.
+    pub tessellator_domain: u32,
+    pub barrier_instructions: u32,
+    pub interlocked_instructions: u32,
+    pub texture_store_instructions: u32,
+}
+
+/// The chunk is 28 or 29 words for shader model 4 and 37 for shader
+/// model 5.
+const MIN_WORDS: usize = 28;
+
+impl Statistics {
+    pub fn parse(chunk: &Chunk) -> Result<Statistics, DxbcError> {
+        let reader = chunk.reader();
+        let words = reader.u32s(0, reader.len() / 4)?;
+        if words.len() < MIN_WORDS {
+            return Err(reader.malformed("the statistics are too short"));
+        }
+        let word = |i: usize| words.get(i).cloned().unwrap_or(0);
+        Ok(Statistics {
+            instruction_count: word(0),
+            temp_register_count: word(1),
+            def_count: word(2),
+            dcl_count: word(3),
+            float_instruction_count: word(4),
+            int_instruction_count: word(5),
+            uint_instruction_count: word(6),
+            static_flow_control_count: word(7),
+            dynamic_flow_control_count: word(8),
+            macro_instruction_count: word(9),
+            temp_array_count: word(10),
+            array_instruction_count: word(11),
+            cut_instruction_count: word(12),
+            emit_instruction_count: word(13),
+            texture_normal_instructions: word(14),
+            texture_load_instructions: word(15),
+            texture_comp_instructions: word(16),
+            texture_bias_instructions: word(17),
+            texture_gradient_instructions: word(18),
+            mov_instruction_count: word(19),
+            movc_instruction_count: word(20),
+            conversion_instruction_count: word(21),
+            // Word 22 is unknown.
+            gs_input_primitive: word(23),
+            gs_output_topology: word(24),
+            gs_max_output_vertex_count: word(25),
+            // Words 26 to 29 are unknown.
+            control_points: word(30),
+            hs_output_primitive: word(31),
+            hs_partitioning: word(32),
+            tessellator_domain: word(33),
+            barrier_instructions: word(34),
+            interlocked_instructions: word(35),
+            texture_store_instructions: word(36),
+        })
+    }
+}
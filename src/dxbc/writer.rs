@@ -0,0 +1,167 @@
+//! Writing containers, to remove chunks from compiled shaders or replace
+//! them.
+
+use crate::dxbc::checksum::CHECKSUM_START;
+use crate::dxbc::{checksum, Container, DxbcError, FourCC, HEADER_SIZE};
+
+use std::borrow::Cow;
+use std::ops::BitOr;
+
+/// Collects chunks and writes them out as a container with a valid
+/// checksum. Chunk data is borrowed where possible, so rewriting a parsed
+/// container only copies it once, in `write`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContainerWriter<'a> {
+    chunks: Vec<(FourCC, Cow<'a, [u8]>)>,
+}
+
+impl<'a> ContainerWriter<'a> {
+    pub fn new() -> ContainerWriter<'a> {
+        ContainerWriter::default()
+    }
+
+    /// Starts from the chunks of a container, in the same order.
+    pub fn from_container(container: &Container<'a>) -> ContainerWriter<'a> {
+        ContainerWriter {
+            chunks: container
+                .chunks()
+                .iter()
+                .map(|chunk| (chunk.fourcc, Cow::Borrowed(chunk.data)))
+                .collect(),
+        }
+    }
+
+    /// The codes of the chunks, in the order they will be written.
+    pub fn fourccs(&self) -> Vec<FourCC> {
+        self.chunks.iter().map(|&(fourcc, _)| fourcc).collect()
+    }
+
+    /// Adds a chunk after the existing ones.
+    pub fn push(&mut self, fourcc: FourCC, data: impl Into<Cow<'a, [u8]>>) -> &mut Self {
+        self.chunks.push((fourcc, data.into()));
+        self
+    }
+
+    /// Replaces the contents of the first chunk with the given code, keeping
+    /// its position, or adds the chunk if there is none. Later chunks with
+    /// the same code are removed.
+    pub fn replace(&mut self, fourcc: FourCC, data: impl Into<Cow<'a, [u8]>>) -> &mut Self {
+        let data = data.into();
+        match self.chunks.iter().position(|&(code, _)| code == fourcc) {
+            Some(index) => {
+                self.chunks[index].1 = data;
+                let mut first = true;
+                self.chunks
+                    .retain(|&(code, _)| code != fourcc || std::mem::replace(&mut first, false));
+            }
+            None => self.chunks.push((fourcc, data)),
+        }
+        self
+    }
+
+    /// Removes every chunk with the given code. Returns whether there were
+    /// any.
+    pub fn remove(&mut self, fourcc: FourCC) -> bool {
+        let len = self.chunks.len();
+        self.chunks.retain(|&(code, _)| code != fourcc);
+        self.chunks.len() != len
+    }
+
+    /// Removes the chunks for which `keep` returns false.
+    pub fn retain(&mut self, mut keep: impl FnMut(FourCC) -> bool) -> &mut Self {
+        self.chunks.retain(|&(code, _)| keep(code));
+        self
+    }
+
+    /// Writes the container. Each chunk starts on a 4 byte boundary, as the
+    /// compiler lays them out.
+    pub fn write(&self) -> Vec<u8> {
+        let header_size = HEADER_SIZE + 4 * self.chunks.len();
+        let size = self.chunks.iter().fold(header_size, |size, (_, data)| {
+            size + 8 + padded_len(data.len())
+        });
+
+        let mut out = Vec::with_capacity(size);
+        out.extend_from_slice(b"DXBC");
+        out.extend_from_slice(&[0; 16]);
+        out.extend_from_slice(&1u32.to_le_bytes());
+        out.extend_from_slice(&(size as u32).to_le_bytes());
+        out.extend_from_slice(&(self.chunks.len() as u32).to_le_bytes());
+        let mut offset = header_size;
+        for (_, data) in &self.chunks {
+            out.extend_from_slice(&(offset as u32).to_le_bytes());
+            offset += 8 + padded_len(data.len());
+        }
+        for (fourcc, data) in &self.chunks {
+            out.extend_from_slice(&fourcc.0);
+            out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            out.extend_from_slice(data);
+            out.resize(out.len() + padded_len(data.len()) - data.len(), 0);
+        }
+        debug_assert_eq!(out.len(), size);
+
+        let checksum = checksum(&out);
+        out[4..CHECKSUM_START].copy_from_slice(&checksum);
+        out
+    }
+}
+
+fn padded_len(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// The groups of chunks `strip` can remove. Mirrors the
+/// `D3DCOMPILER_STRIP_*` flags of `D3DStripShader`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StripFlags(pub u32);
+
+impl StripFlags {
+    pub const NONE: StripFlags = StripFlags(0);
+    /// `RDEF` and `STAT`, used by reflection. Shaders created without them
+    /// still run.
+    pub const REFLECTION_DATA: StripFlags = StripFlags(0x1);
+    /// `SDBG`, `SPDB`, `ILDB` and `ILDN`.
+    pub const DEBUG_INFO: StripFlags = StripFlags(0x2);
+    /// `PRIV`.
+    pub const PRIVATE_DATA: StripFlags = StripFlags(0x8);
+
+    /// Whether all of the flags in `flag` are set.
+    pub fn is_set(&self, flag: StripFlags) -> bool {
+        self.0 & flag.0 == flag.0
+    }
+
+    /// The codes of the chunks removed with these flags.
+    pub fn fourccs(&self) -> Vec<FourCC> {
+        let mut fourccs = Vec::new();
+        if self.is_set(StripFlags::REFLECTION_DATA) {
+            fourccs.extend_from_slice(&[FourCC::RDEF, FourCC::STAT]);
+        }
+        if self.is_set(StripFlags::DEBUG_INFO) {
+            fourccs.extend_from_slice(&[FourCC::SDBG, FourCC::SPDB, FourCC::ILDB, FourCC::ILDN]);
+        }
+        if self.is_set(StripFlags::PRIVATE_DATA) {
+            fourccs.push(FourCC::PRIV);
+        }
+        fourccs
+    }
+}
+
+impl BitOr for StripFlags {
+    type Output = StripFlags;
+
+    fn bitor(self, rhs: StripFlags) -> StripFlags {
+        StripFlags(self.0 | rhs.0)
+    }
+}
+
+/// Removes chunks from a container, like `D3DStripShader`. The checksum of
+/// the input is verified first, so that a corrupted container is not given
+/// a fresh valid checksum.
+pub fn strip(bytecode: &[u8], flags: StripFlags) -> Result<Vec<u8>, DxbcError> {
+    let container = Container::parse(bytecode)?;
+    container.verify_checksum()?;
+    let removed = flags.fourccs();
+    Ok(ContainerWriter::from_container(&container)
+        .retain(|fourcc| !removed.contains(&fourcc))
+        .write())
+}
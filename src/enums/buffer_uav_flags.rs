@@ -0,0 +1,20 @@
+#[auto_enum::enum_flags(u32)]
+/// Options for an unordered access view of a buffer.
+///
+/// [More Information][1]
+///
+/// [1]: https://msdn.microsoft.com/en-us/library/windows/desktop/ff476096(v=vs.85).aspx
+pub enum BufferUavFlags {
+    NONE = 0,
+
+    /// The buffer is viewed as raw bytes. The view format must be
+    /// `R32Typeless` and the buffer must have been created with
+    /// `ResourceMiscFlags::BUFFER_ALLOW_RAW_VIEWS`.
+    RAW = 0x1,
+
+    /// Allows data to be appended to the end of a structured buffer.
+    APPEND = 0x2,
+
+    /// Adds a hidden counter to a structured buffer.
+    COUNTER = 0x4,
+}
@@ -10,7 +10,9 @@ pub enum CreateDeviceFlags {
     /// your application calls methods of Direct3D 11 interfaces from
     /// multiple threads, undefined behavior might result.
     ///
-    /// ***Note*** This flag is not currently supported by the crate
+    /// Devices with this flag are created by
+    /// `DeviceBuilder::build_single_threaded`, which returns types that are
+    /// neither `Send` nor `Sync`.
     SINGLETHREADED = 0x1,
 
     /// Creates a device that supports the [debug layer][1].
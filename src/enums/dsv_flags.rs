@@ -0,0 +1,17 @@
+#[auto_enum::enum_flags(u32)]
+/// Depth-stencil view options for making the depth or stencil aspect
+/// read-only, so the texture can be bound as a shader resource at the same
+/// time.
+///
+/// [More Information][1]
+///
+/// [1]: https://msdn.microsoft.com/en-us/library/windows/desktop/ff476116(v=vs.85).aspx
+pub enum DsvFlags {
+    NONE = 0,
+
+    /// The view is read-only in the depth aspect.
+    READ_ONLY_DEPTH = 0x1,
+
+    /// The view is read-only in the stencil aspect.
+    READ_ONLY_STENCIL = 0x2,
+}
@@ -1,4 +1,7 @@
+use crate::enums::names::ParseEnumError;
+
 use std::fmt;
+use std::str::FromStr;
 
 #[repr(C)]
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -25,3 +28,38 @@ impl fmt::Debug for FeatureLevel {
         write!(fmt, "FeatureLevel({}.{})", self.major(), self.minor())
     }
 }
+
+/// Written as `11_0`, the way Direct3D names the levels.
+impl fmt::Display for FeatureLevel {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}_{}", self.major(), self.minor())
+    }
+}
+
+/// Parses the levels defined above, written as `11_0` or `11.0`.
+impl FromStr for FeatureLevel {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LEVELS
+            .iter()
+            .find(|level| {
+                s == format!("{}_{}", level.major(), level.minor())
+                    || s == format!("{}.{}", level.major(), level.minor())
+            })
+            .cloned()
+            .ok_or_else(|| ParseEnumError::new("FeatureLevel", s))
+    }
+}
+
+const LEVELS: &[FeatureLevel] = &[
+    FeatureLevel::LEVEL_9_1,
+    FeatureLevel::LEVEL_9_2,
+    FeatureLevel::LEVEL_9_3,
+    FeatureLevel::LEVEL_10_0,
+    FeatureLevel::LEVEL_10_1,
+    FeatureLevel::LEVEL_11_0,
+    FeatureLevel::LEVEL_11_1,
+    FeatureLevel::LEVEL_12_0,
+    FeatureLevel::LEVEL_12_1,
+];
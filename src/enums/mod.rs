@@ -1,33 +1,171 @@
 #[doc(inline)]
 pub use crate::enums::bind_flags::BindFlags;
 #[doc(inline)]
+pub use crate::enums::buffer_uav_flags::BufferUavFlags;
+#[doc(inline)]
 pub use crate::enums::cpu_access_flags::CpuAccessFlags;
 #[doc(inline)]
 pub use crate::enums::create_device_flags::CreateDeviceFlags;
 #[doc(inline)]
 pub use crate::enums::driver_type::DriverType;
 #[doc(inline)]
+pub use crate::enums::dsv_flags::DsvFlags;
+#[doc(inline)]
 pub use crate::enums::feature_level::FeatureLevel;
 #[doc(inline)]
 pub use crate::enums::map::Map;
 #[doc(inline)]
+pub use crate::enums::names::ParseEnumError;
+#[doc(inline)]
 pub use crate::enums::resource_misc_flags::ResourceMiscFlags;
 #[doc(inline)]
 pub use crate::enums::usage::Usage;
 
+#[cfg(windows)]
+use crate::error::DescError;
+use crate::state::ColorWriteMask;
+
+#[cfg(windows)]
+use checked_enum::UncheckedEnum;
+#[cfg(windows)]
+use dxgi::enums::Format;
+
+#[macro_use]
+pub(crate) mod names;
+
+named_enum!(DriverType {
+    Unknown,
+    Hardware,
+    Reference,
+    Null,
+    Software,
+    Warp,
+});
+
+named_enum!(Map {
+    Read,
+    Write,
+    ReadWrite,
+    Discard,
+    WriteNoOverwrite,
+});
+
+named_enum!(Usage {
+    Default,
+    Immutable,
+    Dynamic,
+    Staging,
+});
+
+flag_set!(BindFlags {
+    VERTEX_BUFFER,
+    INDEX_BUFFER,
+    CONSTANT_BUFFER,
+    SHADER_RESOURCE,
+    STREAM_OUTPUT,
+    RENDER_TARGET,
+    DEPTH_STENCIL,
+    UNORDERED_ACCESS,
+    DECODER,
+    VIDEO_ENCODER,
+});
+
+flag_set!(BufferUavFlags {
+    RAW,
+    APPEND,
+    COUNTER,
+});
+
+flag_set!(ColorWriteMask {
+    RED,
+    GREEN,
+    BLUE,
+    ALPHA,
+    ALL,
+});
+
+flag_set!(CpuAccessFlags {
+    WRITE,
+    READ,
+    READWRITE,
+});
+
+flag_set!(CreateDeviceFlags {
+    SINGLETHREADED,
+    DEBUG,
+    SWITCH_TO_REF,
+    PREVENT_INTERNAL_THREADING_OPTIMIZATIONS,
+    BGRA_SUPPORT,
+    DEBUGGABLE,
+    PREVENT_ALTERING_LAYER_SETTINGS_FROM_REGISTRY,
+    DISABLE_GPU_TIMEOUT,
+    VIDEO_SUPPORT,
+});
+
+flag_set!(DsvFlags {
+    READ_ONLY_DEPTH,
+    READ_ONLY_STENCIL,
+});
+
+flag_set!(ResourceMiscFlags {
+    GENERATE_MIPS,
+    SHARED,
+    TEXTURECUBE,
+    DRAWINDIRECT_ARGS,
+    BUFFER_ALLOW_RAW_VIEWS,
+    BUFFER_STRUCTURED,
+    RESOURCE_CLAMP,
+    SHARED_KEYEDMUTEX,
+    GDI_COMPATIBLE,
+    SHARED_NTHANDLE,
+    RESTRICTED_CONTENT,
+    RESTRICT_SHARED_RESOURCE,
+    RESTRICT_SHARED_RESOURCE_DRIVER,
+    GUARDED,
+    TILE_POOL,
+    TILED,
+    HW_PROTECTED,
+});
+
+/// Converts a raw `DXGI_FORMAT` from a description, naming `field` if the
+/// value is unknown.
+#[cfg(windows)]
+pub(crate) fn format_from_raw(field: &'static str, value: u32) -> Result<Format, DescError> {
+    UncheckedEnum::<Format>::new(value)
+        .as_enum()
+        .ok_or(DescError::UnknownEnumValue { field, value })
+}
+
+/// Converts a raw `D3D11_USAGE` from a description.
+#[cfg(windows)]
+pub(crate) fn usage_from_raw(value: u32) -> Result<Usage, DescError> {
+    UncheckedEnum::<Usage>::new(value)
+        .as_enum()
+        .ok_or(DescError::UnknownEnumValue {
+            field: "Usage",
+            value,
+        })
+}
+
 #[doc(hidden)]
 pub mod bind_flags;
 #[doc(hidden)]
+pub mod buffer_uav_flags;
+#[doc(hidden)]
 pub mod cpu_access_flags;
 #[doc(hidden)]
 pub mod create_device_flags;
 #[doc(hidden)]
 pub mod driver_type;
 #[doc(hidden)]
+pub mod dsv_flags;
+#[doc(hidden)]
 pub mod feature_level;
 #[doc(hidden)]
 pub mod map;
 #[doc(hidden)]
 pub mod resource_misc_flags;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impls;
 #[doc(hidden)]
 pub mod usage;
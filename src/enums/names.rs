@@ -0,0 +1,199 @@
+//! The names of the variants of each enum and the flags of each flag set,
+//! used to display and parse them and to write them out with serde.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+/// A flag set whose flags have names.
+pub(crate) trait FlagSet: Copy {
+    /// The named flags, single flags before combinations such as
+    /// `CpuAccessFlags::READWRITE`. `NONE` is left out.
+    const NAMES: &'static [(&'static str, u32)];
+
+    fn bits(self) -> u32;
+    fn from_bits(bits: u32) -> Self;
+}
+
+/// The names of the flags set in `flags`, in the order of `F::NAMES`. A
+/// combination is only named if it covers a flag not named before it. Bits
+/// without a name are returned as the error.
+#[cfg(feature = "serde")]
+pub(crate) fn flag_names<F: FlagSet>(flags: F) -> Result<Vec<&'static str>, u32> {
+    match split_flags(flags) {
+        (names, 0) => Ok(names),
+        (_, unknown) => Err(unknown),
+    }
+}
+
+/// The names of the flags set in `flags` and the bits without a name.
+fn split_flags<F: FlagSet>(flags: F) -> (Vec<&'static str>, u32) {
+    let bits = flags.bits();
+    let mut named = 0;
+    let mut names = Vec::new();
+    for &(name, flag) in F::NAMES {
+        if bits & flag == flag && flag & !named != 0 {
+            names.push(name);
+            named |= flag;
+        }
+    }
+    (names, bits & !named)
+}
+
+/// The bits of the flag with the given name.
+#[cfg(feature = "serde")]
+pub(crate) fn flag_by_name<F: FlagSet>(name: &str) -> Option<u32> {
+    F::NAMES
+        .iter()
+        .find(|&&(flag, _)| flag == name)
+        .map(|&(_, bits)| bits)
+}
+
+/// Writes the names of the flags separated by `" | "`, `NONE` if there are
+/// none, and bits without a name in hex.
+pub(crate) fn display_flags<F: FlagSet>(flags: F, fmt: &mut fmt::Formatter) -> fmt::Result {
+    let (names, unknown) = split_flags(flags);
+    let mut parts: Vec<String> = names.into_iter().map(String::from).collect();
+    if unknown != 0 {
+        parts.push(format!("{:#x}", unknown));
+    }
+    if parts.is_empty() {
+        fmt.write_str("NONE")
+    } else {
+        fmt.write_str(&parts.join(" | "))
+    }
+}
+
+/// Parses flag names separated by `|`, ignoring ASCII case and whitespace
+/// around the names. `NONE` parses as no flags.
+pub(crate) fn parse_flags<F: FlagSet>(
+    s: &str,
+    type_name: &'static str,
+) -> Result<F, ParseEnumError> {
+    let mut bits = 0;
+    for name in s.split('|').map(str::trim) {
+        bits |= F::NAMES
+            .iter()
+            .chain(&[("NONE", 0)])
+            .find(|&&(flag, _)| flag.eq_ignore_ascii_case(name))
+            .map(|&(_, bits)| bits)
+            .ok_or_else(|| ParseEnumError::new(type_name, name))?;
+    }
+    Ok(F::from_bits(bits))
+}
+
+/// An enum whose variants have names.
+pub(crate) trait NamedEnum: Copy + PartialEq + 'static {
+    const VARIANTS: &'static [(&'static str, Self)];
+}
+
+/// The name of the variant `value`.
+pub(crate) fn variant_name<E: NamedEnum>(value: E) -> &'static str {
+    E::VARIANTS
+        .iter()
+        .find(|&&(_, variant)| variant == value)
+        .map(|&(name, _)| name)
+        .expect("every variant has a name")
+}
+
+/// The variant with the given name.
+#[cfg(feature = "serde")]
+pub(crate) fn variant_by_name<E: NamedEnum>(name: &str) -> Option<E> {
+    E::VARIANTS
+        .iter()
+        .find(|&&(variant, _)| variant == name)
+        .map(|&(_, value)| value)
+}
+
+/// Parses the name of a variant, ignoring ASCII case.
+pub(crate) fn parse_variant<E: NamedEnum>(
+    s: &str,
+    type_name: &'static str,
+) -> Result<E, ParseEnumError> {
+    E::VARIANTS
+        .iter()
+        .find(|&&(variant, _)| variant.eq_ignore_ascii_case(s))
+        .map(|&(_, value)| value)
+        .ok_or_else(|| ParseEnumError::new(type_name, s))
+}
+
+/// The error of parsing an enum, flag set or feature level from a string.
+/// The value is the part of the string that could not be parsed, e.g. the
+/// unknown flag of a flag set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseEnumError {
+    pub type_name: &'static str,
+    pub value: String,
+}
+
+impl ParseEnumError {
+    pub(crate) fn new(type_name: &'static str, value: &str) -> Self {
+        ParseEnumError {
+            type_name,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "`{}` is not a valid {}", self.value, self.type_name)
+    }
+}
+
+impl StdError for ParseEnumError {}
+
+macro_rules! flag_set {
+    ($name:ident { $($flag:ident,)* }) => {
+        impl crate::enums::names::FlagSet for $name {
+            const NAMES: &'static [(&'static str, u32)] = &[
+                $((stringify!($flag), $name::$flag.0 as u32),)*
+            ];
+
+            fn bits(self) -> u32 {
+                self.0 as u32
+            }
+
+            fn from_bits(bits: u32) -> Self {
+                $name(bits as _)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                crate::enums::names::display_flags(*self, fmt)
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::enums::names::ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                crate::enums::names::parse_flags(s, stringify!($name))
+            }
+        }
+    };
+}
+
+macro_rules! named_enum {
+    ($name:ident { $($variant:ident,)* }) => {
+        impl crate::enums::names::NamedEnum for $name {
+            const VARIANTS: &'static [(&'static str, Self)] = &[
+                $((stringify!($variant), $name::$variant),)*
+            ];
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
+                fmt.write_str(crate::enums::names::variant_name(*self))
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::enums::names::ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                crate::enums::names::parse_variant(s, stringify!($name))
+            }
+        }
+    };
+}
@@ -5,6 +5,9 @@
 ///
 /// [1]: https://msdn.microsoft.com/en-us/library/windows/desktop/ff476203(v=vs.85).aspx
 pub enum ResourceMiscFlags {
+    /// No misc flags specified
+    NONE = 0,
+
     GENERATE_MIPS = 0x1,
     SHARED = 0x2,
     TEXTURECUBE = 0x4,
@@ -0,0 +1,140 @@
+//! `Serialize` and `Deserialize` for the enums and flag sets. Enums are
+//! written as the names of their variants, flag sets as lists of the names
+//! of their flags, and feature levels as strings such as `"11_0"`.
+
+use crate::enums::names::{self, FlagSet, NamedEnum};
+use crate::enums::*;
+use crate::state::ColorWriteMask;
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serialize, SerializeSeq, Serializer};
+
+fn serialize_flags<F: FlagSet, S: Serializer>(flags: F, serializer: S) -> Result<S::Ok, S::Error> {
+    let names = names::flag_names(flags)
+        .map_err(|bits| ser::Error::custom(format_args!("unknown flags {:#x}", bits)))?;
+    let mut seq = serializer.serialize_seq(Some(names.len()))?;
+    for name in names {
+        seq.serialize_element(name)?;
+    }
+    seq.end()
+}
+
+struct FlagsVisitor<F>(PhantomData<F>);
+
+impl<'de, F: FlagSet> Visitor<'de> for FlagsVisitor<F> {
+    type Value = F;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a list of flag names")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<F, A::Error> {
+        let mut bits = 0;
+        while let Some(name) = seq.next_element::<String>()? {
+            bits |= names::flag_by_name::<F>(&name)
+                .ok_or_else(|| de::Error::custom(format_args!("unknown flag `{}`", name)))?;
+        }
+        Ok(F::from_bits(bits))
+    }
+}
+
+fn serialize_enum<E: NamedEnum, S: Serializer>(value: E, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(names::variant_name(value))
+}
+
+struct EnumVisitor<E>(PhantomData<E>);
+
+impl<'de, E: NamedEnum> Visitor<'de> for EnumVisitor<E> {
+    type Value = E;
+
+    fn expecting(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str("a variant name")
+    }
+
+    fn visit_str<Err: de::Error>(self, name: &str) -> Result<E, Err> {
+        names::variant_by_name(name)
+            .ok_or_else(|| Err::custom(format_args!("unknown variant `{}`", name)))
+    }
+}
+
+macro_rules! serde_flags {
+    ($($name:ident),*) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_flags(*self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_seq(FlagsVisitor(PhantomData))
+            }
+        }
+    )*};
+}
+
+macro_rules! serde_enums {
+    ($($name:ident),*) => {$(
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serialize_enum(*self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(EnumVisitor(PhantomData))
+            }
+        }
+    )*};
+}
+
+serde_flags!(
+    BindFlags,
+    BufferUavFlags,
+    ColorWriteMask,
+    CpuAccessFlags,
+    CreateDeviceFlags,
+    DsvFlags,
+    ResourceMiscFlags
+);
+serde_enums!(DriverType, Map, Usage);
+
+impl Serialize for FeatureLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FeatureLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// For `#[serde(with = "...")]` on `Format` fields, which are written as the
+/// names of their formats, e.g. `"R8G8B8A8_UNORM"`.
+pub(crate) mod format {
+    use crate::format_info::{Format, FormatInfo};
+
+    use serde::de::{self, Deserialize, Deserializer};
+    use serde::ser::Serializer;
+
+    pub fn serialize<S: Serializer>(format: &Format, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(FormatInfo::of(*format).name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Format, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        FormatInfo::all()
+            .iter()
+            .find(|info| info.name == name)
+            .map(|info| info.variant())
+            .ok_or_else(|| de::Error::custom(format_args!("unknown format `{}`", name)))
+    }
+}
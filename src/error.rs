@@ -0,0 +1,906 @@
+//! Errors of the checks that do not need Direct3D are available on every
+//! platform; errors that can carry a failed `HRESULT` only on Windows.
+
+#[cfg(windows)]
+use crate::dxbc::DxbcError;
+use crate::enums::{BindFlags, CpuAccessFlags, Map, Usage};
+#[cfg(windows)]
+use crate::enums::{CreateDeviceFlags, DriverType};
+use crate::format_info::Format;
+use crate::initial_data::TextureExtent;
+use crate::limits::LimitError;
+use crate::subresource::{Box3D, Subresource};
+use crate::validation::ResourceDimension;
+#[cfg(windows)]
+use crate::vertex::InputLayoutError;
+use crate::view::{ArrayRange, MipRange, ViewDimension, ViewKind};
+
+use std::error::Error as StdError;
+use std::fmt;
+
+#[cfg(windows)]
+use dcommon::error::Error;
+
+/// Describes why a resource description was rejected before it was ever
+/// passed to Direct3D.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DescError {
+    /// The resource has a size of zero bytes.
+    ZeroSize,
+
+    /// Constant buffers must have a size that is a multiple of 16 bytes.
+    ConstantBufferSize { byte_width: u32 },
+
+    /// `BindFlags::CONSTANT_BUFFER` may not be combined with any other
+    /// bind flag.
+    ConstantBufferBindFlags { bind_flags: BindFlags },
+
+    /// `ResourceMiscFlags::BUFFER_STRUCTURED` requires a structure stride.
+    MissingStructureStride,
+
+    /// The structure stride must be a multiple of 4 no larger than 2048.
+    InvalidStructureStride { stride: u32 },
+
+    /// The size of a structured buffer must be a multiple of its stride.
+    StructureStrideMismatch { byte_width: u32, stride: u32 },
+
+    /// `BUFFER_STRUCTURED` and `BUFFER_ALLOW_RAW_VIEWS` are mutually exclusive.
+    StructuredRawViews,
+
+    /// The initial data does not contain enough bytes to fill the resource.
+    InitialDataTooSmall { expected: usize, actual: usize },
+
+    /// The number of initial data entries does not match the number of
+    /// subresources in the texture.
+    InitialDataCount { expected: usize, actual: usize },
+
+    /// The row pitch of the initial data is smaller than a row of texels.
+    RowPitchTooSmall { row_pitch: u32, row_bytes: u32 },
+
+    /// The slice pitch of the initial data is smaller than a slice of texels.
+    SlicePitchTooSmall { slice_pitch: u32, slice_bytes: u32 },
+
+    /// The size of a texel of this format is not known, so the initial data
+    /// cannot be checked.
+    UnknownFormatSize { format: Format },
+
+    /// A raw description contained a value that does not correspond to any
+    /// variant of the crate's enums.
+    UnknownEnumValue { field: &'static str, value: u32 },
+
+    /// The initial data for one subresource does not match the size of its
+    /// mip level.
+    InvalidSubresourceData {
+        subresource: Subresource,
+        error: Box<DescError>,
+    },
+
+    /// Initial data was given for a subresource the texture does not have.
+    SubresourceOutOfRange { subresource: Subresource },
+
+    /// Initial data was given twice for the same subresource.
+    DuplicateSubresourceData { subresource: Subresource },
+
+    /// Initial data was given for some subresources but not this one.
+    MissingSubresourceData { subresource: Subresource },
+
+    /// `Usage::Immutable` resources can only be filled with initial data.
+    ImmutableWithoutInitialData,
+
+    /// The CPU access flags are not allowed for the usage. Only dynamic
+    /// resources (with exactly `WRITE`) and staging resources (with `READ`,
+    /// `WRITE` or both) may be accessed by the CPU.
+    UsageCpuAccess {
+        usage: Usage,
+        cpu_access_flags: CpuAccessFlags,
+    },
+
+    /// `Usage::Staging` resources cannot be bound to the pipeline.
+    StagingBindFlags { bind_flags: BindFlags },
+
+    /// Immutable and dynamic resources cannot be bound as pipeline outputs.
+    UsageBindFlags { usage: Usage, bind_flags: BindFlags },
+
+    /// Dynamic textures have a single subresource, so they cannot have mip
+    /// maps or more than one array slice.
+    DynamicSubresources { mip_levels: u32, array_size: u32 },
+
+    /// Textures must have a format.
+    MissingFormat,
+
+    /// The texture has more mip levels than a full mip chain.
+    TooManyMipLevels { mip_levels: u32, max: u32 },
+
+    /// The bind flags cannot be used with this kind of resource, or cannot
+    /// be combined with each other.
+    InvalidBindFlags { bind_flags: BindFlags },
+
+    /// `BindFlags::DEPTH_STENCIL` requires a depth format or a typeless one.
+    DepthStencilFormat { format: Format },
+
+    /// The size of a block compressed texture must be a multiple of the
+    /// block size.
+    BlockCompressedSize { width: u32, height: u32 },
+
+    /// `ResourceMiscFlags::GENERATE_MIPS` requires both `RENDER_TARGET` and
+    /// `SHADER_RESOURCE` bind flags.
+    GenerateMipsBindFlags { bind_flags: BindFlags },
+
+    /// `ResourceMiscFlags::TEXTURECUBE` requires an array size that is a
+    /// multiple of 6.
+    CubeArraySize { array_size: u32 },
+
+    /// Multisampled textures cannot have mip maps.
+    MultisampledMipLevels { mip_levels: u32 },
+
+    /// The texture exceeds a limit of the device's feature level.
+    Limit(LimitError),
+}
+
+impl fmt::Display for DescError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DescError::ZeroSize => write!(fmt, "the resource has a size of zero bytes"),
+            DescError::ConstantBufferSize { byte_width } => write!(
+                fmt,
+                "constant buffer size {} is not a multiple of 16 bytes",
+                byte_width
+            ),
+            DescError::ConstantBufferBindFlags { bind_flags } => write!(
+                fmt,
+                "CONSTANT_BUFFER may not be combined with other bind flags (got {:?})",
+                bind_flags
+            ),
+            DescError::MissingStructureStride => {
+                write!(
+                    fmt,
+                    "BUFFER_STRUCTURED requires a non-zero structure stride"
+                )
+            }
+            DescError::InvalidStructureStride { stride } => write!(
+                fmt,
+                "structure stride {} must be a multiple of 4 no larger than 2048",
+                stride
+            ),
+            DescError::StructureStrideMismatch { byte_width, stride } => write!(
+                fmt,
+                "buffer size {} is not a multiple of the structure stride {}",
+                byte_width, stride
+            ),
+            DescError::StructuredRawViews => write!(
+                fmt,
+                "BUFFER_STRUCTURED cannot be combined with BUFFER_ALLOW_RAW_VIEWS"
+            ),
+            DescError::InitialDataTooSmall { expected, actual } => write!(
+                fmt,
+                "initial data contains {} bytes but at least {} are required",
+                actual, expected
+            ),
+            DescError::InitialDataCount { expected, actual } => write!(
+                fmt,
+                "{} initial data entries were given for a texture with {} subresources",
+                actual, expected
+            ),
+            DescError::RowPitchTooSmall {
+                row_pitch,
+                row_bytes,
+            } => write!(
+                fmt,
+                "row pitch {} is smaller than a row of {} bytes",
+                row_pitch, row_bytes
+            ),
+            DescError::SlicePitchTooSmall {
+                slice_pitch,
+                slice_bytes,
+            } => write!(
+                fmt,
+                "slice pitch {} is smaller than a slice of {} bytes",
+                slice_pitch, slice_bytes
+            ),
+            DescError::UnknownFormatSize { format } => write!(
+                fmt,
+                "the texel size of {:?} is not known, initial data cannot be checked",
+                format
+            ),
+            DescError::UnknownEnumValue { field, value } => {
+                write!(fmt, "{} has an unknown value {:#x}", field, value)
+            }
+            DescError::InvalidSubresourceData {
+                subresource,
+                ref error,
+            } => write!(
+                fmt,
+                "initial data for mip {} of array slice {}: {}",
+                subresource.mip, subresource.array_slice, error
+            ),
+            DescError::SubresourceOutOfRange { subresource } => write!(
+                fmt,
+                "mip {} of array slice {} is out of range for this texture",
+                subresource.mip, subresource.array_slice
+            ),
+            DescError::DuplicateSubresourceData { subresource } => write!(
+                fmt,
+                "initial data for mip {} of array slice {} was given more than once",
+                subresource.mip, subresource.array_slice
+            ),
+            DescError::MissingSubresourceData { subresource } => write!(
+                fmt,
+                "no initial data was given for mip {} of array slice {}",
+                subresource.mip, subresource.array_slice
+            ),
+            DescError::ImmutableWithoutInitialData => {
+                write!(fmt, "immutable resources must be created with initial data")
+            }
+            DescError::UsageCpuAccess {
+                usage,
+                cpu_access_flags,
+            } => write!(
+                fmt,
+                "{:?} usage does not allow CPU access flags {:?}",
+                usage, cpu_access_flags
+            ),
+            DescError::StagingBindFlags { bind_flags } => write!(
+                fmt,
+                "staging resources cannot have bind flags (got {:?})",
+                bind_flags
+            ),
+            DescError::UsageBindFlags { usage, bind_flags } => write!(
+                fmt,
+                "{:?} resources cannot be bound as pipeline outputs (got {:?})",
+                usage, bind_flags
+            ),
+            DescError::DynamicSubresources {
+                mip_levels,
+                array_size,
+            } => write!(
+                fmt,
+                "dynamic textures need a single subresource (got {} mip levels and {} slices)",
+                mip_levels, array_size
+            ),
+            DescError::MissingFormat => write!(fmt, "textures must have a format"),
+            DescError::TooManyMipLevels { mip_levels, max } => write!(
+                fmt,
+                "{} mip levels were requested but the full mip chain has {}",
+                mip_levels, max
+            ),
+            DescError::InvalidBindFlags { bind_flags } => {
+                write!(fmt, "invalid combination of bind flags {:?}", bind_flags)
+            }
+            DescError::DepthStencilFormat { format } => write!(
+                fmt,
+                "DEPTH_STENCIL requires a depth or typeless format (got {:?})",
+                format
+            ),
+            DescError::BlockCompressedSize { width, height } => write!(
+                fmt,
+                "block compressed texture size {}x{} is not a multiple of the block size",
+                width, height
+            ),
+            DescError::GenerateMipsBindFlags { bind_flags } => write!(
+                fmt,
+                "GENERATE_MIPS requires RENDER_TARGET and SHADER_RESOURCE (got {:?})",
+                bind_flags
+            ),
+            DescError::CubeArraySize { array_size } => write!(
+                fmt,
+                "TEXTURECUBE requires an array size multiple of 6 (got {})",
+                array_size
+            ),
+            DescError::MultisampledMipLevels { mip_levels } => write!(
+                fmt,
+                "multisampled textures must have a single mip level (got {})",
+                mip_levels
+            ),
+            DescError::Limit(ref err) => write!(fmt, "{}", err),
+        }
+    }
+}
+
+impl StdError for DescError {}
+
+impl From<LimitError> for DescError {
+    fn from(err: LimitError) -> Self {
+        DescError::Limit(err)
+    }
+}
+
+#[cfg(windows)]
+/// The error returned by the resource builders. Either the description was
+/// rejected by the checks done on the Rust side, or the device failed to
+/// create the resource.
+#[derive(Clone, Debug)]
+pub enum BuildError {
+    Desc(DescError),
+    Device(Error),
+}
+
+#[cfg(windows)]
+impl fmt::Display for BuildError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::Desc(ref err) => write!(fmt, "invalid resource description: {}", err),
+            BuildError::Device(ref err) => write!(fmt, "resource creation failed: {:?}", err),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl StdError for BuildError {}
+
+#[cfg(windows)]
+impl From<DescError> for BuildError {
+    fn from(err: DescError) -> Self {
+        BuildError::Desc(err)
+    }
+}
+
+#[cfg(windows)]
+impl From<Error> for BuildError {
+    fn from(err: Error) -> Self {
+        BuildError::Device(err)
+    }
+}
+
+#[cfg(windows)]
+/// Describes why a `DeviceBuilder` could not create a device.
+#[derive(Clone, Debug)]
+pub enum DeviceBuilderError {
+    /// The requested driver type conflicts with another option; an adapter
+    /// requires `DriverType::Unknown` and a software module requires
+    /// `DriverType::Software`.
+    ConflictingDriverType {
+        requested: DriverType,
+        required: DriverType,
+    },
+
+    /// An adapter and a software module cannot both be specified.
+    AdapterAndSoftwareModule,
+
+    /// `DriverType::Software` requires a software module.
+    MissingSoftwareModule,
+
+    /// `DriverType::Unknown` requires an adapter.
+    MissingAdapter,
+
+    /// The flags contain bits that are not valid `CreateDeviceFlags`.
+    InvalidFlags(CreateDeviceFlags),
+
+    /// The flags are valid but not supported.
+    UnsupportedFlags(CreateDeviceFlags),
+
+    /// `CreateDeviceFlags::SINGLETHREADED` was passed to `build()`, which
+    /// returns thread-safe types. Use `build_single_threaded()` instead.
+    SingleThreaded,
+
+    /// No feature level is left to request once the list has been filtered
+    /// by the minimum feature level.
+    NoFeatureLevels,
+
+    /// `D3D11CreateDevice` failed.
+    Creation(Error),
+}
+
+#[cfg(windows)]
+impl fmt::Display for DeviceBuilderError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeviceBuilderError::ConflictingDriverType {
+                requested,
+                required,
+            } => write!(
+                fmt,
+                "driver type {:?} was requested but the other options require {:?}",
+                requested, required
+            ),
+            DeviceBuilderError::AdapterAndSoftwareModule => write!(
+                fmt,
+                "an adapter and a software module cannot both be specified"
+            ),
+            DeviceBuilderError::MissingSoftwareModule => {
+                write!(fmt, "the software driver type requires a software module")
+            }
+            DeviceBuilderError::MissingAdapter => {
+                write!(fmt, "the unknown driver type requires an adapter")
+            }
+            DeviceBuilderError::InvalidFlags(flags) => {
+                write!(fmt, "invalid device creation flags {:?}", flags)
+            }
+            DeviceBuilderError::UnsupportedFlags(flags) => {
+                write!(fmt, "unsupported device creation flags {:?}", flags)
+            }
+            DeviceBuilderError::SingleThreaded => write!(
+                fmt,
+                "single threaded devices must be created with build_single_threaded"
+            ),
+            DeviceBuilderError::NoFeatureLevels => {
+                write!(fmt, "none of the requested feature levels can be used")
+            }
+            DeviceBuilderError::Creation(ref err) => {
+                write!(fmt, "device creation failed: {:?}", err)
+            }
+        }
+    }
+}
+
+#[cfg(windows)]
+impl StdError for DeviceBuilderError {}
+
+#[cfg(windows)]
+impl From<Error> for DeviceBuilderError {
+    fn from(err: Error) -> Self {
+        DeviceBuilderError::Creation(err)
+    }
+}
+
+/// Describes why a resource could not be mapped.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MapError {
+    /// Resources with this usage cannot be mapped with this mode. Default and
+    /// immutable resources cannot be mapped at all, dynamic resources only
+    /// with `Discard` or `WriteNoOverwrite`, and staging resources with
+    /// every mode except those two.
+    UsageMode { map: Map, usage: Usage },
+
+    /// The resource was not created with the CPU access the mode requires.
+    CpuAccess {
+        map: Map,
+        cpu_access_flags: CpuAccessFlags,
+    },
+
+    /// `WriteNoOverwrite` cannot be used on constant buffers.
+    NoOverwriteConstantBuffer,
+
+    /// The resource has no subresource with this index.
+    SubresourceOutOfRange { subresource: u32, count: u32 },
+
+    /// The subresource is already mapped. It has to be unmapped before it
+    /// can be mapped again.
+    AlreadyMapped { subresource: u32 },
+
+    /// Only textures can be viewed as images.
+    NotTexture,
+
+    /// The subresource was mapped with a mode that does not allow this
+    /// access, e.g. reading a mapping made with `Map::Discard`.
+    Access { map: Map },
+
+    /// The description of the resource holds values unknown to this crate.
+    Resource(DescError),
+
+    /// The device context failed to map the resource.
+    #[cfg(windows)]
+    Device(Error),
+}
+
+impl fmt::Display for MapError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MapError::UsageMode { map, usage } => write!(
+                fmt,
+                "resources with usage {:?} cannot be mapped with {:?}",
+                usage, map
+            ),
+            MapError::CpuAccess {
+                map,
+                cpu_access_flags,
+            } => write!(
+                fmt,
+                "mapping with {:?} requires CPU access the resource does not have ({:?})",
+                map, cpu_access_flags
+            ),
+            MapError::NoOverwriteConstantBuffer => {
+                write!(fmt, "constant buffers cannot be mapped with WriteNoOverwrite")
+            }
+            MapError::SubresourceOutOfRange { subresource, count } => write!(
+                fmt,
+                "subresource {} is out of range for a resource with {} subresources",
+                subresource, count
+            ),
+            MapError::AlreadyMapped { subresource } => {
+                write!(fmt, "subresource {} is already mapped", subresource)
+            }
+            MapError::NotTexture => write!(fmt, "only textures can be viewed as images"),
+            MapError::Access { map } => write!(
+                fmt,
+                "the subresource was mapped with {:?}, which does not allow this access",
+                map
+            ),
+            MapError::Resource(ref err) => write!(fmt, "cannot read the resource: {}", err),
+            #[cfg(windows)]
+            MapError::Device(ref err) => write!(fmt, "mapping failed: {:?}", err),
+        }
+    }
+}
+
+impl StdError for MapError {}
+
+impl From<DescError> for MapError {
+    fn from(err: DescError) -> Self {
+        MapError::Resource(err)
+    }
+}
+
+#[cfg(windows)]
+impl From<Error> for MapError {
+    fn from(err: Error) -> Self {
+        MapError::Device(err)
+    }
+}
+
+/// Describes why an update or copy between subresources was rejected.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CopyError {
+    /// The resource has no subresource with this index.
+    SubresourceOutOfRange { subresource: u32, count: u32 },
+
+    /// The box does not contain any texels.
+    EmptyBox { region: Box3D },
+
+    /// The box extends past the edges of a subresource of `size` texels.
+    BoxOutOfBounds { region: Box3D, size: (u32, u32, u32) },
+
+    /// The box is not aligned to the blocks of a block compressed format.
+    /// Edges must be multiples of the block size, except for right and
+    /// bottom edges lying on the edge of the subresource.
+    BoxAlignment { region: Box3D, format: Format },
+
+    /// The formats are not in the same typeless family.
+    IncompatibleFormats { src: Format, dst: Format },
+
+    /// The source image is not the size of the destination region.
+    ImageSize {
+        expected: (u32, u32, u32),
+        actual: (u32, u32, u32),
+    },
+
+    /// The resources are of different kinds, e.g. a buffer and a texture.
+    DimensionMismatch,
+
+    /// Whole-resource copies require the same size, mip count and array
+    /// size. For buffers, the width is the size in bytes.
+    ExtentMismatch {
+        src: TextureExtent,
+        dst: TextureExtent,
+    },
+
+    /// A resource cannot be copied onto itself.
+    SameResource,
+
+    /// A subresource cannot be copied onto itself. Other subresources of
+    /// the same resource can be.
+    SameSubresource { subresource: u32 },
+
+    /// `UpdateSubresource` only writes to textures; use `update_buffer` for
+    /// buffers.
+    ExpectedTexture,
+
+    /// The destination cannot be written by the device context. Updates
+    /// require `Usage::Default`, and copies cannot write to immutable
+    /// resources.
+    DestinationUsage { usage: Usage },
+
+    /// Multisampled and depth-stencil resources can only be copied as whole
+    /// subresources, without a box or offset, and cannot be updated from the
+    /// CPU.
+    WholeSubresourceRequired,
+
+    /// Multisampled resources can only be copied to resources with the same
+    /// sample count.
+    SampleCountMismatch { src: u32, dst: u32 },
+
+    /// Constant buffers can only be updated as a whole.
+    PartialConstantBufferUpdate,
+
+    /// The data is larger than any buffer can be.
+    DataTooLarge { len: usize, byte_width: u32 },
+
+    /// A subresource being copied or updated is mapped. Mapped memory cannot
+    /// change while it is lent out by a `MappedSubresource`.
+    Mapped,
+
+    /// The description of the resource holds values unknown to this crate.
+    Resource(DescError),
+}
+
+impl fmt::Display for CopyError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CopyError::SubresourceOutOfRange { subresource, count } => write!(
+                fmt,
+                "subresource {} is out of range for a resource with {} subresources",
+                subresource, count
+            ),
+            CopyError::EmptyBox { region } => write!(fmt, "the box {:?} is empty", region),
+            CopyError::BoxOutOfBounds { region, size } => write!(
+                fmt,
+                "the box {:?} does not fit in a subresource of size {:?}",
+                region, size
+            ),
+            CopyError::BoxAlignment { region, format } => write!(
+                fmt,
+                "the box {:?} is not aligned to the blocks of {:?}",
+                region, format
+            ),
+            CopyError::IncompatibleFormats { src, dst } => {
+                write!(fmt, "cannot copy from {:?} to {:?}", src, dst)
+            }
+            CopyError::ImageSize { expected, actual } => write!(
+                fmt,
+                "the image has size {:?} but the region has size {:?}",
+                actual, expected
+            ),
+            CopyError::DimensionMismatch => {
+                write!(fmt, "cannot copy between different kinds of resources")
+            }
+            CopyError::ExtentMismatch { src, dst } => write!(
+                fmt,
+                "cannot copy a resource of extent {:?} to one of extent {:?}",
+                src, dst
+            ),
+            CopyError::SameResource => write!(fmt, "cannot copy a resource onto itself"),
+            CopyError::SameSubresource { subresource } => {
+                write!(fmt, "cannot copy subresource {} onto itself", subresource)
+            }
+            CopyError::ExpectedTexture => write!(fmt, "buffers are updated with update_buffer"),
+            CopyError::DestinationUsage { usage } => write!(
+                fmt,
+                "resources with usage {:?} cannot be written by the device context",
+                usage
+            ),
+            CopyError::WholeSubresourceRequired => write!(
+                fmt,
+                "multisampled and depth-stencil resources can only be copied as whole subresources"
+            ),
+            CopyError::SampleCountMismatch { src, dst } => write!(
+                fmt,
+                "cannot copy from {} samples to {} samples",
+                src, dst
+            ),
+            CopyError::PartialConstantBufferUpdate => {
+                write!(fmt, "constant buffers can only be updated as a whole")
+            }
+            CopyError::DataTooLarge { len, byte_width } => write!(
+                fmt,
+                "{} bytes of data do not fit in a buffer of {} bytes",
+                len, byte_width
+            ),
+            CopyError::Mapped => write!(fmt, "cannot copy to or from a mapped subresource"),
+            CopyError::Resource(ref err) => write!(fmt, "cannot read the resource: {}", err),
+        }
+    }
+}
+
+impl StdError for CopyError {}
+
+impl From<DescError> for CopyError {
+    fn from(err: DescError) -> Self {
+        CopyError::Resource(err)
+    }
+}
+
+/// Describes why a view could not be created.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ViewError {
+    /// The resource was not created with the bind flag for this kind of
+    /// view.
+    MissingBindFlag { kind: ViewKind, bind_flags: BindFlags },
+
+    /// Views of this kind cannot have this dimension, e.g. render target
+    /// views of cubes.
+    UnsupportedDimension {
+        kind: ViewKind,
+        dimension: ViewDimension,
+    },
+
+    /// The view dimension does not match the kind of resource.
+    ResourceMismatch {
+        dimension: ViewDimension,
+        resource: ResourceDimension,
+    },
+
+    /// Multisampled textures need multisampled view dimensions, and other
+    /// textures cannot have them.
+    SampleCount {
+        dimension: ViewDimension,
+        sample_count: u32,
+    },
+
+    /// Cube views require a texture created with
+    /// `ResourceMiscFlags::TEXTURECUBE`.
+    NotCube,
+
+    /// Views need a fully typed format. Specify one when viewing a texture
+    /// with a typeless format.
+    TypelessFormat { format: Format },
+
+    /// The view format is not in the typeless family of the resource format.
+    IncompatibleFormat {
+        format: Format,
+        resource_format: Format,
+    },
+
+    /// Depth-stencil views need a depth format, and other views cannot use
+    /// one.
+    FormatAspect { kind: ViewKind, format: Format },
+
+    /// The mip levels are not all in the texture, or more than one mip level
+    /// was given for a view accessing a single one.
+    MipRange { mips: MipRange, mip_count: u32 },
+
+    /// The array or depth slices are not all in the texture.
+    ArrayRange { range: ArrayRange, size: u32 },
+
+    /// A cube array view has more faces than fit in a `u32`.
+    TooManyCubes { num_cubes: u32 },
+
+    /// The elements are not all in the buffer.
+    BufferRange {
+        first_element: u32,
+        num_elements: u32,
+        element_count: u32,
+    },
+
+    /// A buffer view needs a format, or a structured buffer.
+    UnknownElementSize,
+
+    /// The view exceeds a limit of the device's feature level.
+    Limit(LimitError),
+
+    /// The description of the resource holds values unknown to this crate.
+    Resource(DescError),
+
+    /// The device failed to create the view.
+    #[cfg(windows)]
+    Device(Error),
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ViewError::MissingBindFlag { kind, bind_flags } => write!(
+                fmt,
+                "{:?} views require {:?}, but the resource has {:?}",
+                kind,
+                kind.bind_flag(),
+                bind_flags
+            ),
+            ViewError::UnsupportedDimension { kind, dimension } => write!(
+                fmt,
+                "{:?} views cannot have dimension {:?}",
+                kind, dimension
+            ),
+            ViewError::ResourceMismatch {
+                dimension,
+                resource,
+            } => write!(
+                fmt,
+                "view dimension {:?} cannot be used with a {:?}",
+                dimension, resource
+            ),
+            ViewError::SampleCount {
+                dimension,
+                sample_count,
+            } => write!(
+                fmt,
+                "view dimension {:?} cannot be used with a texture of {} samples",
+                dimension, sample_count
+            ),
+            ViewError::NotCube => write!(fmt, "the texture was not created as a cube"),
+            ViewError::TypelessFormat { format } => {
+                write!(fmt, "views cannot use the typeless format {:?}", format)
+            }
+            ViewError::IncompatibleFormat {
+                format,
+                resource_format,
+            } => write!(
+                fmt,
+                "a {:?} resource cannot be viewed as {:?}",
+                resource_format, format
+            ),
+            ViewError::FormatAspect { kind, format } => {
+                write!(fmt, "{:?} views cannot use format {:?}", kind, format)
+            }
+            ViewError::MipRange { mips, mip_count } => write!(
+                fmt,
+                "mip levels {:?} cannot be viewed in a texture with {} mip levels",
+                mips, mip_count
+            ),
+            ViewError::ArrayRange { range, size } => write!(
+                fmt,
+                "slices {:?} cannot be viewed in a texture with {} slices",
+                range, size
+            ),
+            ViewError::TooManyCubes { num_cubes } => {
+                write!(fmt, "a view cannot have the faces of {} cubes", num_cubes)
+            }
+            ViewError::BufferRange {
+                first_element,
+                num_elements,
+                element_count,
+            } => write!(
+                fmt,
+                "{} elements starting at {} cannot be viewed in a buffer of {} elements",
+                num_elements, first_element, element_count
+            ),
+            ViewError::UnknownElementSize => {
+                write!(fmt, "buffer views need a format or a structured buffer")
+            }
+            ViewError::Limit(ref err) => write!(fmt, "{}", err),
+            ViewError::Resource(ref err) => write!(fmt, "cannot read the resource: {}", err),
+            #[cfg(windows)]
+            ViewError::Device(ref err) => write!(fmt, "view creation failed: {:?}", err),
+        }
+    }
+}
+
+impl StdError for ViewError {}
+
+#[cfg(windows)]
+impl From<Error> for ViewError {
+    fn from(err: Error) -> Self {
+        ViewError::Device(err)
+    }
+}
+
+impl From<LimitError> for ViewError {
+    fn from(err: LimitError) -> Self {
+        ViewError::Limit(err)
+    }
+}
+
+impl From<DescError> for ViewError {
+    fn from(err: DescError) -> Self {
+        ViewError::Resource(err)
+    }
+}
+
+#[cfg(windows)]
+/// Describes why a shader could not be created.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ShaderError {
+    /// The bytecode is not a DXBC container, or its checksum does not match
+    /// its contents.
+    Bytecode(DxbcError),
+
+    /// The elements of an input layout do not match the input signature of
+    /// the vertex shader.
+    InputLayout(InputLayoutError),
+
+    /// The device rejected the bytecode.
+    Device(Error),
+}
+
+#[cfg(windows)]
+impl fmt::Display for ShaderError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShaderError::Bytecode(ref err) => write!(fmt, "invalid shader bytecode: {}", err),
+            ShaderError::InputLayout(ref err) => write!(fmt, "invalid input layout: {}", err),
+            ShaderError::Device(ref err) => write!(fmt, "shader creation failed: {:?}", err),
+        }
+    }
+}
+
+#[cfg(windows)]
+impl StdError for ShaderError {}
+
+#[cfg(windows)]
+impl From<DxbcError> for ShaderError {
+    fn from(err: DxbcError) -> Self {
+        ShaderError::Bytecode(err)
+    }
+}
+
+#[cfg(windows)]
+impl From<InputLayoutError> for ShaderError {
+    fn from(err: InputLayoutError) -> Self {
+        ShaderError::InputLayout(err)
+    }
+}
+
+#[cfg(windows)]
+impl From<Error> for ShaderError {
+    fn from(err: Error) -> Self {
+        ShaderError::Device(err)
+    }
+}
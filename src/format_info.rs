@@ -0,0 +1,363 @@
+//! Static metadata about every DXGI format, used to compute the size of
+//! texture data and to decide which formats are compatible with each other.
+//!
+//! On Windows `Format` is the `dxgi` crate's enum. Elsewhere, where `dxgi`
+//! is not available, it is an enum with the same variants generated from
+//! the table, so descriptions can be checked on any platform.
+
+#[cfg(windows)]
+pub use dxgi::enums::Format;
+
+/// Whether a format holds color data or is one of the depth-stencil formats.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Aspect {
+    Color,
+    Depth,
+    DepthStencil,
+}
+
+/// The block compression scheme of a format. Each scheme has its own
+/// typeless family and feature level requirements.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BlockCompression {
+    Bc1,
+    Bc2,
+    Bc3,
+    Bc4,
+    Bc5,
+    Bc6h,
+    Bc7,
+}
+
+/// The extra rows taken up by the chroma planes of a planar format, which
+/// share the row pitch of the luma plane.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+enum ChromaRows {
+    None,
+    /// One plane at half vertical resolution (4:2:0).
+    HalfHeight,
+    /// One plane at full vertical resolution (4:2:2, 4:1:1).
+    FullHeight,
+    /// Two planes at half vertical resolution.
+    TwoHalfHeight,
+    /// Two planes at full vertical resolution.
+    TwoFullHeight,
+}
+
+/// Describes the memory layout and relationships of a DXGI format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FormatInfo {
+    /// The raw `DXGI_FORMAT` value.
+    pub format: u32,
+    /// The name of the format without the `DXGI_FORMAT_` prefix.
+    pub name: &'static str,
+    /// The average number of bits used by a single texel.
+    pub bits_per_pixel: u32,
+    /// The width of a block of texels. 1 unless the format is block
+    /// compressed, packed or subsampled.
+    pub block_width: u32,
+    /// The height of a block of texels. 1 unless the format is block
+    /// compressed.
+    pub block_height: u32,
+    /// The size of a block in bytes. For planar formats, this is the size of
+    /// a block of the luma plane.
+    pub bytes_per_block: u32,
+    pub plane_count: u32,
+    pub aspect: Aspect,
+    typeless: u32,
+    srgb_pair: u32,
+    chroma_rows: ChromaRows,
+    variant: Format,
+}
+
+impl FormatInfo {
+    /// The format information for `format`.
+    ///
+    /// ### panics
+    /// Panics if `format` is not a valid DXGI format.
+    pub fn of(format: Format) -> &'static FormatInfo {
+        FormatInfo::from_raw(format as u32).expect("Format is not a valid DXGI_FORMAT")
+    }
+
+    /// The format information for a raw `DXGI_FORMAT` value.
+    pub fn from_raw(format: u32) -> Option<&'static FormatInfo> {
+        let index = match format {
+            0..=115 => format,
+            130..=132 => format - 14,
+            _ => return None,
+        };
+        Some(&FORMATS[index as usize])
+    }
+
+    /// All formats in the table, in order of their `DXGI_FORMAT` value.
+    pub fn all() -> &'static [FormatInfo] {
+        &FORMATS
+    }
+
+    /// The format as a `Format`.
+    pub fn variant(&self) -> Format {
+        self.variant
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        self.format == 0
+    }
+
+    pub fn is_block_compressed(&self) -> bool {
+        self.block_height > 1
+    }
+
+    /// The block compression scheme of the format, or `None` if it is not
+    /// block compressed.
+    pub fn block_compression(&self) -> Option<BlockCompression> {
+        match self.typeless {
+            70 => Some(BlockCompression::Bc1),
+            73 => Some(BlockCompression::Bc2),
+            76 => Some(BlockCompression::Bc3),
+            79 => Some(BlockCompression::Bc4),
+            82 => Some(BlockCompression::Bc5),
+            94 => Some(BlockCompression::Bc6h),
+            97 => Some(BlockCompression::Bc7),
+            _ => None,
+        }
+    }
+
+    pub fn is_planar(&self) -> bool {
+        self.plane_count > 1
+    }
+
+    pub fn is_typeless(&self) -> bool {
+        self.format != 0 && self.typeless == self.format
+    }
+
+    pub fn is_srgb(&self) -> bool {
+        self.name.ends_with("_SRGB")
+    }
+
+    pub fn is_depth(&self) -> bool {
+        self.aspect != Aspect::Color
+    }
+
+    pub fn is_stencil(&self) -> bool {
+        self.aspect == Aspect::DepthStencil
+    }
+
+    /// The raw value of the typeless format this format belongs to, if
+    /// there is one.
+    pub fn raw_typeless(&self) -> Option<u32> {
+        match self.typeless {
+            0 => None,
+            typeless => Some(typeless),
+        }
+    }
+
+    /// The typeless format this format belongs to, if there is one.
+    pub fn typeless(&self) -> Option<Format> {
+        self.raw_typeless().map(to_format)
+    }
+
+    /// The raw value of the sRGB counterpart of a non-sRGB format, or the
+    /// other way around.
+    pub fn raw_srgb_pair(&self) -> Option<u32> {
+        match self.srgb_pair {
+            0 => None,
+            pair => Some(pair),
+        }
+    }
+
+    /// The sRGB counterpart of a non-sRGB format, or the other way around.
+    pub fn srgb_pair(&self) -> Option<Format> {
+        self.raw_srgb_pair().map(to_format)
+    }
+
+    /// Formats in the same typeless family can be copied between and
+    /// reinterpreted by views. Formats without a typeless family are only
+    /// compatible with themselves.
+    pub fn is_cast_compatible(&self, other: &FormatInfo) -> bool {
+        self.raw_typeless().unwrap_or(self.format) == other.raw_typeless().unwrap_or(other.format)
+    }
+
+    /// The number of bytes in a tightly packed row of blocks covering
+    /// `width` texels.
+    pub fn row_bytes(&self, width: u32) -> u32 {
+        width.div_ceil(self.block_width) * self.bytes_per_block
+    }
+
+    /// The number of rows of blocks covering `height` texels, including the
+    /// rows of any chroma planes.
+    pub fn row_count(&self, height: u32) -> u32 {
+        let rows = height.div_ceil(self.block_height);
+        match self.chroma_rows {
+            ChromaRows::None => rows,
+            ChromaRows::HalfHeight => rows + height.div_ceil(2),
+            ChromaRows::FullHeight => rows * 2,
+            ChromaRows::TwoHalfHeight => rows + height.div_ceil(2) * 2,
+            ChromaRows::TwoFullHeight => rows * 3,
+        }
+    }
+
+    /// The size in bytes of a tightly packed `width` x `height` image.
+    pub fn surface_bytes(&self, width: u32, height: u32) -> u64 {
+        self.row_bytes(width) as u64 * self.row_count(height) as u64
+    }
+}
+
+/// The variant of a format referenced by the table.
+fn to_format(raw: u32) -> Format {
+    FormatInfo::from_raw(raw).unwrap().variant
+}
+
+macro_rules! formats {
+    ($(info!($format:expr, $variant:ident, $name:expr, $bpp:expr, $bw:expr, $bh:expr,
+             $bytes:expr, $planes:expr, $typeless:expr, $srgb:expr, $aspect:ident,
+             $chroma:ident),)*) => {
+        /// The DXGI formats, with the variants and values of `dxgi::enums::Format`.
+        #[cfg(not(windows))]
+        #[allow(non_camel_case_types)]
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(u32)]
+        pub enum Format {
+            $($variant = $format,)*
+        }
+
+        static FORMATS: [FormatInfo; 119] = [$(FormatInfo {
+            format: $format,
+            name: $name,
+            bits_per_pixel: $bpp,
+            block_width: $bw,
+            block_height: $bh,
+            bytes_per_block: $bytes,
+            plane_count: $planes,
+            aspect: Aspect::$aspect,
+            typeless: $typeless,
+            srgb_pair: $srgb,
+            chroma_rows: ChromaRows::$chroma,
+            variant: Format::$variant,
+        },)*];
+    };
+}
+
+#[rustfmt::skip]
+formats! {
+    // format, variant, name, bits per pixel, block width, block height, bytes per block,
+    // planes, typeless family, srgb pair, aspect, chroma rows
+    info!(0,   Unknown,                "UNKNOWN",                    0,   1, 1, 0,  1, 0,  0,  Color,        None),
+    info!(1,   R32G32B32A32Typeless,   "R32G32B32A32_TYPELESS",      128, 1, 1, 16, 1, 1,  0,  Color,        None),
+    info!(2,   R32G32B32A32Float,      "R32G32B32A32_FLOAT",         128, 1, 1, 16, 1, 1,  0,  Color,        None),
+    info!(3,   R32G32B32A32Uint,       "R32G32B32A32_UINT",          128, 1, 1, 16, 1, 1,  0,  Color,        None),
+    info!(4,   R32G32B32A32Sint,       "R32G32B32A32_SINT",          128, 1, 1, 16, 1, 1,  0,  Color,        None),
+    info!(5,   R32G32B32Typeless,      "R32G32B32_TYPELESS",         96,  1, 1, 12, 1, 5,  0,  Color,        None),
+    info!(6,   R32G32B32Float,         "R32G32B32_FLOAT",            96,  1, 1, 12, 1, 5,  0,  Color,        None),
+    info!(7,   R32G32B32Uint,          "R32G32B32_UINT",             96,  1, 1, 12, 1, 5,  0,  Color,        None),
+    info!(8,   R32G32B32Sint,          "R32G32B32_SINT",             96,  1, 1, 12, 1, 5,  0,  Color,        None),
+    info!(9,   R16G16B16A16Typeless,   "R16G16B16A16_TYPELESS",      64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(10,  R16G16B16A16Float,      "R16G16B16A16_FLOAT",         64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(11,  R16G16B16A16Unorm,      "R16G16B16A16_UNORM",         64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(12,  R16G16B16A16Uint,       "R16G16B16A16_UINT",          64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(13,  R16G16B16A16Snorm,      "R16G16B16A16_SNORM",         64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(14,  R16G16B16A16Sint,       "R16G16B16A16_SINT",          64,  1, 1, 8,  1, 9,  0,  Color,        None),
+    info!(15,  R32G32Typeless,         "R32G32_TYPELESS",            64,  1, 1, 8,  1, 15, 0,  Color,        None),
+    info!(16,  R32G32Float,            "R32G32_FLOAT",               64,  1, 1, 8,  1, 15, 0,  Color,        None),
+    info!(17,  R32G32Uint,             "R32G32_UINT",                64,  1, 1, 8,  1, 15, 0,  Color,        None),
+    info!(18,  R32G32Sint,             "R32G32_SINT",                64,  1, 1, 8,  1, 15, 0,  Color,        None),
+    info!(19,  R32G8X24Typeless,       "R32G8X24_TYPELESS",          64,  1, 1, 8,  1, 19, 0,  Color,        None),
+    info!(20,  D32FloatS8X24Uint,      "D32_FLOAT_S8X24_UINT",       64,  1, 1, 8,  1, 19, 0,  DepthStencil, None),
+    info!(21,  R32FloatX8X24Typeless,  "R32_FLOAT_X8X24_TYPELESS",   64,  1, 1, 8,  1, 19, 0,  Color,        None),
+    info!(22,  X32TypelessG8X24Uint,   "X32_TYPELESS_G8X24_UINT",    64,  1, 1, 8,  1, 19, 0,  Color,        None),
+    info!(23,  R10G10B10A2Typeless,    "R10G10B10A2_TYPELESS",       32,  1, 1, 4,  1, 23, 0,  Color,        None),
+    info!(24,  R10G10B10A2Unorm,       "R10G10B10A2_UNORM",          32,  1, 1, 4,  1, 23, 0,  Color,        None),
+    info!(25,  R10G10B10A2Uint,        "R10G10B10A2_UINT",           32,  1, 1, 4,  1, 23, 0,  Color,        None),
+    info!(26,  R11G11B10Float,         "R11G11B10_FLOAT",            32,  1, 1, 4,  1, 0,  0,  Color,        None),
+    info!(27,  R8G8B8A8Typeless,       "R8G8B8A8_TYPELESS",          32,  1, 1, 4,  1, 27, 0,  Color,        None),
+    info!(28,  R8G8B8A8Unorm,          "R8G8B8A8_UNORM",             32,  1, 1, 4,  1, 27, 29, Color,        None),
+    info!(29,  R8G8B8A8UnormSrgb,      "R8G8B8A8_UNORM_SRGB",        32,  1, 1, 4,  1, 27, 28, Color,        None),
+    info!(30,  R8G8B8A8Uint,           "R8G8B8A8_UINT",              32,  1, 1, 4,  1, 27, 0,  Color,        None),
+    info!(31,  R8G8B8A8Snorm,          "R8G8B8A8_SNORM",             32,  1, 1, 4,  1, 27, 0,  Color,        None),
+    info!(32,  R8G8B8A8Sint,           "R8G8B8A8_SINT",              32,  1, 1, 4,  1, 27, 0,  Color,        None),
+    info!(33,  R16G16Typeless,         "R16G16_TYPELESS",            32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(34,  R16G16Float,            "R16G16_FLOAT",               32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(35,  R16G16Unorm,            "R16G16_UNORM",               32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(36,  R16G16Uint,             "R16G16_UINT",                32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(37,  R16G16Snorm,            "R16G16_SNORM",               32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(38,  R16G16Sint,             "R16G16_SINT",                32,  1, 1, 4,  1, 33, 0,  Color,        None),
+    info!(39,  R32Typeless,            "R32_TYPELESS",               32,  1, 1, 4,  1, 39, 0,  Color,        None),
+    info!(40,  D32Float,               "D32_FLOAT",                  32,  1, 1, 4,  1, 39, 0,  Depth,        None),
+    info!(41,  R32Float,               "R32_FLOAT",                  32,  1, 1, 4,  1, 39, 0,  Color,        None),
+    info!(42,  R32Uint,                "R32_UINT",                   32,  1, 1, 4,  1, 39, 0,  Color,        None),
+    info!(43,  R32Sint,                "R32_SINT",                   32,  1, 1, 4,  1, 39, 0,  Color,        None),
+    info!(44,  R24G8Typeless,          "R24G8_TYPELESS",             32,  1, 1, 4,  1, 44, 0,  Color,        None),
+    info!(45,  D24UnormS8Uint,         "D24_UNORM_S8_UINT",          32,  1, 1, 4,  1, 44, 0,  DepthStencil, None),
+    info!(46,  R24UnormX8Typeless,     "R24_UNORM_X8_TYPELESS",      32,  1, 1, 4,  1, 44, 0,  Color,        None),
+    info!(47,  X24TypelessG8Uint,      "X24_TYPELESS_G8_UINT",       32,  1, 1, 4,  1, 44, 0,  Color,        None),
+    info!(48,  R8G8Typeless,           "R8G8_TYPELESS",              16,  1, 1, 2,  1, 48, 0,  Color,        None),
+    info!(49,  R8G8Unorm,              "R8G8_UNORM",                 16,  1, 1, 2,  1, 48, 0,  Color,        None),
+    info!(50,  R8G8Uint,               "R8G8_UINT",                  16,  1, 1, 2,  1, 48, 0,  Color,        None),
+    info!(51,  R8G8Snorm,              "R8G8_SNORM",                 16,  1, 1, 2,  1, 48, 0,  Color,        None),
+    info!(52,  R8G8Sint,               "R8G8_SINT",                  16,  1, 1, 2,  1, 48, 0,  Color,        None),
+    info!(53,  R16Typeless,            "R16_TYPELESS",               16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(54,  R16Float,               "R16_FLOAT",                  16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(55,  D16Unorm,               "D16_UNORM",                  16,  1, 1, 2,  1, 53, 0,  Depth,        None),
+    info!(56,  R16Unorm,               "R16_UNORM",                  16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(57,  R16Uint,                "R16_UINT",                   16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(58,  R16Snorm,               "R16_SNORM",                  16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(59,  R16Sint,                "R16_SINT",                   16,  1, 1, 2,  1, 53, 0,  Color,        None),
+    info!(60,  R8Typeless,             "R8_TYPELESS",                8,   1, 1, 1,  1, 60, 0,  Color,        None),
+    info!(61,  R8Unorm,                "R8_UNORM",                   8,   1, 1, 1,  1, 60, 0,  Color,        None),
+    info!(62,  R8Uint,                 "R8_UINT",                    8,   1, 1, 1,  1, 60, 0,  Color,        None),
+    info!(63,  R8Snorm,                "R8_SNORM",                   8,   1, 1, 1,  1, 60, 0,  Color,        None),
+    info!(64,  R8Sint,                 "R8_SINT",                    8,   1, 1, 1,  1, 60, 0,  Color,        None),
+    info!(65,  A8Unorm,                "A8_UNORM",                   8,   1, 1, 1,  1, 0,  0,  Color,        None),
+    info!(66,  R1Unorm,                "R1_UNORM",                   1,   8, 1, 1,  1, 0,  0,  Color,        None),
+    info!(67,  R9G9B9E5SharedExponent, "R9G9B9E5_SHAREDEXP",         32,  1, 1, 4,  1, 0,  0,  Color,        None),
+    info!(68,  R8G8_B8G8Unorm,         "R8G8_B8G8_UNORM",            16,  2, 1, 4,  1, 0,  0,  Color,        None),
+    info!(69,  G8R8_G8B8Unorm,         "G8R8_G8B8_UNORM",            16,  2, 1, 4,  1, 0,  0,  Color,        None),
+    info!(70,  Bc1Typeless,            "BC1_TYPELESS",               4,   4, 4, 8,  1, 70, 0,  Color,        None),
+    info!(71,  Bc1Unorm,               "BC1_UNORM",                  4,   4, 4, 8,  1, 70, 72, Color,        None),
+    info!(72,  Bc1UnormSrgb,           "BC1_UNORM_SRGB",             4,   4, 4, 8,  1, 70, 71, Color,        None),
+    info!(73,  Bc2Typeless,            "BC2_TYPELESS",               8,   4, 4, 16, 1, 73, 0,  Color,        None),
+    info!(74,  Bc2Unorm,               "BC2_UNORM",                  8,   4, 4, 16, 1, 73, 75, Color,        None),
+    info!(75,  Bc2UnormSrgb,           "BC2_UNORM_SRGB",             8,   4, 4, 16, 1, 73, 74, Color,        None),
+    info!(76,  Bc3Typeless,            "BC3_TYPELESS",               8,   4, 4, 16, 1, 76, 0,  Color,        None),
+    info!(77,  Bc3Unorm,               "BC3_UNORM",                  8,   4, 4, 16, 1, 76, 78, Color,        None),
+    info!(78,  Bc3UnormSrgb,           "BC3_UNORM_SRGB",             8,   4, 4, 16, 1, 76, 77, Color,        None),
+    info!(79,  Bc4Typeless,            "BC4_TYPELESS",               4,   4, 4, 8,  1, 79, 0,  Color,        None),
+    info!(80,  Bc4Unorm,               "BC4_UNORM",                  4,   4, 4, 8,  1, 79, 0,  Color,        None),
+    info!(81,  Bc4Snorm,               "BC4_SNORM",                  4,   4, 4, 8,  1, 79, 0,  Color,        None),
+    info!(82,  Bc5Typeless,            "BC5_TYPELESS",               8,   4, 4, 16, 1, 82, 0,  Color,        None),
+    info!(83,  Bc5Unorm,               "BC5_UNORM",                  8,   4, 4, 16, 1, 82, 0,  Color,        None),
+    info!(84,  Bc5Snorm,               "BC5_SNORM",                  8,   4, 4, 16, 1, 82, 0,  Color,        None),
+    info!(85,  B5G6R5Unorm,            "B5G6R5_UNORM",               16,  1, 1, 2,  1, 0,  0,  Color,        None),
+    info!(86,  B5G5R5A1Unorm,          "B5G5R5A1_UNORM",             16,  1, 1, 2,  1, 0,  0,  Color,        None),
+    info!(87,  B8G8R8A8Unorm,          "B8G8R8A8_UNORM",             32,  1, 1, 4,  1, 90, 91, Color,        None),
+    info!(88,  B8G8R8X8Unorm,          "B8G8R8X8_UNORM",             32,  1, 1, 4,  1, 92, 93, Color,        None),
+    info!(89,  R10G10B10XrBiasA2Unorm, "R10G10B10_XR_BIAS_A2_UNORM", 32,  1, 1, 4,  1, 0,  0,  Color,        None),
+    info!(90,  B8G8R8A8Typeless,       "B8G8R8A8_TYPELESS",          32,  1, 1, 4,  1, 90, 0,  Color,        None),
+    info!(91,  B8G8R8A8UnormSrgb,      "B8G8R8A8_UNORM_SRGB",        32,  1, 1, 4,  1, 90, 87, Color,        None),
+    info!(92,  B8G8R8X8Typeless,       "B8G8R8X8_TYPELESS",          32,  1, 1, 4,  1, 92, 0,  Color,        None),
+    info!(93,  B8G8R8X8UnormSrgb,      "B8G8R8X8_UNORM_SRGB",        32,  1, 1, 4,  1, 92, 88, Color,        None),
+    info!(94,  Bc6hTypeless,           "BC6H_TYPELESS",              8,   4, 4, 16, 1, 94, 0,  Color,        None),
+    info!(95,  Bc6hUf16,               "BC6H_UF16",                  8,   4, 4, 16, 1, 94, 0,  Color,        None),
+    info!(96,  Bc6hSf16,               "BC6H_SF16",                  8,   4, 4, 16, 1, 94, 0,  Color,        None),
+    info!(97,  Bc7Typeless,            "BC7_TYPELESS",               8,   4, 4, 16, 1, 97, 0,  Color,        None),
+    info!(98,  Bc7Unorm,               "BC7_UNORM",                  8,   4, 4, 16, 1, 97, 99, Color,        None),
+    info!(99,  Bc7UnormSrgb,           "BC7_UNORM_SRGB",             8,   4, 4, 16, 1, 97, 98, Color,        None),
+    info!(100, Ayuv,                   "AYUV",                       32,  1, 1, 4,  1, 0,  0,  Color,        None),
+    info!(101, Y410,                   "Y410",                       32,  1, 1, 4,  1, 0,  0,  Color,        None),
+    info!(102, Y416,                   "Y416",                       64,  1, 1, 8,  1, 0,  0,  Color,        None),
+    info!(103, Nv12,                   "NV12",                       12,  2, 1, 2,  2, 0,  0,  Color,        HalfHeight),
+    info!(104, P010,                   "P010",                       24,  2, 1, 4,  2, 0,  0,  Color,        HalfHeight),
+    info!(105, P016,                   "P016",                       24,  2, 1, 4,  2, 0,  0,  Color,        HalfHeight),
+    info!(106, Yuv420Opaque,           "420_OPAQUE",                 12,  2, 1, 2,  2, 0,  0,  Color,        HalfHeight),
+    info!(107, Yuy2,                   "YUY2",                       16,  2, 1, 4,  1, 0,  0,  Color,        None),
+    info!(108, Y210,                   "Y210",                       32,  2, 1, 8,  1, 0,  0,  Color,        None),
+    info!(109, Y216,                   "Y216",                       32,  2, 1, 8,  1, 0,  0,  Color,        None),
+    info!(110, Nv11,                   "NV11",                       12,  4, 1, 4,  2, 0,  0,  Color,        FullHeight),
+    info!(111, Ai44,                   "AI44",                       8,   1, 1, 1,  1, 0,  0,  Color,        None),
+    info!(112, Ia44,                   "IA44",                       8,   1, 1, 1,  1, 0,  0,  Color,        None),
+    info!(113, P8,                     "P8",                         8,   1, 1, 1,  1, 0,  0,  Color,        None),
+    info!(114, A8P8,                   "A8P8",                       16,  1, 1, 2,  1, 0,  0,  Color,        None),
+    info!(115, B4G4R4A4Unorm,          "B4G4R4A4_UNORM",             16,  1, 1, 2,  1, 0,  0,  Color,        None),
+    info!(130, P208,                   "P208",                       16,  2, 1, 2,  2, 0,  0,  Color,        FullHeight),
+    info!(131, V208,                   "V208",                       16,  1, 1, 1,  3, 0,  0,  Color,        TwoHalfHeight),
+    info!(132, V408,                   "V408",                       24,  1, 1, 1,  3, 0,  0,  Color,        TwoFullHeight),
+}
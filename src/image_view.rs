@@ -0,0 +1,343 @@
+//! Views over texture memory whose rows and depth slices are padded out to a
+//! pitch, such as mapped subresources and initial data.
+
+use crate::error::DescError;
+use crate::format_info::{Format, FormatInfo};
+use crate::initial_data::{self, SubresourceData};
+
+use std::mem;
+use std::ops::Range;
+
+/// The shape of an image and where its rows are in memory.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Layout {
+    width: u32,
+    height: u32,
+    depth: u32,
+    row_pitch: u32,
+    slice_pitch: u32,
+    format: Format,
+    row_bytes: u32,
+    rows: u32,
+}
+
+impl Layout {
+    fn new(
+        bytes: &[u8],
+        format: Format,
+        (width, height, depth): (u32, u32, u32),
+        row_pitch: u32,
+        slice_pitch: u32,
+    ) -> Result<Layout, DescError> {
+        let row_bytes = initial_data::row_bytes(format, width)?;
+        let rows = initial_data::row_count(format, height);
+        let data = SubresourceData::new(bytes, row_pitch, slice_pitch);
+        initial_data::check_subresource_size(&data, row_bytes, rows, depth)?;
+
+        Ok(Layout {
+            width,
+            height,
+            depth,
+            row_pitch,
+            slice_pitch,
+            format,
+            row_bytes,
+            rows,
+        })
+    }
+
+    fn packed(bytes: &[u8], format: Format, size: (u32, u32, u32)) -> Result<Layout, DescError> {
+        let (width, height, _) = size;
+        let row_bytes = initial_data::row_bytes(format, width)?;
+        let rows = initial_data::row_count(format, height);
+        Layout::new(bytes, format, size, row_bytes, row_bytes * rows)
+    }
+
+    fn row_range(&self, row: u32, slice: u32) -> Range<usize> {
+        assert!(row < self.rows && slice < self.depth, "row out of range");
+        let start =
+            slice as usize * self.slice_pitch as usize + row as usize * self.row_pitch as usize;
+        start..start + self.row_bytes as usize
+    }
+
+    fn texel_range(&self, x: u32, y: u32, z: u32) -> Option<Range<usize>> {
+        let info = FormatInfo::of(self.format);
+        if info.block_width != 1 || info.block_height != 1 || info.is_planar() {
+            return None;
+        }
+        if x >= self.width || y >= self.height || z >= self.depth {
+            return None;
+        }
+        let texel = info.bytes_per_block as usize;
+        let start = self.row_range(y, z).start + x as usize * texel;
+        Some(start..start + texel)
+    }
+
+    fn packed_len(&self) -> usize {
+        self.row_bytes as usize * self.rows as usize * self.depth as usize
+    }
+
+    fn packed_ranges(&self) -> impl Iterator<Item = (Range<usize>, usize)> + '_ {
+        let row_bytes = self.row_bytes as usize;
+        (0..self.depth)
+            .flat_map(move |z| (0..self.rows).map(move |y| (y, z)))
+            .enumerate()
+            .map(move |(i, (y, z))| (self.row_range(y, z), i * row_bytes))
+    }
+}
+
+macro_rules! layout_accessors {
+    () => {
+        /// The width of the image in texels.
+        pub fn width(&self) -> u32 {
+            self.layout.width
+        }
+
+        /// The height of the image in texels.
+        pub fn height(&self) -> u32 {
+            self.layout.height
+        }
+
+        /// The number of depth slices. 1 unless the image is a 3D texture.
+        pub fn depth(&self) -> u32 {
+            self.layout.depth
+        }
+
+        /// The distance in bytes between the starts of two rows.
+        pub fn row_pitch(&self) -> u32 {
+            self.layout.row_pitch
+        }
+
+        /// The distance in bytes between the starts of two depth slices.
+        pub fn slice_pitch(&self) -> u32 {
+            self.layout.slice_pitch
+        }
+
+        pub fn format(&self) -> Format {
+            self.layout.format
+        }
+
+        /// The number of bytes of texel data in each row, without padding.
+        pub fn row_bytes(&self) -> u32 {
+            self.layout.row_bytes
+        }
+
+        /// The number of rows in each depth slice. For block compressed
+        /// formats a row is a row of blocks, and planar formats include the
+        /// rows of their chroma planes.
+        pub fn row_count(&self) -> u32 {
+            self.layout.rows
+        }
+
+        /// The texel data of a row, without padding.
+        ///
+        /// ### panics
+        /// Panics if the row or slice is out of range.
+        pub fn row(&self, row: u32, slice: u32) -> &[u8] {
+            &self.data[self.layout.row_range(row, slice)]
+        }
+
+        /// Every row of the image, slice by slice, without padding.
+        pub fn rows(&self) -> impl Iterator<Item = &[u8]> + '_ {
+            self.layout
+                .packed_ranges()
+                .map(move |(range, _)| &self.data[range])
+        }
+
+        /// The bytes of a single texel, or `None` if the coordinates are out
+        /// of range or the format is block compressed, packed or planar.
+        pub fn texel(&self, x: u32, y: u32, z: u32) -> Option<&[u8]> {
+            let range = self.layout.texel_range(x, y, z)?;
+            Some(&self.data[range])
+        }
+
+        /// The size of the image with its rows tightly packed.
+        pub fn packed_len(&self) -> usize {
+            self.layout.packed_len()
+        }
+
+        /// Copies the image into `out` with its rows tightly packed.
+        ///
+        /// ### panics
+        /// Panics if `out` is not `packed_len()` bytes long.
+        pub fn copy_to_packed(&self, out: &mut [u8]) {
+            assert_eq!(out.len(), self.packed_len(), "destination size mismatch");
+            let row_bytes = self.layout.row_bytes as usize;
+            for (range, offset) in self.layout.packed_ranges() {
+                out[offset..offset + row_bytes].copy_from_slice(&self.data[range]);
+            }
+        }
+
+        /// The image with its rows tightly packed.
+        pub fn to_packed_vec(&self) -> Vec<u8> {
+            let mut out = vec![0; self.packed_len()];
+            self.copy_to_packed(&mut out);
+            out
+        }
+    };
+}
+
+/// A read-only view of an image stored with padded rows and depth slices.
+#[derive(Copy, Clone, Debug)]
+pub struct ImageView<'a> {
+    data: &'a [u8],
+    layout: Layout,
+}
+
+impl<'a> ImageView<'a> {
+    /// Views `data` as a `width` x `height` x `depth` image of `format`.
+    /// Fails if the pitches cannot hold a row or slice, or if `data` is too
+    /// small. The last row and slice do not need to be padded.
+    pub fn new(
+        data: &'a [u8],
+        format: Format,
+        width: u32,
+        height: u32,
+        depth: u32,
+        row_pitch: u32,
+        slice_pitch: u32,
+    ) -> Result<Self, DescError> {
+        let layout = Layout::new(data, format, (width, height, depth), row_pitch, slice_pitch)?;
+        Ok(ImageView { data, layout })
+    }
+
+    /// Views `data` as an image with tightly packed rows and slices.
+    pub fn packed(
+        data: &'a [u8],
+        format: Format,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Result<Self, DescError> {
+        let layout = Layout::packed(data, format, (width, height, depth))?;
+        Ok(ImageView { data, layout })
+    }
+
+    /// The underlying memory, including padding.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// The image as initial data for a texture subresource.
+    pub fn as_subresource_data(&self) -> SubresourceData<'a> {
+        SubresourceData::new(self.data, self.layout.row_pitch, self.layout.slice_pitch)
+    }
+
+    layout_accessors!();
+}
+
+/// A writable view of an image stored with padded rows and depth slices.
+/// Padding bytes are never written.
+#[derive(Debug)]
+pub struct ImageViewMut<'a> {
+    data: &'a mut [u8],
+    layout: Layout,
+}
+
+impl<'a> ImageViewMut<'a> {
+    /// See [`ImageView::new`](struct.ImageView.html#method.new).
+    pub fn new(
+        data: &'a mut [u8],
+        format: Format,
+        width: u32,
+        height: u32,
+        depth: u32,
+        row_pitch: u32,
+        slice_pitch: u32,
+    ) -> Result<Self, DescError> {
+        let layout = Layout::new(data, format, (width, height, depth), row_pitch, slice_pitch)?;
+        Ok(ImageViewMut { data, layout })
+    }
+
+    /// See [`ImageView::packed`](struct.ImageView.html#method.packed).
+    pub fn packed(
+        data: &'a mut [u8],
+        format: Format,
+        width: u32,
+        height: u32,
+        depth: u32,
+    ) -> Result<Self, DescError> {
+        let layout = Layout::packed(data, format, (width, height, depth))?;
+        Ok(ImageViewMut { data, layout })
+    }
+
+    pub fn as_view(&self) -> ImageView<'_> {
+        ImageView {
+            data: &self.data[..],
+            layout: self.layout,
+        }
+    }
+
+    layout_accessors!();
+
+    /// The texel data of a row, without padding.
+    ///
+    /// ### panics
+    /// Panics if the row or slice is out of range.
+    pub fn row_mut(&mut self, row: u32, slice: u32) -> &mut [u8] {
+        &mut self.data[self.layout.row_range(row, slice)]
+    }
+
+    /// Every row of the image, slice by slice, without padding.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> + '_ {
+        let layout = self.layout;
+        let row_pitch = layout.row_pitch as usize;
+        let slice_pitch = layout.slice_pitch as usize;
+        let row_bytes = layout.row_bytes as usize;
+        let rows = layout.rows as usize;
+        let depth = layout.depth as usize;
+
+        // Split the memory front to back so the rows can be handed out
+        // as independent borrows.
+        let mut rest: &mut [u8] = &mut self.data[..];
+        let mut position = 0;
+        (0..depth)
+            .flat_map(move |z| (0..rows).map(move |y| z * slice_pitch + y * row_pitch))
+            .map(move |start| {
+                let tail = mem::take(&mut rest);
+                let (row, tail) = tail[start - position..].split_at_mut(row_bytes);
+                rest = tail;
+                position = start + row_bytes;
+                row
+            })
+    }
+
+    /// The bytes of a single texel, or `None` if the coordinates are out of
+    /// range or the format is block compressed, packed or planar.
+    pub fn texel_mut(&mut self, x: u32, y: u32, z: u32) -> Option<&mut [u8]> {
+        let range = self.layout.texel_range(x, y, z)?;
+        Some(&mut self.data[range])
+    }
+
+    /// Fills the image from tightly packed rows.
+    ///
+    /// ### panics
+    /// Panics if `src` is not `packed_len()` bytes long.
+    pub fn copy_from_packed(&mut self, src: &[u8]) {
+        assert_eq!(src.len(), self.packed_len(), "source size mismatch");
+        let row_bytes = self.layout.row_bytes as usize;
+        for (range, offset) in self.layout.packed_ranges() {
+            self.data[range].copy_from_slice(&src[offset..offset + row_bytes]);
+        }
+    }
+
+    /// Copies the rows of another image of the same format and size, which
+    /// may have different pitches.
+    ///
+    /// ### panics
+    /// Panics if the images differ in format or size.
+    pub fn copy_from(&mut self, src: &ImageView) {
+        assert!(
+            src.layout.format == self.layout.format
+                && (src.layout.width, src.layout.height, src.layout.depth)
+                    == (self.layout.width, self.layout.height, self.layout.depth),
+            "image format or size mismatch"
+        );
+        for z in 0..self.layout.depth {
+            for y in 0..self.layout.rows {
+                let dst = self.layout.row_range(y, z);
+                self.data[dst].copy_from_slice(src.row(y, z));
+            }
+        }
+    }
+}
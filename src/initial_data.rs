@@ -0,0 +1,253 @@
+//! Initial data for texture resources, and the checks that make sure it
+//! covers the texture being created.
+
+use crate::error::DescError;
+use crate::format_info::{Format, FormatInfo};
+use crate::subresource::{self, Subresource};
+
+#[cfg(windows)]
+use std::ptr;
+
+#[cfg(windows)]
+use winapi::um::d3d11::D3D11_SUBRESOURCE_DATA;
+
+/// The initial contents of a single subresource.
+#[derive(Copy, Clone, Debug)]
+pub struct SubresourceData<'a> {
+    pub bytes: &'a [u8],
+    /// The distance in bytes between the start of two rows. Ignored for
+    /// 1D textures.
+    pub row_pitch: u32,
+    /// The distance in bytes between the start of two depth slices. Only
+    /// used for 3D textures.
+    pub slice_pitch: u32,
+}
+
+impl<'a> SubresourceData<'a> {
+    pub fn new(bytes: &'a [u8], row_pitch: u32, slice_pitch: u32) -> Self {
+        SubresourceData {
+            bytes,
+            row_pitch,
+            slice_pitch,
+        }
+    }
+}
+
+/// The number of bytes in a tightly packed row of `width` texels, or an
+/// error if the size of a texel of `format` is not known.
+pub fn row_bytes(format: Format, width: u32) -> Result<u32, DescError> {
+    let info = FormatInfo::of(format);
+    if info.is_unknown() {
+        return Err(DescError::UnknownFormatSize { format });
+    }
+    Ok(info.row_bytes(width))
+}
+
+/// The number of rows of `format` needed to cover `height` texels. Block
+/// compressed formats store a row of blocks for every 4 rows of texels, and
+/// planar formats add the rows of their chroma planes.
+pub fn row_count(format: Format, height: u32) -> u32 {
+    FormatInfo::of(format).row_count(height)
+}
+
+/// Checks that `data` contains `depth` slices of `rows` rows that are
+/// `row_bytes` long. For block compressed formats, a row is a row of blocks.
+///
+/// The last row of each slice and the last slice of the image do not need
+/// to be padded out to the full pitch.
+pub fn check_subresource_size(
+    data: &SubresourceData,
+    row_bytes: u32,
+    rows: u32,
+    depth: u32,
+) -> Result<(), DescError> {
+    let row_bytes = row_bytes as usize;
+    let row_pitch = data.row_pitch as usize;
+    let slice_pitch = data.slice_pitch as usize;
+
+    let mut slice_bytes = row_bytes;
+    if rows > 1 {
+        if row_pitch < row_bytes {
+            return Err(DescError::RowPitchTooSmall {
+                row_pitch: data.row_pitch,
+                row_bytes: row_bytes as u32,
+            });
+        }
+        slice_bytes += row_pitch * (rows as usize - 1);
+    }
+
+    let mut expected = slice_bytes;
+    if depth > 1 {
+        if slice_pitch < slice_bytes {
+            return Err(DescError::SlicePitchTooSmall {
+                slice_pitch: data.slice_pitch,
+                slice_bytes: slice_bytes as u32,
+            });
+        }
+        expected += slice_pitch * (depth as usize - 1);
+    }
+
+    if data.bytes.len() < expected {
+        return Err(DescError::InitialDataTooSmall {
+            expected,
+            actual: data.bytes.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// The size of a texture, used to check initial data against each mip
+/// level. Unused dimensions are 1.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TextureExtent {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub mip_levels: u32,
+    pub array_size: u32,
+}
+
+impl TextureExtent {
+    /// The number of mip levels, with 0 expanded to the full mip chain.
+    pub fn mip_count(&self) -> u32 {
+        match self.mip_levels {
+            0 => subresource::full_mip_count(self.width, self.height, self.depth),
+            levels => levels,
+        }
+    }
+
+    pub fn subresource_count(&self) -> u32 {
+        self.mip_count() * self.array_size
+    }
+}
+
+/// Checks initial data for every subresource of a texture, given in
+/// subresource order (every mip of the first array slice, then every mip of
+/// the next). Each entry must cover the halved dimensions of its mip level.
+pub fn check_subresources(
+    items: &[SubresourceData],
+    format: Format,
+    extent: &TextureExtent,
+) -> Result<(), DescError> {
+    check_subresource_count(items.len(), extent.subresource_count())?;
+
+    let mip_count = extent.mip_count();
+    for (i, item) in items.iter().enumerate() {
+        let subresource = Subresource::from_index(i as u32, mip_count);
+        let mip = subresource.mip;
+        let width = subresource::mip_size(extent.width, mip);
+        let height = subresource::mip_size(extent.height, mip);
+        let depth = subresource::mip_size(extent.depth, mip);
+
+        row_bytes(format, width)
+            .and_then(|row_bytes| {
+                check_subresource_size(item, row_bytes, row_count(format, height), depth)
+            })
+            .map_err(|err| DescError::InvalidSubresourceData {
+                subresource,
+                error: Box::new(err),
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the number of initial data entries matches the number of
+/// subresources in the texture.
+pub fn check_subresource_count(count: usize, subresources: u32) -> Result<(), DescError> {
+    if count != subresources as usize {
+        return Err(DescError::InitialDataCount {
+            expected: subresources as usize,
+            actual: count,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+pub(crate) enum InitialData<'a> {
+    None,
+    Single(SubresourceData<'a>),
+    Vec(Vec<SubresourceData<'a>>),
+    Indexed(Vec<(Subresource, SubresourceData<'a>)>),
+}
+
+#[cfg(windows)]
+impl<'a> InitialData<'a> {
+    /// Adds the data for a single subresource, discarding anything that was
+    /// not itself given by subresource.
+    pub(crate) fn insert(&mut self, subresource: Subresource, data: SubresourceData<'a>) {
+        match *self {
+            InitialData::Indexed(ref mut items) => items.push((subresource, data)),
+            _ => *self = InitialData::Indexed(vec![(subresource, data)]),
+        }
+    }
+
+    /// Whether no initial data was given.
+    pub(crate) fn is_empty(&self) -> bool {
+        match *self {
+            InitialData::None => true,
+            InitialData::Single(_) => false,
+            InitialData::Vec(ref items) => items.is_empty(),
+            InitialData::Indexed(ref items) => items.is_empty(),
+        }
+    }
+
+    /// All of the initial data in subresource order.
+    pub(crate) fn ordered(
+        &self,
+        extent: &TextureExtent,
+    ) -> Result<Vec<SubresourceData<'a>>, DescError> {
+        match *self {
+            InitialData::None => Ok(vec![]),
+            InitialData::Single(item) => Ok(vec![item]),
+            InitialData::Vec(ref items) => Ok(items.clone()),
+            InitialData::Indexed(ref items) => {
+                let mip_count = extent.mip_count();
+                let mut ordered = vec![None; extent.subresource_count() as usize];
+                for &(subresource, item) in items {
+                    if subresource.mip >= mip_count || subresource.array_slice >= extent.array_size
+                    {
+                        return Err(DescError::SubresourceOutOfRange { subresource });
+                    }
+                    let slot = &mut ordered[subresource.index(mip_count) as usize];
+                    if slot.is_some() {
+                        return Err(DescError::DuplicateSubresourceData { subresource });
+                    }
+                    *slot = Some(item);
+                }
+
+                ordered
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        item.ok_or(DescError::MissingSubresourceData {
+                            subresource: Subresource::from_index(i as u32, mip_count),
+                        })
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Builds the array passed to the `Create*` methods, or null if there is no
+/// initial data.
+#[cfg(windows)]
+pub(crate) fn to_desc(
+    items: &[SubresourceData],
+    data: &mut Vec<D3D11_SUBRESOURCE_DATA>,
+) -> *const D3D11_SUBRESOURCE_DATA {
+    if items.is_empty() {
+        return ptr::null();
+    }
+
+    data.clear();
+    data.extend(items.iter().map(|item| D3D11_SUBRESOURCE_DATA {
+        pSysMem: item.bytes.as_ptr() as *const _,
+        SysMemPitch: item.row_pitch,
+        SysMemSlicePitch: item.slice_pitch,
+    }));
+    data.as_ptr()
+}
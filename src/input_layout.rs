@@ -0,0 +1,143 @@
+//! Input layouts, which tell the input assembler how to read vertex buffers
+//! into the inputs of a vertex shader.
+
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::dxbc::{Container, DxbcError, FourCC};
+use crate::error::ShaderError;
+use crate::vertex::{self, InputClassification, InputElement, Vertex};
+
+use std::ffi::CString;
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use winapi::um::d3d11::{ID3D11DeviceChild, ID3D11InputLayout, D3D11_INPUT_ELEMENT_DESC};
+use winapi::um::d3d11::{D3D11_INPUT_PER_INSTANCE_DATA, D3D11_INPUT_PER_VERTEX_DATA};
+use wio::com::ComPtr;
+
+#[derive(ComWrapper, Clone, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct InputLayout {
+    ptr: ComPtr<ID3D11InputLayout>,
+}
+
+impl InputLayout {
+    /// Starts an input layout for the vertex shader compiled to `bytecode`.
+    /// The layout can be used with any vertex shader with the same input
+    /// signature.
+    pub fn create<'a, 'b>(
+        device: &'a dyn IDevice,
+        bytecode: &'b [u8],
+    ) -> InputLayoutBuilder<'a, 'b> {
+        InputLayoutBuilder::new(device, bytecode)
+    }
+}
+
+unsafe impl IDeviceChild for InputLayout {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+pub struct InputLayoutBuilder<'a, 'b> {
+    device: &'a dyn IDevice,
+    bytecode: &'b [u8],
+    elements: Vec<InputElement>,
+    unchecked_layout: bool,
+}
+
+impl<'a, 'b> InputLayoutBuilder<'a, 'b> {
+    pub fn new(device: &'a dyn IDevice, bytecode: &'b [u8]) -> Self {
+        InputLayoutBuilder {
+            device,
+            bytecode,
+            elements: Vec::new(),
+            unchecked_layout: false,
+        }
+    }
+
+    /// Adds the elements of a vertex type, read from the vertex buffer bound
+    /// to `slot`.
+    pub fn with_vertex<V: Vertex>(mut self, slot: u32) -> Self {
+        self.elements
+            .extend(V::elements().into_iter().map(|element| InputElement {
+                input_slot: slot,
+                ..element
+            }));
+        self
+    }
+
+    /// Adds elements as they are, for layouts without a vertex type.
+    pub fn with_elements(mut self, elements: &[InputElement]) -> Self {
+        self.elements.extend_from_slice(elements);
+        self
+    }
+
+    /// The elements added so far.
+    pub fn elements(&self) -> &[InputElement] {
+        &self.elements
+    }
+
+    /// Disables the check that the elements match the input signature of
+    /// the shader. Direct3D still rejects elements it cannot bind, without
+    /// saying why outside the debug layer.
+    pub fn with_unchecked_layout(mut self) -> Self {
+        self.unchecked_layout = true;
+        self
+    }
+
+    /// Creates the layout. The bytecode is checked like it is for
+    /// `VertexShader::create`, then the elements are checked against its
+    /// input signature with `vertex::validate_input_layout`.
+    ///
+    /// ### panics
+    /// Panics if a semantic name contains a nul byte.
+    pub fn build(self) -> Result<InputLayout, ShaderError> {
+        let container = Container::parse(self.bytecode)?;
+        if !self.unchecked_layout {
+            let signature = container
+                .input_signature()?
+                .ok_or(DxbcError::MissingChunk(FourCC::ISGN))?;
+            vertex::validate_input_layout(&self.elements, &signature)?;
+        }
+
+        let names: Vec<CString> = self
+            .elements
+            .iter()
+            .map(|element| {
+                CString::new(element.semantic_name).expect("semantic names cannot contain nul")
+            })
+            .collect();
+        let descs: Vec<D3D11_INPUT_ELEMENT_DESC> = self
+            .elements
+            .iter()
+            .zip(&names)
+            .map(|(element, name)| D3D11_INPUT_ELEMENT_DESC {
+                SemanticName: name.as_ptr(),
+                SemanticIndex: element.semantic_index,
+                Format: element.format.to_raw(),
+                InputSlot: element.input_slot,
+                AlignedByteOffset: element.offset,
+                InputSlotClass: match element.classification {
+                    InputClassification::PerVertex => D3D11_INPUT_PER_VERTEX_DATA,
+                    InputClassification::PerInstance => D3D11_INPUT_PER_INSTANCE_DATA,
+                },
+                InstanceDataStepRate: element.instance_step_rate,
+            })
+            .collect();
+
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateInputLayout(
+                descs.as_ptr(),
+                descs.len() as u32,
+                self.bytecode.as_ptr() as *const _,
+                self.bytecode.len(),
+                &mut ptr,
+            );
+            Ok(Error::map_if(hr, || InputLayout::from_raw(ptr))?)
+        }
+    }
+}
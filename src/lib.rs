@@ -1,12 +1,58 @@
-#![cfg(windows)]
+//! Safe bindings to Direct3D 11. The COM wrappers are only available on
+//! Windows. Everything that does not call into Direct3D is available on
+//! every platform: the `dxbc` module, which parses compiled shaders, the
+//! `image_view` module, which reads and writes pitched image data, the
+//! `vertex` module, which describes vertex structs, the enums, the format
+//! table, the limits of each feature level, and the resource, view and state
+//! descriptions with the checks done on them.
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for the
+//! enums, flag sets and descriptions. Flag sets are written as lists of flag
+//! names, e.g. `["SHADER_RESOURCE", "RENDER_TARGET"]`, and formats as the
+//! names of their formats, e.g. `"R8G8B8A8_UNORM"`.
 
+#[cfg(windows)]
+pub use crate::buffer::Buffer;
+#[cfg(windows)]
 pub use crate::device::Device;
+#[cfg(windows)]
 pub use crate::device_context::DeviceContext;
+#[cfg(windows)]
+pub use crate::texture1d::Texture1D;
+#[cfg(windows)]
 pub use crate::texture2d::Texture2D;
+#[cfg(windows)]
+pub use crate::texture3d::Texture3D;
 
+pub mod buffer;
+#[cfg(windows)]
+mod copy;
+#[cfg(windows)]
 pub mod device;
+#[cfg(windows)]
 pub mod device_context;
+pub mod dxbc;
 pub mod enums;
+pub mod error;
+pub mod format_info;
+pub mod image_view;
+pub mod initial_data;
+#[cfg(windows)]
+pub mod input_layout;
+pub mod limits;
+#[cfg(windows)]
+pub mod mapped_subresource;
+pub mod texture1d;
 pub mod texture2d;
+pub mod texture3d;
+#[cfg(windows)]
 pub mod resource;
+#[cfg(windows)]
+pub mod shader;
+pub mod state;
+pub mod subresource;
+pub mod validation;
+pub mod vertex;
+pub mod view;
+#[cfg(windows)]
 pub mod device_child;
@@ -0,0 +1,263 @@
+//! What a device can do at each feature level: the largest textures it can
+//! create and the features it is guaranteed to have. The texture and view
+//! builders check their descriptions against the limits of the device's
+//! level, so exceeding one is reported before Direct3D rejects it.
+//!
+//! ```
+//! use direct3d11::enums::FeatureLevel;
+//! use direct3d11::limits::Feature;
+//!
+//! let limits = FeatureLevel::LEVEL_10_0.limits();
+//! assert_eq!(limits.max_texture2d_dimension, 8192);
+//! assert!(!limits.supports(Feature::Bc6hBc7));
+//! assert!(limits.check_texture2d(16384, 16384, 1, false).is_err());
+//! ```
+
+use crate::enums::FeatureLevel;
+
+use std::error::Error as StdError;
+use std::fmt;
+
+/// The limits of a feature level. Devices may do more through optional
+/// features queried with `CheckFeatureSupport`, but every device of the
+/// level does at least this much.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Limits {
+    /// The level these are the limits of.
+    pub level: FeatureLevel,
+    pub max_texture1d_dimension: u32,
+    pub max_texture2d_dimension: u32,
+    pub max_texture3d_dimension: u32,
+    /// The largest width and height of the faces of a cube.
+    pub max_cube_dimension: u32,
+    /// The most slices of a 1D or 2D texture array. Below 10_0 textures
+    /// cannot be arrays, but cubes still have 6 faces.
+    pub max_array_size: u32,
+    /// The most render targets bound at once.
+    pub max_render_targets: u32,
+    /// The most unordered access views bound at once. 0 if textures cannot
+    /// have unordered access views.
+    pub max_uav_slots: u32,
+    pub compute_shaders: bool,
+    pub cube_arrays: bool,
+    /// Whether the BC6H and BC7 block compressed formats are supported.
+    pub bc6h_bc7: bool,
+}
+
+impl FeatureLevel {
+    /// The limits of this level. Levels between or beyond the known ones
+    /// have the limits of the known level below them.
+    pub fn limits(self) -> Limits {
+        let row = LIMITS
+            .iter()
+            .rev()
+            .find(|row| row.level <= self)
+            .unwrap_or(&LIMITS[0]);
+        Limits {
+            level: self,
+            ..*row
+        }
+    }
+}
+
+/// The features tested by `Limits::supports`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Feature {
+    ComputeShaders,
+    CubeArrays,
+    Bc6hBc7,
+    /// Unordered access views of textures, and the `UNORDERED_ACCESS` bind
+    /// flag on textures.
+    UnorderedAccess,
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.write_str(match *self {
+            Feature::ComputeShaders => "compute shaders",
+            Feature::CubeArrays => "cube arrays",
+            Feature::Bc6hBc7 => "BC6H and BC7 formats",
+            Feature::UnorderedAccess => "unordered access views of textures",
+        })
+    }
+}
+
+impl Limits {
+    pub fn supports(&self, feature: Feature) -> bool {
+        match feature {
+            Feature::ComputeShaders => self.compute_shaders,
+            Feature::CubeArrays => self.cube_arrays,
+            Feature::Bc6hBc7 => self.bc6h_bc7,
+            Feature::UnorderedAccess => self.max_uav_slots > 0,
+        }
+    }
+
+    /// Fails if `feature` is not supported.
+    pub fn require(&self, feature: Feature) -> Result<(), LimitError> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(LimitError::Unsupported {
+                feature,
+                level: self.level,
+            })
+        }
+    }
+
+    pub fn check_texture1d(&self, width: u32, array_size: u32) -> Result<(), LimitError> {
+        self.check_size(width, self.max_texture1d_dimension)?;
+        self.check_array_size(array_size)
+    }
+
+    /// Checks a 2D texture, or with `cube` the faces of a cube texture with
+    /// `array_size / 6` cubes.
+    pub fn check_texture2d(
+        &self,
+        width: u32,
+        height: u32,
+        array_size: u32,
+        cube: bool,
+    ) -> Result<(), LimitError> {
+        self.check_size(width.max(height), self.max_texture2d_dimension)?;
+        if !cube {
+            return self.check_array_size(array_size);
+        }
+
+        let size = width.max(height);
+        if size > self.max_cube_dimension {
+            return Err(LimitError::CubeSize {
+                size,
+                max: self.max_cube_dimension,
+                level: self.level,
+            });
+        }
+        if array_size > 6 {
+            self.require(Feature::CubeArrays)?;
+            self.check_array_size(array_size)?;
+        }
+        Ok(())
+    }
+
+    pub fn check_texture3d(&self, width: u32, height: u32, depth: u32) -> Result<(), LimitError> {
+        self.check_size(width.max(height).max(depth), self.max_texture3d_dimension)
+    }
+
+    fn check_size(&self, size: u32, max: u32) -> Result<(), LimitError> {
+        if size > max {
+            return Err(LimitError::TextureSize {
+                size,
+                max,
+                level: self.level,
+            });
+        }
+        Ok(())
+    }
+
+    fn check_array_size(&self, array_size: u32) -> Result<(), LimitError> {
+        if array_size > self.max_array_size {
+            return Err(LimitError::ArraySize {
+                array_size,
+                max: self.max_array_size,
+                level: self.level,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Describes a limit of a feature level that a description exceeds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LimitError {
+    /// A dimension of the texture is larger than the level allows.
+    TextureSize {
+        size: u32,
+        max: u32,
+        level: FeatureLevel,
+    },
+
+    /// The faces of the cube are larger than the level allows.
+    CubeSize {
+        size: u32,
+        max: u32,
+        level: FeatureLevel,
+    },
+
+    /// The texture has more array slices than the level allows.
+    ArraySize {
+        array_size: u32,
+        max: u32,
+        level: FeatureLevel,
+    },
+
+    /// The level does not have a feature the description needs.
+    Unsupported {
+        feature: Feature,
+        level: FeatureLevel,
+    },
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LimitError::TextureSize { size, max, level } => write!(
+                fmt,
+                "texture size {} is larger than the maximum of {} at feature level {}",
+                size, max, level
+            ),
+            LimitError::CubeSize { size, max, level } => write!(
+                fmt,
+                "cube size {} is larger than the maximum of {} at feature level {}",
+                size, max, level
+            ),
+            LimitError::ArraySize {
+                array_size,
+                max,
+                level,
+            } => write!(
+                fmt,
+                "array size {} is larger than the maximum of {} at feature level {}",
+                array_size, max, level
+            ),
+            LimitError::Unsupported { feature, level } => write!(
+                fmt,
+                "{} are not supported at feature level {}",
+                feature, level
+            ),
+        }
+    }
+}
+
+impl StdError for LimitError {}
+
+macro_rules! limits {
+    ($($level:ident: $d1:expr, $d2:expr, $d3:expr, $cube:expr, $array:expr, $rts:expr,
+       $uavs:expr, $cs:expr, $cube_arrays:expr, $bc7:expr;)*) => {
+        /// From the feature level tables of the Direct3D documentation, in
+        /// increasing order.
+        const LIMITS: &[Limits] = &[$(Limits {
+            level: FeatureLevel::$level,
+            max_texture1d_dimension: $d1,
+            max_texture2d_dimension: $d2,
+            max_texture3d_dimension: $d3,
+            max_cube_dimension: $cube,
+            max_array_size: $array,
+            max_render_targets: $rts,
+            max_uav_slots: $uavs,
+            compute_shaders: $cs,
+            cube_arrays: $cube_arrays,
+            bc6h_bc7: $bc7,
+        },)*];
+    };
+}
+
+#[rustfmt::skip]
+limits! {
+    //          1D     2D     3D    cube   array RTs UAVs  CS     cube[] BC7
+    LEVEL_9_1:  2048,  2048,  256,  512,   1,    1,  0,  false, false, false;
+    LEVEL_9_2:  2048,  2048,  256,  512,   1,    1,  0,  false, false, false;
+    LEVEL_9_3:  4096,  4096,  256,  4096,  1,    4,  0,  false, false, false;
+    LEVEL_10_0: 8192,  8192,  2048, 8192,  512,  8,  0,  false, false, false;
+    LEVEL_10_1: 8192,  8192,  2048, 8192,  512,  8,  0,  false, true,  false;
+    LEVEL_11_0: 16384, 16384, 2048, 16384, 2048, 8,  8,  true,  true,  true;
+    LEVEL_11_1: 16384, 16384, 2048, 16384, 2048, 8,  64, true,  true,  true;
+}
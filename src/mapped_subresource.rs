@@ -0,0 +1,200 @@
+use crate::enums::Map;
+use crate::error::MapError;
+use crate::format_info::FormatInfo;
+use crate::image_view::{ImageView, ImageViewMut};
+use crate::resource::ResourceInfo;
+use crate::validation;
+
+use std::mem;
+use std::slice;
+use std::sync::{Mutex, MutexGuard};
+
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::{ID3D11DeviceContext, ID3D11Resource};
+use wio::com::ComPtr;
+
+/// A subresource mapped into CPU memory by `IDeviceContext::map`. The
+/// subresource is unmapped when the guard is dropped.
+///
+/// Texture rows are `row_pitch` bytes apart and depth slices `depth_pitch`
+/// bytes apart; both may be larger than the texel data they hold.
+pub struct MappedSubresource<'ctx> {
+    ctx: &'ctx ID3D11DeviceContext,
+    resource: ComPtr<ID3D11Resource>,
+    subresource: u32,
+    map: Map,
+    data: *mut u8,
+    len: usize,
+    row_pitch: u32,
+    depth_pitch: u32,
+    image: Option<(Format, u32, u32, u32)>,
+}
+
+impl<'ctx> MappedSubresource<'ctx> {
+    /// The mode the subresource was mapped with.
+    pub fn mode(&self) -> Map {
+        self.map
+    }
+
+    /// The distance in bytes between the starts of two rows.
+    pub fn row_pitch(&self) -> u32 {
+        self.row_pitch
+    }
+
+    /// The distance in bytes between the starts of two depth slices.
+    pub fn depth_pitch(&self) -> u32 {
+        self.depth_pitch
+    }
+
+    /// The mapped memory, from the first byte of the first row to the last
+    /// byte of texel data in the last row, or `None` if the subresource was
+    /// mapped for writing only. Such mappings do not hold the contents of
+    /// the resource and may be write-combined, so they cannot be read.
+    pub fn data(&self) -> Option<&[u8]> {
+        match self.map {
+            Map::Read | Map::ReadWrite => {
+                Some(unsafe { slice::from_raw_parts(self.data, self.len) })
+            }
+            _ => None,
+        }
+    }
+
+    /// The mapped memory for writing, or `None` if the subresource was mapped
+    /// with `Map::Read`.
+    pub fn data_mut(&mut self) -> Option<&mut [u8]> {
+        match self.map {
+            Map::Read => None,
+            _ => Some(unsafe { slice::from_raw_parts_mut(self.data, self.len) }),
+        }
+    }
+
+    /// A view of the mapped texture data. Fails if a buffer was mapped or
+    /// the subresource was mapped for writing only.
+    pub fn image_view(&self) -> Result<ImageView<'_>, MapError> {
+        let (format, width, height, depth) = self.image.ok_or(MapError::NotTexture)?;
+        let data = self.data().ok_or(MapError::Access { map: self.map })?;
+        let view = ImageView::new(
+            data,
+            format,
+            width,
+            height,
+            depth,
+            self.row_pitch,
+            self.depth_pitch,
+        )?;
+        Ok(view)
+    }
+
+    /// A writable view of the mapped texture data. Fails if a buffer was
+    /// mapped or the subresource was mapped with `Map::Read`.
+    pub fn image_view_mut(&mut self) -> Result<ImageViewMut<'_>, MapError> {
+        let (format, width, height, depth) = self.image.ok_or(MapError::NotTexture)?;
+        let (map, row_pitch, depth_pitch) = (self.map, self.row_pitch, self.depth_pitch);
+        let data = self.data_mut().ok_or(MapError::Access { map })?;
+        let view = ImageViewMut::new(data, format, width, height, depth, row_pitch, depth_pitch)?;
+        Ok(view)
+    }
+
+    /// Unmaps the subresource. Same as dropping the guard.
+    pub fn unmap(self) {}
+}
+
+impl<'ctx> Drop for MappedSubresource<'ctx> {
+    fn drop(&mut self) {
+        unsafe {
+            self.ctx.Unmap(self.resource.as_raw(), self.subresource);
+        }
+        let key = (self.resource.as_raw() as usize, self.subresource);
+        mapped().retain(|&entry| entry != key);
+    }
+}
+
+/// The subresources held by a `MappedSubresource`, by the address of their
+/// resource. The guards keep their resources alive, so an address cannot be
+/// reused while it is in the list.
+static MAPPED: Mutex<Vec<(usize, u32)>> = Mutex::new(Vec::new());
+
+fn mapped() -> MutexGuard<'static, Vec<(usize, u32)>> {
+    MAPPED.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// Whether `subresource` of `resource` is mapped, or any of its subresources
+/// if `subresource` is `None`. The device context must not write to mapped
+/// memory while a guard lends it out.
+pub(crate) fn is_mapped(resource: &ID3D11Resource, subresource: Option<u32>) -> bool {
+    let address = resource as *const _ as usize;
+    mapped()
+        .iter()
+        .any(|&(res, sub)| res == address && subresource.is_none_or(|s| s == sub))
+}
+
+/// The format and size of a texture subresource, or `None` for buffers.
+fn image(info: &ResourceInfo, subresource: u32) -> Option<(Format, u32, u32, u32)> {
+    if info.is_buffer() {
+        return None;
+    }
+    let (width, height, depth) = info.subresource_size(subresource);
+    Some((info.format, width, height, depth))
+}
+
+/// The number of bytes that can be accessed through a mapping of
+/// `subresource` with the given pitches.
+fn mapped_len(info: &ResourceInfo, subresource: u32, row_pitch: u32, depth_pitch: u32) -> usize {
+    match image(info, subresource) {
+        None => info.extent.width as usize,
+        Some((format, width, height, depth)) => {
+            let format = FormatInfo::of(format);
+            (depth as usize - 1) * depth_pitch as usize
+                + (format.row_count(height) as usize - 1) * row_pitch as usize
+                + format.row_bytes(width) as usize
+        }
+    }
+}
+
+/// Checks `map` against the resource and maps it. Called by
+/// `IDeviceContext::map`.
+pub(crate) unsafe fn map<'ctx>(
+    ctx: &'ctx ID3D11DeviceContext,
+    resource: &ID3D11Resource,
+    subresource: u32,
+    map: Map,
+) -> Result<MappedSubresource<'ctx>, MapError> {
+    let info = ResourceInfo::of(resource)?;
+    validation::validate_map(map, info.usage, info.bind_flags, info.cpu_access_flags)?;
+    let count = info.subresource_count();
+    if subresource >= count {
+        return Err(MapError::SubresourceOutOfRange { subresource, count });
+    }
+
+    // Two guards over the same memory would alias, so a subresource can only
+    // be mapped once at a time.
+    let key = (resource as *const _ as usize, subresource);
+    let mut list = mapped();
+    if list.contains(&key) {
+        return Err(MapError::AlreadyMapped { subresource });
+    }
+
+    let mut mapped = mem::zeroed();
+    resource.AddRef();
+    let resource = ComPtr::from_raw(resource as *const _ as *mut ID3D11Resource);
+    let hr = ctx.Map(resource.as_raw(), subresource, map as u32, 0, &mut mapped);
+    Error::map_if(hr, || ())?;
+    list.push(key);
+
+    let len = mapped_len(&info, subresource, mapped.RowPitch, mapped.DepthPitch);
+    let data = mapped.pData as *mut u8;
+    debug_assert!(!data.is_null());
+
+    Ok(MappedSubresource {
+        ctx,
+        resource,
+        subresource,
+        map,
+        data,
+        len,
+        row_pitch: mapped.RowPitch,
+        depth_pitch: mapped.DepthPitch,
+        image: image(&info, subresource),
+    })
+}
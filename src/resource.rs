@@ -1,9 +1,24 @@
+use crate::enums::{self, BindFlags, CpuAccessFlags, ResourceMiscFlags};
+use crate::error::DescError;
+use crate::initial_data::TextureExtent;
+use crate::texture1d::Texture1D;
+use crate::texture2d::Texture2D;
+use crate::texture3d::Texture3D;
+
+use std::mem;
+
 use com_wrapper::ComWrapper;
+use dxgi::enums::Format;
 use winapi::shared::dxgi::{IDXGIDeviceSubObject, IDXGIResource};
 use winapi::shared::winerror::SUCCEEDED;
-use winapi::um::d3d11::ID3D11Resource;
+use winapi::um::d3d11::{ID3D11Buffer, ID3D11Resource};
+use winapi::um::d3d11::{ID3D11Texture1D, ID3D11Texture2D, ID3D11Texture3D};
+use winapi::um::d3d11::{D3D11_RESOURCE_DIMENSION_BUFFER, D3D11_RESOURCE_DIMENSION_TEXTURE1D};
+use winapi::um::d3d11::{D3D11_RESOURCE_DIMENSION_TEXTURE2D, D3D11_RESOURCE_DIMENSION_TEXTURE3D};
 use wio::com::ComPtr;
 
+pub use crate::validation::{ResourceDimension, ResourceInfo};
+
 #[repr(transparent)]
 #[derive(ComWrapper)]
 #[com(send, sync, debug)]
@@ -20,3 +35,71 @@ unsafe impl IResource for Resource {
         &self.ptr
     }
 }
+
+impl ResourceInfo {
+    /// Reads the description of any resource, or fails if it contains
+    /// values unknown to this crate.
+    pub(crate) unsafe fn of(resource: &ID3D11Resource) -> Result<ResourceInfo, DescError> {
+        resource.AddRef();
+        let resource = ComPtr::from_raw(resource as *const _ as *mut ID3D11Resource);
+
+        let mut dimension = 0;
+        resource.GetType(&mut dimension);
+
+        macro_rules! texture {
+            ($wrapper:ident, $interface:ident, $desc:ident => $sample_count:expr) => {{
+                let texture = $wrapper::from_ptr(resource.cast::<$interface>().unwrap());
+                let $desc = texture.desc()?;
+                Ok(ResourceInfo {
+                    dimension: ResourceDimension::$wrapper,
+                    usage: $desc.usage,
+                    bind_flags: $desc.bind_flags,
+                    cpu_access_flags: $desc.cpu_access_flags,
+                    misc_flags: $desc.misc_flags,
+                    format: $desc.format,
+                    extent: $desc.extent(),
+                    sample_count: $sample_count,
+                    structure_byte_stride: 0,
+                })
+            }};
+        }
+
+        match dimension {
+            D3D11_RESOURCE_DIMENSION_BUFFER => {
+                let buffer = resource.cast::<ID3D11Buffer>().unwrap();
+                let mut desc = mem::zeroed();
+                buffer.GetDesc(&mut desc);
+                Ok(ResourceInfo {
+                    dimension: ResourceDimension::Buffer,
+                    usage: enums::usage_from_raw(desc.Usage)?,
+                    bind_flags: BindFlags(desc.BindFlags),
+                    cpu_access_flags: CpuAccessFlags(desc.CPUAccessFlags),
+                    misc_flags: ResourceMiscFlags(desc.MiscFlags),
+                    format: Format::Unknown,
+                    extent: TextureExtent {
+                        width: desc.ByteWidth,
+                        height: 1,
+                        depth: 1,
+                        mip_levels: 1,
+                        array_size: 1,
+                    },
+                    sample_count: 1,
+                    structure_byte_stride: desc.StructureByteStride,
+                })
+            }
+            D3D11_RESOURCE_DIMENSION_TEXTURE1D => {
+                texture!(Texture1D, ID3D11Texture1D, desc => 1)
+            }
+            D3D11_RESOURCE_DIMENSION_TEXTURE2D => {
+                texture!(Texture2D, ID3D11Texture2D, desc => desc.sample_count)
+            }
+            D3D11_RESOURCE_DIMENSION_TEXTURE3D => {
+                texture!(Texture3D, ID3D11Texture3D, desc => 1)
+            }
+            _ => Err(DescError::UnknownEnumValue {
+                field: "Dimension",
+                value: dimension,
+            }),
+        }
+    }
+}
@@ -0,0 +1,248 @@
+//! Shader objects for the six programmable stages of the pipeline, and the
+//! `ShaderStage` trait through which the device context binds them and their
+//! resources.
+
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::dxbc::Container;
+use crate::error::ShaderError;
+
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use winapi::um::d3d11::{ID3D11Buffer, ID3D11DeviceChild, ID3D11DeviceContext};
+use winapi::um::d3d11::{ID3D11ComputeShader, ID3D11DomainShader, ID3D11GeometryShader};
+use winapi::um::d3d11::{ID3D11HullShader, ID3D11PixelShader, ID3D11VertexShader};
+use winapi::um::d3d11::{ID3D11SamplerState, ID3D11ShaderResourceView};
+use wio::com::ComPtr;
+
+/// The number of constant buffer slots of each stage.
+pub const CONSTANT_BUFFER_SLOTS: u32 = 14;
+/// The number of shader resource slots of each stage.
+pub const SHADER_RESOURCE_SLOTS: u32 = 128;
+/// The number of sampler slots of each stage.
+pub const SAMPLER_SLOTS: u32 = 16;
+
+/// A programmable stage of the pipeline, implemented by the shader type run
+/// in that stage. Lets the device context bind shaders and resources to any
+/// stage with the same methods, e.g.
+/// `ctx.set_constant_buffers::<PixelShader>(0, &[Some(&buffer)])`.
+///
+/// The functions take raw pointers and leave the slot ranges unchecked; use
+/// the methods of `IDeviceContext` instead.
+///
+/// # Safety
+///
+/// Implementations must call the Direct3D methods of the stage they stand
+/// for, with the arguments they are given.
+pub unsafe trait ShaderStage {
+    /// The name of the stage, as used in the Direct3D method prefixes.
+    const NAME: &'static str;
+
+    /// Binds `shader` to the stage, or unbinds the current shader.
+    ///
+    /// # Safety
+    ///
+    /// The shader must have been created by the device that owns `ctx`.
+    unsafe fn set_shader(ctx: &ID3D11DeviceContext, shader: Option<&Self>);
+
+    /// Binds constant buffers to the stage, starting at `start_slot`.
+    ///
+    /// # Safety
+    ///
+    /// Every pointer must be null or point to a live buffer created by the
+    /// device that owns `ctx`, and the slots must be in range for the stage.
+    unsafe fn set_constant_buffers(
+        ctx: &ID3D11DeviceContext,
+        start_slot: u32,
+        buffers: &[*mut ID3D11Buffer],
+    );
+
+    /// Binds shader resource views to the stage, starting at `start_slot`.
+    ///
+    /// # Safety
+    ///
+    /// Every pointer must be null or point to a live view created by the
+    /// device that owns `ctx`, and the slots must be in range for the stage.
+    unsafe fn set_shader_resources(
+        ctx: &ID3D11DeviceContext,
+        start_slot: u32,
+        views: &[*mut ID3D11ShaderResourceView],
+    );
+
+    /// Binds samplers to the stage, starting at `start_slot`.
+    ///
+    /// # Safety
+    ///
+    /// Every pointer must be null or point to a live sampler state created
+    /// by the device that owns `ctx`, and the slots must be in range for the
+    /// stage.
+    unsafe fn set_samplers(
+        ctx: &ID3D11DeviceContext,
+        start_slot: u32,
+        samplers: &[*mut ID3D11SamplerState],
+    );
+}
+
+macro_rules! shader {
+    (
+        $(#[$attr:meta])*
+        $name:ident($iface:ident, $stage:expr) {
+            $create:ident,
+            $set_shader:ident,
+            $set_constant_buffers:ident,
+            $set_shader_resources:ident,
+            $set_samplers:ident,
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(ComWrapper, Clone, PartialEq)]
+        #[com(send, sync, debug)]
+        #[repr(transparent)]
+        pub struct $name {
+            ptr: ComPtr<$iface>,
+        }
+
+        impl $name {
+            /// Creates the shader from compiled DXBC bytecode. The container
+            /// is parsed first, so that bytecode that is not a container is
+            /// reported as `ShaderError::Bytecode`. Its checksum is left to
+            /// the runtime; use `create_verified` to check it here.
+            pub fn create(device: &dyn IDevice, bytecode: &[u8]) -> Result<$name, ShaderError> {
+                Container::parse(bytecode)?;
+                $name::create_raw(device, bytecode)
+            }
+
+            /// Like `create`, but also checks the checksum of the container,
+            /// so that corrupted bytecode is reported as
+            /// `ShaderError::Bytecode` instead of a failure of the device.
+            pub fn create_verified(
+                device: &dyn IDevice,
+                bytecode: &[u8],
+            ) -> Result<$name, ShaderError> {
+                Container::parse(bytecode)?.verify_checksum()?;
+                $name::create_raw(device, bytecode)
+            }
+
+            fn create_raw(device: &dyn IDevice, bytecode: &[u8]) -> Result<$name, ShaderError> {
+                unsafe {
+                    let mut ptr = ptr::null_mut();
+                    let hr = device.raw_dev().$create(
+                        bytecode.as_ptr() as *const _,
+                        bytecode.len(),
+                        ptr::null_mut(),
+                        &mut ptr,
+                    );
+                    Ok(Error::map_if(hr, || $name::from_raw(ptr))?)
+                }
+            }
+        }
+
+        unsafe impl IDeviceChild for $name {
+            unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+                &self.ptr
+            }
+        }
+
+        unsafe impl ShaderStage for $name {
+            const NAME: &'static str = $stage;
+
+            unsafe fn set_shader(ctx: &ID3D11DeviceContext, shader: Option<&Self>) {
+                let shader = shader.map(|s| s.ptr.as_raw()).unwrap_or(ptr::null_mut());
+                ctx.$set_shader(shader, ptr::null(), 0);
+            }
+
+            unsafe fn set_constant_buffers(
+                ctx: &ID3D11DeviceContext,
+                start_slot: u32,
+                buffers: &[*mut ID3D11Buffer],
+            ) {
+                ctx.$set_constant_buffers(start_slot, buffers.len() as u32, buffers.as_ptr());
+            }
+
+            unsafe fn set_shader_resources(
+                ctx: &ID3D11DeviceContext,
+                start_slot: u32,
+                views: &[*mut ID3D11ShaderResourceView],
+            ) {
+                ctx.$set_shader_resources(start_slot, views.len() as u32, views.as_ptr());
+            }
+
+            unsafe fn set_samplers(
+                ctx: &ID3D11DeviceContext,
+                start_slot: u32,
+                samplers: &[*mut ID3D11SamplerState],
+            ) {
+                ctx.$set_samplers(start_slot, samplers.len() as u32, samplers.as_ptr());
+            }
+        }
+    };
+}
+
+shader! {
+    VertexShader(ID3D11VertexShader, "VS") {
+        CreateVertexShader,
+        VSSetShader,
+        VSSetConstantBuffers,
+        VSSetShaderResources,
+        VSSetSamplers,
+    }
+}
+
+shader! {
+    /// Runs between the vertex and rasterizer stages. Stream output is not
+    /// supported yet.
+    GeometryShader(ID3D11GeometryShader, "GS") {
+        CreateGeometryShader,
+        GSSetShader,
+        GSSetConstantBuffers,
+        GSSetShaderResources,
+        GSSetSamplers,
+    }
+}
+
+shader! {
+    /// Computes the tessellation factors of a patch. Requires feature level
+    /// 11_0.
+    HullShader(ID3D11HullShader, "HS") {
+        CreateHullShader,
+        HSSetShader,
+        HSSetConstantBuffers,
+        HSSetShaderResources,
+        HSSetSamplers,
+    }
+}
+
+shader! {
+    /// Computes the vertices generated by the tessellator. Requires feature
+    /// level 11_0.
+    DomainShader(ID3D11DomainShader, "DS") {
+        CreateDomainShader,
+        DSSetShader,
+        DSSetConstantBuffers,
+        DSSetShaderResources,
+        DSSetSamplers,
+    }
+}
+
+shader! {
+    PixelShader(ID3D11PixelShader, "PS") {
+        CreatePixelShader,
+        PSSetShader,
+        PSSetConstantBuffers,
+        PSSetShaderResources,
+        PSSetSamplers,
+    }
+}
+
+shader! {
+    /// Runs outside the graphics pipeline, dispatched by the device context.
+    ComputeShader(ID3D11ComputeShader, "CS") {
+        CreateComputeShader,
+        CSSetShader,
+        CSSetConstantBuffers,
+        CSSetShaderResources,
+        CSSetSamplers,
+    }
+}
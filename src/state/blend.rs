@@ -0,0 +1,195 @@
+//! Blend state, which combines the colors a pixel shader outputs with those
+//! already in the render targets.
+
+use std::ops::BitOr;
+
+state_enum! {
+    /// A factor the source or destination color is multiplied by. Mirrors
+    /// `D3D11_BLEND`.
+    pub enum Blend {
+        Zero = 1,
+        One = 2,
+        SrcColor = 3,
+        InvSrcColor = 4,
+        SrcAlpha = 5,
+        InvSrcAlpha = 6,
+        DestAlpha = 7,
+        InvDestAlpha = 8,
+        DestColor = 9,
+        InvDestColor = 10,
+        /// `min(src_alpha, 1 - dest_alpha)`.
+        SrcAlphaSat = 11,
+        /// The factor given when binding the state.
+        BlendFactor = 14,
+        InvBlendFactor = 15,
+        /// The second output of the pixel shader, for dual source blending.
+        Src1Color = 16,
+        InvSrc1Color = 17,
+        Src1Alpha = 18,
+        InvSrc1Alpha = 19,
+    }
+}
+
+state_enum! {
+    /// How the weighted source and destination are combined. Mirrors
+    /// `D3D11_BLEND_OP`.
+    pub enum BlendOp {
+        Add = 1,
+        /// Destination subtracted from source.
+        Subtract = 2,
+        /// Source subtracted from destination.
+        RevSubtract = 3,
+        /// The minimum of the unweighted source and destination.
+        Min = 4,
+        /// The maximum of the unweighted source and destination.
+        Max = 5,
+    }
+}
+
+/// The channels of a render target that are written. Mirrors
+/// `D3D11_COLOR_WRITE_ENABLE`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColorWriteMask(pub u8);
+
+impl ColorWriteMask {
+    pub const NONE: ColorWriteMask = ColorWriteMask(0);
+    pub const RED: ColorWriteMask = ColorWriteMask(0x1);
+    pub const GREEN: ColorWriteMask = ColorWriteMask(0x2);
+    pub const BLUE: ColorWriteMask = ColorWriteMask(0x4);
+    pub const ALPHA: ColorWriteMask = ColorWriteMask(0x8);
+    pub const ALL: ColorWriteMask = ColorWriteMask(0xF);
+
+    /// Whether all of the channels in `mask` are written.
+    pub fn is_set(&self, mask: ColorWriteMask) -> bool {
+        self.0 & mask.0 == mask.0
+    }
+}
+
+impl Default for ColorWriteMask {
+    fn default() -> Self {
+        ColorWriteMask::ALL
+    }
+}
+
+impl BitOr for ColorWriteMask {
+    type Output = ColorWriteMask;
+
+    fn bitor(self, rhs: ColorWriteMask) -> ColorWriteMask {
+        ColorWriteMask(self.0 | rhs.0)
+    }
+}
+
+/// Blending of one render target. Mirrors
+/// `D3D11_RENDER_TARGET_BLEND_DESC`.
+///
+/// With blending enabled, the color written is
+/// `src * src_blend OP dest * dest_blend`, and likewise for alpha with the
+/// `_alpha` fields.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RenderTargetBlend {
+    pub blend_enable: bool,
+    pub src_blend: Blend,
+    pub dest_blend: Blend,
+    pub blend_op: BlendOp,
+    pub src_blend_alpha: Blend,
+    pub dest_blend_alpha: Blend,
+    pub blend_op_alpha: BlendOp,
+    pub write_mask: ColorWriteMask,
+}
+
+impl RenderTargetBlend {
+    /// Writes the source, ignoring the destination.
+    pub const OPAQUE: RenderTargetBlend = RenderTargetBlend {
+        blend_enable: false,
+        src_blend: Blend::One,
+        dest_blend: Blend::Zero,
+        blend_op: BlendOp::Add,
+        src_blend_alpha: Blend::One,
+        dest_blend_alpha: Blend::Zero,
+        blend_op_alpha: BlendOp::Add,
+        write_mask: ColorWriteMask::ALL,
+    };
+
+    /// Blends colors that are not premultiplied by their alpha:
+    /// `src * src_alpha + dest * (1 - src_alpha)`.
+    pub const ALPHA_BLEND: RenderTargetBlend = RenderTargetBlend {
+        blend_enable: true,
+        src_blend: Blend::SrcAlpha,
+        dest_blend: Blend::InvSrcAlpha,
+        blend_op: BlendOp::Add,
+        src_blend_alpha: Blend::One,
+        dest_blend_alpha: Blend::InvSrcAlpha,
+        blend_op_alpha: BlendOp::Add,
+        write_mask: ColorWriteMask::ALL,
+    };
+
+    /// Blends colors premultiplied by their alpha:
+    /// `src + dest * (1 - src_alpha)`.
+    pub const PREMULTIPLIED: RenderTargetBlend = RenderTargetBlend {
+        blend_enable: true,
+        src_blend: Blend::One,
+        dest_blend: Blend::InvSrcAlpha,
+        blend_op: BlendOp::Add,
+        src_blend_alpha: Blend::One,
+        dest_blend_alpha: Blend::InvSrcAlpha,
+        blend_op_alpha: BlendOp::Add,
+        write_mask: ColorWriteMask::ALL,
+    };
+
+    /// Adds the source weighted by its alpha: `src * src_alpha + dest`.
+    pub const ADDITIVE: RenderTargetBlend = RenderTargetBlend {
+        blend_enable: true,
+        src_blend: Blend::SrcAlpha,
+        dest_blend: Blend::One,
+        blend_op: BlendOp::Add,
+        src_blend_alpha: Blend::SrcAlpha,
+        dest_blend_alpha: Blend::One,
+        blend_op_alpha: BlendOp::Add,
+        write_mask: ColorWriteMask::ALL,
+    };
+}
+
+impl Default for RenderTargetBlend {
+    fn default() -> Self {
+        RenderTargetBlend::OPAQUE
+    }
+}
+
+/// Describes a blend state. Mirrors `D3D11_BLEND_DESC`. The default is the
+/// state Direct3D uses when none is bound, `BlendDesc::OPAQUE`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct BlendDesc {
+    /// Turns the alpha of the first render target into a coverage mask for
+    /// multisampling.
+    pub alpha_to_coverage_enable: bool,
+    /// Whether each render target uses its own entry of `render_target`.
+    /// Otherwise all of them use the first.
+    pub independent_blend_enable: bool,
+    pub render_target: [RenderTargetBlend; 8],
+}
+
+impl BlendDesc {
+    pub const OPAQUE: BlendDesc = BlendDesc::uniform(RenderTargetBlend::OPAQUE);
+    pub const ALPHA_BLEND: BlendDesc = BlendDesc::uniform(RenderTargetBlend::ALPHA_BLEND);
+    pub const PREMULTIPLIED: BlendDesc = BlendDesc::uniform(RenderTargetBlend::PREMULTIPLIED);
+    pub const ADDITIVE: BlendDesc = BlendDesc::uniform(RenderTargetBlend::ADDITIVE);
+
+    /// The same blending for every render target.
+    pub const fn uniform(blend: RenderTargetBlend) -> BlendDesc {
+        BlendDesc {
+            alpha_to_coverage_enable: false,
+            independent_blend_enable: false,
+            render_target: [blend; 8],
+        }
+    }
+}
+
+impl Default for BlendDesc {
+    fn default() -> Self {
+        BlendDesc::OPAQUE
+    }
+}
@@ -0,0 +1,119 @@
+//! Depth-stencil state, which decides whether a pixel passes the depth and
+//! stencil tests and how it updates the depth-stencil target.
+
+use crate::state::ComparisonFunc;
+
+state_enum! {
+    /// Whether the depth test writes the depths of passing pixels. Mirrors
+    /// `D3D11_DEPTH_WRITE_MASK`.
+    pub enum DepthWriteMask {
+        Zero = 0,
+        All = 1,
+    }
+}
+
+state_enum! {
+    /// How the stencil buffer is updated. Mirrors `D3D11_STENCIL_OP`.
+    pub enum StencilOp {
+        Keep = 1,
+        Zero = 2,
+        /// Writes the stencil reference given when binding the state.
+        Replace = 3,
+        IncrSat = 4,
+        DecrSat = 5,
+        Invert = 6,
+        /// Increments, wrapping around.
+        Incr = 7,
+        /// Decrements, wrapping around.
+        Decr = 8,
+    }
+}
+
+/// The stencil test of front or back facing triangles. Mirrors
+/// `D3D11_DEPTH_STENCILOP_DESC`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DepthStencilOpDesc {
+    /// Applied when the stencil test fails.
+    pub stencil_fail_op: StencilOp,
+    /// Applied when the stencil test passes and the depth test fails.
+    pub stencil_depth_fail_op: StencilOp,
+    /// Applied when both tests pass.
+    pub stencil_pass_op: StencilOp,
+    pub stencil_func: ComparisonFunc,
+}
+
+impl DepthStencilOpDesc {
+    /// Always passes and leaves the stencil buffer alone.
+    pub const KEEP: DepthStencilOpDesc = DepthStencilOpDesc {
+        stencil_fail_op: StencilOp::Keep,
+        stencil_depth_fail_op: StencilOp::Keep,
+        stencil_pass_op: StencilOp::Keep,
+        stencil_func: ComparisonFunc::Always,
+    };
+}
+
+impl Default for DepthStencilOpDesc {
+    fn default() -> Self {
+        DepthStencilOpDesc::KEEP
+    }
+}
+
+/// Describes a depth-stencil state. Mirrors `D3D11_DEPTH_STENCIL_DESC`. The
+/// default is the state Direct3D uses when none is bound,
+/// `DepthStencilDesc::DEPTH_DEFAULT`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DepthStencilDesc {
+    pub depth_enable: bool,
+    pub depth_write_mask: DepthWriteMask,
+    pub depth_func: ComparisonFunc,
+    pub stencil_enable: bool,
+    pub stencil_read_mask: u8,
+    pub stencil_write_mask: u8,
+    pub front_face: DepthStencilOpDesc,
+    pub back_face: DepthStencilOpDesc,
+}
+
+impl DepthStencilDesc {
+    /// Neither tests nor writes depth.
+    pub const DEPTH_NONE: DepthStencilDesc =
+        DepthStencilDesc::depth(false, false, ComparisonFunc::Less);
+    /// Tests depth with `Less` and writes it.
+    pub const DEPTH_DEFAULT: DepthStencilDesc =
+        DepthStencilDesc::depth(true, true, ComparisonFunc::Less);
+    /// Tests depth with `LessEqual` without writing it, e.g. for
+    /// transparent geometry drawn after the opaque geometry.
+    pub const DEPTH_READ: DepthStencilDesc =
+        DepthStencilDesc::depth(true, false, ComparisonFunc::LessEqual);
+    /// Tests depth with `Greater` and writes it, for depth buffers cleared
+    /// to 0 with the near plane at 1.
+    pub const DEPTH_REVERSE_Z: DepthStencilDesc =
+        DepthStencilDesc::depth(true, true, ComparisonFunc::Greater);
+
+    /// A state with the given depth test and no stencil test.
+    pub const fn depth(enable: bool, write: bool, func: ComparisonFunc) -> DepthStencilDesc {
+        DepthStencilDesc {
+            depth_enable: enable,
+            depth_write_mask: if write {
+                DepthWriteMask::All
+            } else {
+                DepthWriteMask::Zero
+            },
+            depth_func: func,
+            stencil_enable: false,
+            stencil_read_mask: 0xFF,
+            stencil_write_mask: 0xFF,
+            front_face: DepthStencilOpDesc::KEEP,
+            back_face: DepthStencilOpDesc::KEEP,
+        }
+    }
+}
+
+impl Default for DepthStencilDesc {
+    fn default() -> Self {
+        DepthStencilDesc::DEPTH_DEFAULT
+    }
+}
@@ -0,0 +1,64 @@
+//! Fixed-function state: how the output merger blends colors and tests
+//! depth and stencil, how the rasterizer turns primitives into pixels, and
+//! how shaders sample textures.
+//!
+//! The descriptions, with presets for common configurations, are plain data
+//! available on every platform. The state objects created from them with
+//! the `create_*_state` methods of `IDevice`, and bound with the `set_*`
+//! methods of `IDeviceContext`, need Windows.
+
+pub use self::blend::{Blend, BlendDesc, BlendOp, ColorWriteMask, RenderTargetBlend};
+pub use self::depth_stencil::{DepthStencilDesc, DepthStencilOpDesc, DepthWriteMask, StencilOp};
+#[cfg(windows)]
+pub use self::objects::{BlendState, DepthStencilState, RasterizerState, SamplerState};
+pub use self::rasterizer::{CullMode, FillMode, RasterizerDesc};
+pub use self::sampler::{Filter, SamplerDesc, TextureAddressMode};
+
+macro_rules! state_enum {
+    ($(#[$attr:meta])* pub enum $name:ident { $($(#[$vattr:meta])* $variant:ident = $value:expr,)* }) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum $name {
+            $($(#[$vattr])* $variant,)*
+        }
+
+        impl $name {
+            pub fn from_raw(value: u32) -> Option<$name> {
+                Some(match value {
+                    $($value => $name::$variant,)*
+                    _ => return None,
+                })
+            }
+
+            pub const fn to_raw(self) -> u32 {
+                match self {
+                    $($name::$variant => $value,)*
+                }
+            }
+        }
+    };
+}
+
+pub mod blend;
+pub mod depth_stencil;
+#[cfg(windows)]
+mod objects;
+pub mod rasterizer;
+pub mod sampler;
+
+state_enum! {
+    /// How a value is compared with a reference, for depth and stencil tests
+    /// and comparison samplers. The test passes if `value FUNC reference`
+    /// holds. Mirrors `D3D11_COMPARISON_FUNC`.
+    pub enum ComparisonFunc {
+        Never = 1,
+        Less = 2,
+        Equal = 3,
+        LessEqual = 4,
+        Greater = 5,
+        NotEqual = 6,
+        GreaterEqual = 7,
+        Always = 8,
+    }
+}
@@ -0,0 +1,138 @@
+//! The state objects, and the conversion of their descriptions to the raw
+//! structures Direct3D takes.
+
+use crate::device_child::IDeviceChild;
+use crate::state::{BlendDesc, DepthStencilDesc, DepthStencilOpDesc, RasterizerDesc};
+use crate::state::{RenderTargetBlend, SamplerDesc};
+
+use com_wrapper::ComWrapper;
+use winapi::um::d3d11::{ID3D11BlendState, ID3D11DepthStencilState, ID3D11DeviceChild};
+use winapi::um::d3d11::{ID3D11RasterizerState, ID3D11SamplerState};
+use winapi::um::d3d11::{D3D11_BLEND_DESC, D3D11_DEPTH_STENCILOP_DESC, D3D11_DEPTH_STENCIL_DESC};
+use winapi::um::d3d11::{
+    D3D11_RASTERIZER_DESC, D3D11_RENDER_TARGET_BLEND_DESC, D3D11_SAMPLER_DESC,
+};
+use wio::com::ComPtr;
+
+macro_rules! state_object {
+    ($(#[$attr:meta])* $name:ident($iface:ident)) => {
+        $(#[$attr])*
+        #[derive(ComWrapper, Clone, PartialEq)]
+        #[com(send, sync, debug)]
+        #[repr(transparent)]
+        pub struct $name {
+            ptr: ComPtr<$iface>,
+        }
+
+        unsafe impl IDeviceChild for $name {
+            unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+                &self.ptr
+            }
+        }
+    };
+}
+
+state_object! {
+    /// Created with `IDevice::create_blend_state`.
+    BlendState(ID3D11BlendState)
+}
+
+state_object! {
+    /// Created with `IDevice::create_depth_stencil_state`.
+    DepthStencilState(ID3D11DepthStencilState)
+}
+
+state_object! {
+    /// Created with `IDevice::create_rasterizer_state`.
+    RasterizerState(ID3D11RasterizerState)
+}
+
+state_object! {
+    /// Created with `IDevice::create_sampler_state`.
+    SamplerState(ID3D11SamplerState)
+}
+
+impl From<&RenderTargetBlend> for D3D11_RENDER_TARGET_BLEND_DESC {
+    fn from(desc: &RenderTargetBlend) -> Self {
+        D3D11_RENDER_TARGET_BLEND_DESC {
+            BlendEnable: desc.blend_enable as i32,
+            SrcBlend: desc.src_blend.to_raw(),
+            DestBlend: desc.dest_blend.to_raw(),
+            BlendOp: desc.blend_op.to_raw(),
+            SrcBlendAlpha: desc.src_blend_alpha.to_raw(),
+            DestBlendAlpha: desc.dest_blend_alpha.to_raw(),
+            BlendOpAlpha: desc.blend_op_alpha.to_raw(),
+            RenderTargetWriteMask: desc.write_mask.0,
+        }
+    }
+}
+
+impl From<&BlendDesc> for D3D11_BLEND_DESC {
+    fn from(desc: &BlendDesc) -> Self {
+        D3D11_BLEND_DESC {
+            AlphaToCoverageEnable: desc.alpha_to_coverage_enable as i32,
+            IndependentBlendEnable: desc.independent_blend_enable as i32,
+            RenderTarget: desc.render_target.map(|rt| (&rt).into()),
+        }
+    }
+}
+
+impl From<&DepthStencilOpDesc> for D3D11_DEPTH_STENCILOP_DESC {
+    fn from(desc: &DepthStencilOpDesc) -> Self {
+        D3D11_DEPTH_STENCILOP_DESC {
+            StencilFailOp: desc.stencil_fail_op.to_raw(),
+            StencilDepthFailOp: desc.stencil_depth_fail_op.to_raw(),
+            StencilPassOp: desc.stencil_pass_op.to_raw(),
+            StencilFunc: desc.stencil_func.to_raw(),
+        }
+    }
+}
+
+impl From<&DepthStencilDesc> for D3D11_DEPTH_STENCIL_DESC {
+    fn from(desc: &DepthStencilDesc) -> Self {
+        D3D11_DEPTH_STENCIL_DESC {
+            DepthEnable: desc.depth_enable as i32,
+            DepthWriteMask: desc.depth_write_mask.to_raw(),
+            DepthFunc: desc.depth_func.to_raw(),
+            StencilEnable: desc.stencil_enable as i32,
+            StencilReadMask: desc.stencil_read_mask,
+            StencilWriteMask: desc.stencil_write_mask,
+            FrontFace: (&desc.front_face).into(),
+            BackFace: (&desc.back_face).into(),
+        }
+    }
+}
+
+impl From<&RasterizerDesc> for D3D11_RASTERIZER_DESC {
+    fn from(desc: &RasterizerDesc) -> Self {
+        D3D11_RASTERIZER_DESC {
+            FillMode: desc.fill_mode.to_raw(),
+            CullMode: desc.cull_mode.to_raw(),
+            FrontCounterClockwise: desc.front_counter_clockwise as i32,
+            DepthBias: desc.depth_bias,
+            DepthBiasClamp: desc.depth_bias_clamp,
+            SlopeScaledDepthBias: desc.slope_scaled_depth_bias,
+            DepthClipEnable: desc.depth_clip_enable as i32,
+            ScissorEnable: desc.scissor_enable as i32,
+            MultisampleEnable: desc.multisample_enable as i32,
+            AntialiasedLineEnable: desc.antialiased_line_enable as i32,
+        }
+    }
+}
+
+impl From<&SamplerDesc> for D3D11_SAMPLER_DESC {
+    fn from(desc: &SamplerDesc) -> Self {
+        D3D11_SAMPLER_DESC {
+            Filter: desc.filter.to_raw(),
+            AddressU: desc.address_u.to_raw(),
+            AddressV: desc.address_v.to_raw(),
+            AddressW: desc.address_w.to_raw(),
+            MipLODBias: desc.mip_lod_bias,
+            MaxAnisotropy: desc.max_anisotropy,
+            ComparisonFunc: desc.comparison_func.to_raw(),
+            BorderColor: desc.border_color,
+            MinLOD: desc.min_lod,
+            MaxLOD: desc.max_lod,
+        }
+    }
+}
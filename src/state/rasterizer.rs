@@ -0,0 +1,78 @@
+//! Rasterizer state, which decides which triangles are drawn and how they
+//! are turned into pixels.
+
+state_enum! {
+    /// Mirrors `D3D11_FILL_MODE`.
+    pub enum FillMode {
+        /// Draws the edges of triangles as lines.
+        Wireframe = 2,
+        Solid = 3,
+    }
+}
+
+state_enum! {
+    /// Which triangles are not drawn. Mirrors `D3D11_CULL_MODE`.
+    pub enum CullMode {
+        None = 1,
+        Front = 2,
+        Back = 3,
+    }
+}
+
+/// Describes a rasterizer state. Mirrors `D3D11_RASTERIZER_DESC`. The
+/// default is the state Direct3D uses when none is bound,
+/// `RasterizerDesc::CULL_BACK`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct RasterizerDesc {
+    pub fill_mode: FillMode,
+    pub cull_mode: CullMode,
+    /// Whether triangles with counter-clockwise vertices face the front.
+    /// Clockwise ones do by default.
+    pub front_counter_clockwise: bool,
+    pub depth_bias: i32,
+    pub depth_bias_clamp: f32,
+    pub slope_scaled_depth_bias: f32,
+    pub depth_clip_enable: bool,
+    pub scissor_enable: bool,
+    pub multisample_enable: bool,
+    pub antialiased_line_enable: bool,
+}
+
+impl RasterizerDesc {
+    /// Draws every triangle.
+    pub const CULL_NONE: RasterizerDesc = RasterizerDesc::cull(CullMode::None);
+    /// Skips triangles facing away, those with counter-clockwise vertices.
+    pub const CULL_BACK: RasterizerDesc = RasterizerDesc::cull(CullMode::Back);
+    /// Skips triangles facing the viewer, those with clockwise vertices.
+    pub const CULL_FRONT: RasterizerDesc = RasterizerDesc::cull(CullMode::Front);
+    /// Draws the edges of every triangle.
+    pub const WIREFRAME: RasterizerDesc = RasterizerDesc {
+        fill_mode: FillMode::Wireframe,
+        ..RasterizerDesc::CULL_NONE
+    };
+
+    /// A solid state culling with `cull_mode`, the other fields at their
+    /// defaults.
+    pub const fn cull(cull_mode: CullMode) -> RasterizerDesc {
+        RasterizerDesc {
+            fill_mode: FillMode::Solid,
+            cull_mode,
+            front_counter_clockwise: false,
+            depth_bias: 0,
+            depth_bias_clamp: 0.0,
+            slope_scaled_depth_bias: 0.0,
+            depth_clip_enable: true,
+            scissor_enable: false,
+            multisample_enable: false,
+            antialiased_line_enable: false,
+        }
+    }
+}
+
+impl Default for RasterizerDesc {
+    fn default() -> Self {
+        RasterizerDesc::CULL_BACK
+    }
+}
@@ -0,0 +1,132 @@
+//! Sampler state, which decides how shaders filter and address textures.
+
+use crate::state::ComparisonFunc;
+
+state_enum! {
+    /// The filtering of minification, magnification and mip level
+    /// selection, in that order in the names. The comparison filters
+    /// compare samples with a reference first, for `SampleCmp`. Mirrors
+    /// `D3D11_FILTER`.
+    pub enum Filter {
+        MinMagMipPoint = 0x00,
+        MinMagPointMipLinear = 0x01,
+        MinPointMagLinearMipPoint = 0x04,
+        MinPointMagMipLinear = 0x05,
+        MinLinearMagMipPoint = 0x10,
+        MinLinearMagPointMipLinear = 0x11,
+        MinMagLinearMipPoint = 0x14,
+        MinMagMipLinear = 0x15,
+        Anisotropic = 0x55,
+        ComparisonMinMagMipPoint = 0x80,
+        ComparisonMinMagPointMipLinear = 0x81,
+        ComparisonMinPointMagLinearMipPoint = 0x84,
+        ComparisonMinPointMagMipLinear = 0x85,
+        ComparisonMinLinearMagMipPoint = 0x90,
+        ComparisonMinLinearMagPointMipLinear = 0x91,
+        ComparisonMinMagLinearMipPoint = 0x94,
+        ComparisonMinMagMipLinear = 0x95,
+        ComparisonAnisotropic = 0xD5,
+    }
+}
+
+impl Filter {
+    /// Whether the filter compares samples with a reference.
+    pub fn is_comparison(self) -> bool {
+        self.to_raw() & 0x80 != 0
+    }
+
+    /// Whether the filter is anisotropic, using `max_anisotropy`.
+    pub fn is_anisotropic(self) -> bool {
+        self.to_raw() & 0x40 != 0
+    }
+}
+
+state_enum! {
+    /// How texture coordinates outside of `[0, 1]` are resolved. Mirrors
+    /// `D3D11_TEXTURE_ADDRESS_MODE`.
+    pub enum TextureAddressMode {
+        /// Repeats the texture.
+        Wrap = 1,
+        /// Repeats the texture, flipping every other copy.
+        Mirror = 2,
+        /// Uses the texel at the edge.
+        Clamp = 3,
+        /// Uses the border color.
+        Border = 4,
+        /// Mirrors once around 0, then clamps.
+        MirrorOnce = 5,
+    }
+}
+
+/// Describes a sampler state. Mirrors `D3D11_SAMPLER_DESC`. The default is
+/// the state Direct3D uses when none is bound, `SamplerDesc::LINEAR_CLAMP`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct SamplerDesc {
+    pub filter: Filter,
+    pub address_u: TextureAddressMode,
+    pub address_v: TextureAddressMode,
+    pub address_w: TextureAddressMode,
+    pub mip_lod_bias: f32,
+    /// From 1 to 16, used by the anisotropic filters.
+    pub max_anisotropy: u32,
+    /// The comparison of the comparison filters.
+    pub comparison_func: ComparisonFunc,
+    /// The color of `TextureAddressMode::Border`.
+    pub border_color: [f32; 4],
+    pub min_lod: f32,
+    pub max_lod: f32,
+}
+
+impl SamplerDesc {
+    pub const POINT_WRAP: SamplerDesc =
+        SamplerDesc::new(Filter::MinMagMipPoint, TextureAddressMode::Wrap);
+    pub const POINT_CLAMP: SamplerDesc =
+        SamplerDesc::new(Filter::MinMagMipPoint, TextureAddressMode::Clamp);
+    pub const LINEAR_WRAP: SamplerDesc =
+        SamplerDesc::new(Filter::MinMagMipLinear, TextureAddressMode::Wrap);
+    pub const LINEAR_CLAMP: SamplerDesc =
+        SamplerDesc::new(Filter::MinMagMipLinear, TextureAddressMode::Clamp);
+    pub const ANISOTROPIC_WRAP: SamplerDesc = SamplerDesc {
+        max_anisotropy: 16,
+        ..SamplerDesc::new(Filter::Anisotropic, TextureAddressMode::Wrap)
+    };
+    pub const ANISOTROPIC_CLAMP: SamplerDesc = SamplerDesc {
+        max_anisotropy: 16,
+        ..SamplerDesc::new(Filter::Anisotropic, TextureAddressMode::Clamp)
+    };
+    /// Compares with `LessEqual` and filters the results linearly, for
+    /// sampling shadow maps with `SampleCmp`. Outside the map, samples
+    /// compare with a depth of 1.
+    pub const SHADOW: SamplerDesc = SamplerDesc {
+        comparison_func: ComparisonFunc::LessEqual,
+        ..SamplerDesc::new(
+            Filter::ComparisonMinMagLinearMipPoint,
+            TextureAddressMode::Border,
+        )
+    };
+
+    /// A sampler using `address` on every axis, the other fields at their
+    /// defaults.
+    pub const fn new(filter: Filter, address: TextureAddressMode) -> SamplerDesc {
+        SamplerDesc {
+            filter,
+            address_u: address,
+            address_v: address,
+            address_w: address,
+            mip_lod_bias: 0.0,
+            max_anisotropy: 1,
+            comparison_func: ComparisonFunc::Never,
+            border_color: [1.0; 4],
+            min_lod: -f32::MAX,
+            max_lod: f32::MAX,
+        }
+    }
+}
+
+impl Default for SamplerDesc {
+    fn default() -> Self {
+        SamplerDesc::LINEAR_CLAMP
+    }
+}
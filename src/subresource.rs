@@ -0,0 +1,132 @@
+use std::cmp;
+
+#[cfg(windows)]
+use winapi::um::d3d11::D3D11_BOX;
+
+/// Identifies one mip level of one array slice of a resource.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Subresource {
+    pub mip: u32,
+    pub array_slice: u32,
+}
+
+impl Subresource {
+    pub fn new(mip: u32, array_slice: u32) -> Self {
+        Subresource { mip, array_slice }
+    }
+
+    /// The index of the subresource in a resource with `mip_levels` mip
+    /// levels. Equivalent to `D3D11CalcSubresource`.
+    #[inline]
+    pub fn index(&self, mip_levels: u32) -> u32 {
+        self.mip + self.array_slice * mip_levels
+    }
+
+    /// The inverse of [`index`](#method.index).
+    ///
+    /// ### panics
+    /// Panics if `mip_levels` is 0.
+    #[inline]
+    pub fn from_index(index: u32, mip_levels: u32) -> Self {
+        assert!(mip_levels > 0, "a resource has at least one mip level");
+        Subresource {
+            mip: index % mip_levels,
+            array_slice: index / mip_levels,
+        }
+    }
+}
+
+/// The size of a dimension of `size` texels at mip level `mip`.
+#[inline]
+pub fn mip_size(size: u32, mip: u32) -> u32 {
+    cmp::max(1, size.checked_shr(mip).unwrap_or(0))
+}
+
+/// The number of mip levels in a full mip chain for a resource of the given
+/// size, i.e. what `MipLevels = 0` expands to.
+pub fn full_mip_count(width: u32, height: u32, depth: u32) -> u32 {
+    let largest = cmp::max(width, cmp::max(height, depth));
+    32 - cmp::max(largest, 1).leading_zeros()
+}
+
+/// A region of a subresource. The left, top and front edges are inclusive
+/// and the right, bottom and back edges exclusive. For buffers, left and
+/// right are byte offsets and the other dimensions span 0 to 1.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Box3D {
+    pub left: u32,
+    pub top: u32,
+    pub front: u32,
+    pub right: u32,
+    pub bottom: u32,
+    pub back: u32,
+}
+
+impl Box3D {
+    pub fn new(left: u32, top: u32, front: u32, right: u32, bottom: u32, back: u32) -> Self {
+        Box3D {
+            left,
+            top,
+            front,
+            right,
+            bottom,
+            back,
+        }
+    }
+
+    /// The box of `size` texels starting at `offset`, or `None` if its far
+    /// edges do not fit in a `u32`.
+    pub fn from_offset_size(offset: (u32, u32, u32), size: (u32, u32, u32)) -> Option<Self> {
+        Some(Box3D::new(
+            offset.0,
+            offset.1,
+            offset.2,
+            offset.0.checked_add(size.0)?,
+            offset.1.checked_add(size.1)?,
+            offset.2.checked_add(size.2)?,
+        ))
+    }
+
+    /// The box covering a whole subresource of `size` texels.
+    pub fn whole(size: (u32, u32, u32)) -> Self {
+        Box3D::new(0, 0, 0, size.0, size.1, size.2)
+    }
+
+    pub fn width(&self) -> u32 {
+        self.right.saturating_sub(self.left)
+    }
+
+    pub fn height(&self) -> u32 {
+        self.bottom.saturating_sub(self.top)
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.back.saturating_sub(self.front)
+    }
+
+    /// The width, height and depth of the box.
+    pub fn size(&self) -> (u32, u32, u32) {
+        (self.width(), self.height(), self.depth())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0 || self.depth() == 0
+    }
+}
+
+#[cfg(windows)]
+impl From<Box3D> for D3D11_BOX {
+    fn from(b: Box3D) -> Self {
+        D3D11_BOX {
+            left: b.left,
+            top: b.top,
+            front: b.front,
+            right: b.right,
+            bottom: b.bottom,
+            back: b.back,
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::device::IDevice;
+use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::{BuildError, DescError};
+use crate::initial_data::{self, InitialData, SubresourceData};
+use crate::subresource::Subresource;
+use crate::texture1d::desc::Texture1DDesc;
+use crate::texture1d::Texture1D;
+
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::D3D11_TEXTURE1D_DESC;
+
+pub struct Texture1DBuilder<'a, 'b> {
+    device: &'a dyn IDevice,
+    desc: Texture1DDesc,
+    initial_data: InitialData<'b>,
+    unchecked_format: bool,
+}
+
+impl<'a, 'b> Texture1DBuilder<'a, 'b> {
+    pub fn new(device: &'a dyn IDevice) -> Self {
+        Texture1DBuilder::from_desc(device, &Default::default())
+    }
+
+    /// Starts from an existing description, e.g. one returned by
+    /// `Texture1D::desc()` to create a texture of the same shape.
+    pub fn from_desc(device: &'a dyn IDevice, desc: &Texture1DDesc) -> Self {
+        Texture1DBuilder {
+            device,
+            desc: *desc,
+            initial_data: InitialData::None,
+            unchecked_format: false,
+        }
+    }
+
+    pub fn with_size(mut self, width: u32) -> Self {
+        self.desc.width = width;
+        self
+    }
+
+    pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
+        self.desc.mip_levels = mip_levels;
+        self
+    }
+
+    pub fn with_array_size(mut self, array_size: u32) -> Self {
+        self.desc.array_size = array_size;
+        self
+    }
+
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.desc.format = format;
+        self
+    }
+
+    pub fn with_usage(mut self, usage: Usage) -> Self {
+        self.desc.usage = usage;
+        self
+    }
+
+    pub fn with_bind_flags(mut self, bind_flags: BindFlags) -> Self {
+        self.desc.bind_flags = bind_flags;
+        self
+    }
+
+    pub fn with_cpu_access(mut self, cpu_access_flags: CpuAccessFlags) -> Self {
+        self.desc.cpu_access_flags = cpu_access_flags;
+        self
+    }
+
+    pub fn with_misc_flags(mut self, misc_flags: ResourceMiscFlags) -> Self {
+        self.desc.misc_flags = misc_flags;
+        self
+    }
+
+    pub fn with_initial_data(mut self, data: &'b [u8]) -> Self {
+        self.initial_data = InitialData::Single(SubresourceData::new(data, 0, 0));
+        self
+    }
+
+    /// Initial data for every subresource of the texture, in subresource
+    /// order: all mip levels of the first array slice, followed by all mip
+    /// levels of the next one.
+    pub fn with_initial_data_slice(mut self, data: &'b [&'b [u8]]) -> Self {
+        self.initial_data = InitialData::Vec(
+            data.iter()
+                .map(|&bytes| SubresourceData::new(bytes, 0, 0))
+                .collect(),
+        );
+        self
+    }
+
+    /// Initial data for a single subresource. When initial data is given this
+    /// way, it must be given for every subresource of the texture.
+    pub fn with_subresource_data(mut self, subresource: Subresource, data: &'b [u8]) -> Self {
+        self.initial_data
+            .insert(subresource, SubresourceData::new(data, 0, 0));
+        self
+    }
+
+    /// Disables the safety check that initial_data contains enough bytes to
+    /// fill the texture. The check knows the layout of every DXGI format,
+    /// including block compressed and planar ones, so this should only be
+    /// needed to work around a mistake in it.
+    ///
+    /// # Safety
+    ///
+    /// Direct3D reads a whole subresource from each entry of the initial
+    /// data, so every entry must hold enough bytes for its subresource.
+    pub unsafe fn with_unchecked_format(mut self) -> Self {
+        self.unchecked_format = true;
+        self
+    }
+
+    pub fn build(self) -> Result<Texture1D, BuildError> {
+        // The extent is only known to be sane once the description and
+        // limits are checked, so the data is ordered afterwards.
+        self.desc.validate(!self.initial_data.is_empty())?;
+        let limits = self.device.feature_level().limits();
+        self.desc.validate_limits(&limits).map_err(DescError::from)?;
+        let extent = self.desc.extent();
+        let items = self.initial_data.ordered(&extent)?;
+        if !items.is_empty() {
+            initial_data::check_subresource_count(items.len(), extent.subresource_count())?;
+            if !self.unchecked_format {
+                initial_data::check_subresources(&items, self.desc.format, &extent)?;
+            }
+        }
+
+        unsafe {
+            let mut v_initial_data = vec![];
+            let p_initial_data = initial_data::to_desc(&items, &mut v_initial_data);
+
+            let desc: D3D11_TEXTURE1D_DESC = self.desc.into();
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateTexture1D(&desc, p_initial_data, &mut ptr);
+
+            Ok(Error::map_if(hr, || Texture1D::from_raw(ptr))?)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub use self::desc::Texture1DDesc;
+#[cfg(windows)]
+pub use self::wrapper::Texture1D;
+
+#[cfg(windows)]
+pub mod builder;
+pub mod desc;
+#[cfg(windows)]
+mod wrapper;
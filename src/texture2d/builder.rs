@@ -1,217 +1,190 @@
-use crate::device::Device;
+use crate::device::IDevice;
 use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::{BuildError, DescError};
+use crate::image_view::ImageView;
+use crate::initial_data::{self, InitialData, SubresourceData};
+use crate::subresource::Subresource;
+use crate::texture2d::desc::Texture2DDesc;
 use crate::texture2d::Texture2D;
 
-use std::mem;
 use std::ptr;
 
 use com_wrapper::ComWrapper;
 use dcommon::error::Error;
 use dxgi::enums::Format;
-use winapi::um::d3d11::{D3D11_SUBRESOURCE_DATA, D3D11_TEXTURE2D_DESC};
+use winapi::um::d3d11::D3D11_TEXTURE2D_DESC;
 
 pub struct Texture2DBuilder<'a, 'b> {
-    device: &'a Device,
-    desc: D3D11_TEXTURE2D_DESC,
+    device: &'a dyn IDevice,
+    desc: Texture2DDesc,
     initial_data: InitialData<'b>,
     unchecked_format: bool,
 }
 
 impl<'a, 'b> Texture2DBuilder<'a, 'b> {
-    pub fn new(device: &'a Device) -> Self {
-        let mut desc: D3D11_TEXTURE2D_DESC = unsafe { mem::zeroed() };
-
-        desc.MipLevels = 1;
-        desc.ArraySize = 1;
-        desc.SampleDesc.Count = 1;
+    pub fn new(device: &'a dyn IDevice) -> Self {
+        Texture2DBuilder::from_desc(device, &Default::default())
+    }
 
+    /// Starts from an existing description, e.g. one returned by
+    /// `Texture2D::desc()` to create a texture of the same shape.
+    pub fn from_desc(device: &'a dyn IDevice, desc: &Texture2DDesc) -> Self {
         Texture2DBuilder {
             device,
-            desc,
+            desc: *desc,
             initial_data: InitialData::None,
             unchecked_format: false,
         }
     }
 
     pub fn with_size(mut self, width: u32, height: u32) -> Self {
-        self.desc.Width = width;
-        self.desc.Height = height;
+        self.desc.width = width;
+        self.desc.height = height;
         self
     }
 
     pub fn with_mip_levels(mut self, mip_levels: u32) -> Self {
-        self.desc.MipLevels = mip_levels;
+        self.desc.mip_levels = mip_levels;
         self
     }
 
     pub fn with_array_size(mut self, array_size: u32) -> Self {
-        self.desc.ArraySize = array_size;
+        self.desc.array_size = array_size;
         self
     }
 
     pub fn with_format(mut self, format: Format) -> Self {
-        self.desc.Format = format as u32;
+        self.desc.format = format;
         self
     }
 
     pub fn with_samples(mut self, count: u32, quality: u32) -> Self {
-        self.desc.SampleDesc.Count = count;
-        self.desc.SampleDesc.Quality = quality;
+        self.desc.sample_count = count;
+        self.desc.sample_quality = quality;
         self
     }
 
     pub fn with_usage(mut self, usage: Usage) -> Self {
-        self.desc.Usage = usage as u32;
+        self.desc.usage = usage;
         self
     }
 
     pub fn with_bind_flags(mut self, bind_flags: BindFlags) -> Self {
-        self.desc.BindFlags = bind_flags.0;
+        self.desc.bind_flags = bind_flags;
         self
     }
 
     pub fn with_cpu_access(mut self, cpu_access_flags: CpuAccessFlags) -> Self {
-        self.desc.CPUAccessFlags = cpu_access_flags.0;
+        self.desc.cpu_access_flags = cpu_access_flags;
         self
     }
 
     pub fn with_misc_flags(mut self, misc_flags: ResourceMiscFlags) -> Self {
-        self.desc.MiscFlags = misc_flags.0;
+        self.desc.misc_flags = misc_flags;
         self
     }
 
     pub fn with_initial_data(mut self, data: &'b [u8], pitch: u32) -> Self {
-        self.initial_data = InitialData::Single(data, pitch);
+        self.initial_data =
+            InitialData::Single(SubresourceData::new(data, pitch, data.len() as u32));
         self
     }
 
+    /// Initial data for every subresource of the texture, in subresource
+    /// order: all mip levels of the first array slice, followed by all mip
+    /// levels of the next one. The pitch is the row pitch in bytes.
     pub fn with_initial_data_slice(mut self, data: &'b [(&'b [u8], u32)]) -> Self {
-        self.initial_data = InitialData::Slice(data);
+        self.initial_data = InitialData::Vec(
+            data.iter()
+                .map(|&(bytes, pitch)| SubresourceData::new(bytes, pitch, bytes.len() as u32))
+                .collect(),
+        );
         self
     }
 
+    /// Same as [`with_initial_data_slice`](#method.with_initial_data_slice).
     pub fn with_initial_data_vec(mut self, data: Vec<(&'b [u8], u32)>) -> Self {
-        self.initial_data = InitialData::Vec(data);
+        self.initial_data = InitialData::Vec(
+            data.into_iter()
+                .map(|(bytes, pitch)| SubresourceData::new(bytes, pitch, bytes.len() as u32))
+                .collect(),
+        );
         self
     }
 
-    /// This flag must be used if you are specifying initial data using a
-    /// format with `pixel_size() == 0`. It disables the safety check that
-    /// initial_data contains enough bytes to fill the texture. Otherwise,
-    /// `build()` will panic if `format.pixel_size() == 0`.
-    pub unsafe fn with_unchecked_format(mut self) -> Self {
-        self.unchecked_format = true;
+    /// Initial data for a single subresource. When initial data is given this
+    /// way, it must be given for every subresource of the texture, each one
+    /// matching the dimensions of its mip level.
+    pub fn with_subresource_data(
+        mut self,
+        subresource: Subresource,
+        data: &'b [u8],
+        pitch: u32,
+    ) -> Self {
+        self.initial_data.insert(
+            subresource,
+            SubresourceData::new(data, pitch, data.len() as u32),
+        );
         self
     }
 
-    pub fn build(self) -> Result<Texture2D, Error> {
-        if !self.unchecked_format {
-            self.check_format();
-        }
-
-        unsafe {
-            let mut v_initial_data = vec![];
-            let p_initial_data = self
-                .initial_data
-                .to_desc(&mut v_initial_data, self.desc.ArraySize);
-
-            let mut ptr = ptr::null_mut();
-            let hr = (*self.device.get_raw()).CreateTexture2D(&self.desc, p_initial_data, &mut ptr);
-
-            Error::map_if(hr, || Texture2D::from_raw(ptr))
-        }
+    /// Initial data for the first subresource, taken from an image that may
+    /// have padded rows.
+    pub fn with_image(mut self, image: ImageView<'b>) -> Self {
+        self.initial_data = InitialData::Single(image.as_subresource_data());
+        self
     }
 
-    fn check_format(&self) {
-        self.initial_data.check_format(self.desc.Width);
+    /// Same as [`with_subresource_data`](#method.with_subresource_data),
+    /// taking the data and pitch from an image.
+    pub fn with_subresource_image(
+        mut self,
+        subresource: Subresource,
+        image: ImageView<'b>,
+    ) -> Self {
+        self.initial_data
+            .insert(subresource, image.as_subresource_data());
+        self
     }
-}
 
-enum InitialData<'a> {
-    None,
-    Single(&'a [u8], u32),
-    Slice(&'a [(&'a [u8], u32)]),
-    Vec(Vec<(&'a [u8], u32)>),
-}
-
-impl<'a> InitialData<'a> {
-    fn len(&self) -> usize {
-        match *self {
-            InitialData::None => 0,
-            InitialData::Single(..) => 1,
-            InitialData::Slice(items) => items.len(),
-            InitialData::Vec(ref items) => items.len(),
-        }
+    /// Disables the safety check that initial_data contains enough bytes to
+    /// fill the texture. The check knows the layout of every DXGI format,
+    /// including block compressed and planar ones, so this should only be
+    /// needed to work around a mistake in it.
+    ///
+    /// # Safety
+    ///
+    /// Direct3D reads a whole subresource from each entry of the initial
+    /// data, so every entry must hold enough bytes for its subresource.
+    pub unsafe fn with_unchecked_format(mut self) -> Self {
+        self.unchecked_format = true;
+        self
     }
 
-    fn to_desc(
-        &self,
-        data: &mut Vec<D3D11_SUBRESOURCE_DATA>,
-        array_size: u32,
-    ) -> *const D3D11_SUBRESOURCE_DATA {
-        if self.len() == 0 {
-            return ptr::null();
+    pub fn build(self) -> Result<Texture2D, BuildError> {
+        // The extent is only known to be sane once the description and
+        // limits are checked, so the data is ordered afterwards.
+        self.desc.validate(!self.initial_data.is_empty())?;
+        let limits = self.device.feature_level().limits();
+        self.desc.validate_limits(&limits).map_err(DescError::from)?;
+        let extent = self.desc.extent();
+        let items = self.initial_data.ordered(&extent)?;
+        if !items.is_empty() {
+            initial_data::check_subresource_count(items.len(), extent.subresource_count())?;
+            if !self.unchecked_format {
+                initial_data::check_subresources(&items, self.desc.format, &extent)?;
+            }
         }
 
-        let len = self.len();
-        assert!(len == array_size as usize);
-        data.clear();
-        data.reserve(len);
         unsafe {
-            data.set_len(len);
-
-            self.each(|i, bytes, pitch| {
-                let desc = &mut data[i];
-                desc.pSysMem = bytes.as_ptr() as *const _;
-                desc.SysMemPitch = pitch;
-                desc.SysMemSlicePitch = bytes.len() as u32;
-            });
-
-            data.as_ptr()
-        }
-    }
+            let mut v_initial_data = vec![];
+            let p_initial_data = initial_data::to_desc(&items, &mut v_initial_data);
 
-    fn each<F>(&self, mut f: F)
-    where
-        F: FnMut(usize, &'a [u8], u32),
-    {
-        match *self {
-            InitialData::None => (),
-            InitialData::Single(bytes, pitch) => f(0, bytes, pitch),
-            InitialData::Slice(items) => {
-                for (i, &(bytes, pitch)) in items.iter().enumerate() {
-                    f(i, bytes, pitch);
-                }
-            }
-            InitialData::Vec(ref items) => {
-                for (i, &(bytes, pitch)) in items.iter().enumerate() {
-                    f(i, bytes, pitch);
-                }
-            }
-        }
-    }
+            let desc: D3D11_TEXTURE2D_DESC = self.desc.into();
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateTexture2D(&desc, p_initial_data, &mut ptr);
 
-    fn check_format(&self, width: u32) {
-        match *self {
-            InitialData::None => (),
-            InitialData::Single(bytes, pitch) => {
-                InitialData::check_format_item(bytes, pitch, width);
-            }
-            InitialData::Slice(items) => {
-                for &(bytes, pitch) in items {
-                    InitialData::check_format_item(bytes, pitch, width);
-                }
-            }
-            InitialData::Vec(ref items) => {
-                for &(bytes, pitch) in items {
-                    InitialData::check_format_item(bytes, pitch, width);
-                }
-            }
+            Ok(Error::map_if(hr, || Texture2D::from_raw(ptr))?)
         }
     }
-
-    fn check_format_item(bytes: &[u8], pitch: u32, width: u32) {
-        let img_bytes = width as usize * pitch as usize;
-        assert!(bytes.len() >= img_bytes);
-    }
 }
@@ -1 +1,154 @@
+#[cfg(windows)]
+use crate::enums;
+use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::DescError;
+use crate::format_info::Format;
+use crate::initial_data::TextureExtent;
+use crate::limits::{LimitError, Limits};
+use crate::validation;
 
+#[cfg(windows)]
+use std::convert::TryFrom;
+
+#[cfg(windows)]
+use winapi::shared::dxgitype::DXGI_SAMPLE_DESC;
+#[cfg(windows)]
+use winapi::um::d3d11::D3D11_TEXTURE2D_DESC;
+
+/// Describes a 2D texture.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Texture2DDesc {
+    pub width: u32,
+    pub height: u32,
+    pub mip_levels: u32,
+    pub array_size: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::enums::serde_impls::format"))]
+    pub format: Format,
+    pub sample_count: u32,
+    pub sample_quality: u32,
+    pub usage: Usage,
+    pub bind_flags: BindFlags,
+    pub cpu_access_flags: CpuAccessFlags,
+    pub misc_flags: ResourceMiscFlags,
+}
+
+impl Default for Texture2DDesc {
+    fn default() -> Self {
+        Texture2DDesc {
+            width: 0,
+            height: 0,
+            mip_levels: 1,
+            array_size: 1,
+            format: Format::Unknown,
+            sample_count: 1,
+            sample_quality: 0,
+            usage: Usage::Default,
+            bind_flags: BindFlags::NONE,
+            cpu_access_flags: CpuAccessFlags::NONE,
+            misc_flags: ResourceMiscFlags::NONE,
+        }
+    }
+}
+
+impl Texture2DDesc {
+    pub fn extent(&self) -> TextureExtent {
+        TextureExtent {
+            width: self.width,
+            height: self.height,
+            depth: 1,
+            mip_levels: self.mip_levels,
+            array_size: self.array_size,
+        }
+    }
+
+    /// Checks the description against the rules Direct3D enforces when
+    /// creating the texture.
+    pub fn validate(&self, has_initial_data: bool) -> Result<(), DescError> {
+        validation::validate_usage(
+            self.usage,
+            self.bind_flags,
+            self.cpu_access_flags,
+            has_initial_data,
+        )?;
+        validation::validate_texture(
+            &self.extent(),
+            self.format,
+            self.usage,
+            self.bind_flags,
+            self.misc_flags,
+        )?;
+
+        if self.misc_flags.is_set(ResourceMiscFlags::TEXTURECUBE)
+            && !self.array_size.is_multiple_of(6)
+        {
+            return Err(DescError::CubeArraySize {
+                array_size: self.array_size,
+            });
+        }
+
+        if self.sample_count > 1 && self.mip_levels != 1 {
+            return Err(DescError::MultisampledMipLevels {
+                mip_levels: self.mip_levels,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks the description against the limits of a feature level, see
+    /// `FeatureLevel::limits`.
+    pub fn validate_limits(&self, limits: &Limits) -> Result<(), LimitError> {
+        limits.check_texture2d(
+            self.width,
+            self.height,
+            self.array_size,
+            self.misc_flags.is_set(ResourceMiscFlags::TEXTURECUBE),
+        )?;
+        validation::validate_texture_limits(limits, self.format, self.bind_flags)
+    }
+}
+
+#[cfg(windows)]
+impl From<Texture2DDesc> for D3D11_TEXTURE2D_DESC {
+    fn from(desc: Texture2DDesc) -> Self {
+        D3D11_TEXTURE2D_DESC {
+            Width: desc.width,
+            Height: desc.height,
+            MipLevels: desc.mip_levels,
+            ArraySize: desc.array_size,
+            Format: desc.format as u32,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: desc.sample_count,
+                Quality: desc.sample_quality,
+            },
+            Usage: desc.usage as u32,
+            BindFlags: desc.bind_flags.0,
+            CPUAccessFlags: desc.cpu_access_flags.0,
+            MiscFlags: desc.misc_flags.0,
+        }
+    }
+}
+
+#[cfg(windows)]
+impl TryFrom<D3D11_TEXTURE2D_DESC> for Texture2DDesc {
+    type Error = DescError;
+
+    /// Fails if the format or usage is not a value known to this crate.
+    fn try_from(desc: D3D11_TEXTURE2D_DESC) -> Result<Self, DescError> {
+        Ok(Texture2DDesc {
+            width: desc.Width,
+            height: desc.Height,
+            mip_levels: desc.MipLevels,
+            array_size: desc.ArraySize,
+            format: enums::format_from_raw("Format", desc.Format)?,
+            sample_count: desc.SampleDesc.Count,
+            sample_quality: desc.SampleDesc.Quality,
+            usage: enums::usage_from_raw(desc.Usage)?,
+            bind_flags: BindFlags(desc.BindFlags),
+            cpu_access_flags: CpuAccessFlags(desc.CPUAccessFlags),
+            misc_flags: ResourceMiscFlags(desc.MiscFlags),
+        })
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(windows)]
+use crate::enums;
+use crate::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use crate::error::DescError;
+use crate::format_info::Format;
+use crate::initial_data::TextureExtent;
+use crate::limits::{LimitError, Limits};
+use crate::validation;
+
+#[cfg(windows)]
+use std::convert::TryFrom;
+
+#[cfg(windows)]
+use winapi::um::d3d11::D3D11_TEXTURE3D_DESC;
+
+/// Describes a 3D texture.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Texture3DDesc {
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub mip_levels: u32,
+    #[cfg_attr(feature = "serde", serde(with = "crate::enums::serde_impls::format"))]
+    pub format: Format,
+    pub usage: Usage,
+    pub bind_flags: BindFlags,
+    pub cpu_access_flags: CpuAccessFlags,
+    pub misc_flags: ResourceMiscFlags,
+}
+
+impl Default for Texture3DDesc {
+    fn default() -> Self {
+        Texture3DDesc {
+            width: 0,
+            height: 0,
+            depth: 0,
+            mip_levels: 1,
+            format: Format::Unknown,
+            usage: Usage::Default,
+            bind_flags: BindFlags::NONE,
+            cpu_access_flags: CpuAccessFlags::NONE,
+            misc_flags: ResourceMiscFlags::NONE,
+        }
+    }
+}
+
+impl Texture3DDesc {
+    pub fn extent(&self) -> TextureExtent {
+        TextureExtent {
+            width: self.width,
+            height: self.height,
+            depth: self.depth,
+            mip_levels: self.mip_levels,
+            array_size: 1,
+        }
+    }
+
+    /// Checks the description against the rules Direct3D enforces when
+    /// creating the texture.
+    pub fn validate(&self, has_initial_data: bool) -> Result<(), DescError> {
+        validation::validate_usage(
+            self.usage,
+            self.bind_flags,
+            self.cpu_access_flags,
+            has_initial_data,
+        )?;
+        validation::validate_texture(
+            &self.extent(),
+            self.format,
+            self.usage,
+            self.bind_flags,
+            self.misc_flags,
+        )?;
+
+        if self.bind_flags.is_set(BindFlags::DEPTH_STENCIL) {
+            return Err(DescError::InvalidBindFlags {
+                bind_flags: self.bind_flags,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Checks the description against the limits of a feature level, see
+    /// `FeatureLevel::limits`.
+    pub fn validate_limits(&self, limits: &Limits) -> Result<(), LimitError> {
+        limits.check_texture3d(self.width, self.height, self.depth)?;
+        validation::validate_texture_limits(limits, self.format, self.bind_flags)
+    }
+}
+
+#[cfg(windows)]
+impl From<Texture3DDesc> for D3D11_TEXTURE3D_DESC {
+    fn from(desc: Texture3DDesc) -> Self {
+        D3D11_TEXTURE3D_DESC {
+            Width: desc.width,
+            Height: desc.height,
+            Depth: desc.depth,
+            MipLevels: desc.mip_levels,
+            Format: desc.format as u32,
+            Usage: desc.usage as u32,
+            BindFlags: desc.bind_flags.0,
+            CPUAccessFlags: desc.cpu_access_flags.0,
+            MiscFlags: desc.misc_flags.0,
+        }
+    }
+}
+
+#[cfg(windows)]
+impl TryFrom<D3D11_TEXTURE3D_DESC> for Texture3DDesc {
+    type Error = DescError;
+
+    /// Fails if the format or usage is not a value known to this crate.
+    fn try_from(desc: D3D11_TEXTURE3D_DESC) -> Result<Self, DescError> {
+        Ok(Texture3DDesc {
+            width: desc.Width,
+            height: desc.Height,
+            depth: desc.Depth,
+            mip_levels: desc.MipLevels,
+            format: enums::format_from_raw("Format", desc.Format)?,
+            usage: enums::usage_from_raw(desc.Usage)?,
+            bind_flags: BindFlags(desc.BindFlags),
+            cpu_access_flags: CpuAccessFlags(desc.CPUAccessFlags),
+            misc_flags: ResourceMiscFlags(desc.MiscFlags),
+        })
+    }
+}
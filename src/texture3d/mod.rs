@@ -0,0 +1,9 @@
+pub use self::desc::Texture3DDesc;
+#[cfg(windows)]
+pub use self::wrapper::Texture3D;
+
+#[cfg(windows)]
+pub mod builder;
+pub mod desc;
+#[cfg(windows)]
+mod wrapper;
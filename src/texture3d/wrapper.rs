@@ -0,0 +1,78 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::resource::{IResource, Resource};
+use crate::error::{DescError, ViewError};
+use crate::view::{RenderTargetView, ShaderResourceView, UnorderedAccessView};
+use crate::texture3d::builder;
+use crate::texture3d::desc::Texture3DDesc;
+
+use std::convert::TryFrom;
+use std::mem;
+
+use com_wrapper::ComWrapper;
+use winapi::um::d3d11::{ID3D11DeviceChild, ID3D11Resource, ID3D11Texture3D, D3D11_TEXTURE3D_DESC};
+use wio::com::ComPtr;
+
+#[derive(ComWrapper, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct Texture3D {
+    ptr: ComPtr<ID3D11Texture3D>,
+}
+
+impl Texture3D {
+    #[inline]
+    pub fn create(device: &dyn IDevice) -> builder::Texture3DBuilder<'_, '_> {
+        builder::Texture3DBuilder::new(device)
+    }
+
+    /// The description the texture was created with, or an error if the
+    /// runtime reports a format or usage unknown to this crate. `raw_desc`
+    /// returns the description as it is.
+    pub fn desc(&self) -> Result<Texture3DDesc, DescError> {
+        Texture3DDesc::try_from(self.raw_desc())
+    }
+
+    pub fn raw_desc(&self) -> D3D11_TEXTURE3D_DESC {
+        unsafe {
+            let mut desc = mem::zeroed();
+            self.ptr.GetDesc(&mut desc);
+            desc
+        }
+    }
+
+    #[inline]
+    pub fn as_resource(&self) -> Resource {
+        unsafe { Resource::from_ptr(self.ptr.cast::<ID3D11Resource>().unwrap()) }
+    }
+
+    /// Creates a shader resource view of the whole texture on
+    /// `device`, which must be the device that created it.
+    pub fn create_srv(&self, device: &dyn IDevice) -> Result<ShaderResourceView, ViewError> {
+        ShaderResourceView::create(device, self).build()
+    }
+
+    /// Creates a render target view of the top mip level of the texture on
+    /// `device`, which must be the device that created it.
+    pub fn create_rtv(&self, device: &dyn IDevice) -> Result<RenderTargetView, ViewError> {
+        RenderTargetView::create(device, self).build()
+    }
+
+    /// Creates an unordered access view of the top mip level of the texture on
+    /// `device`, which must be the device that created it.
+    pub fn create_uav(&self, device: &dyn IDevice) -> Result<UnorderedAccessView, ViewError> {
+        UnorderedAccessView::create(device, self).build()
+    }
+}
+
+unsafe impl IDeviceChild for Texture3D {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+unsafe impl IResource for Texture3D {
+    unsafe fn raw_res(&self) -> &ID3D11Resource {
+        &self.ptr
+    }
+}
@@ -0,0 +1,360 @@
+//! Checks for the documented rules on resource descriptions. Direct3D
+//! rejects descriptions breaking these rules with `E_INVALIDARG` and, at
+//! best, a message from the debug layer; checking them up front lets the
+//! builders report exactly what is wrong.
+
+use crate::enums::{BindFlags, CpuAccessFlags, Map, ResourceMiscFlags, Usage};
+use crate::error::{CopyError, DescError, MapError};
+use crate::format_info::{BlockCompression, Format, FormatInfo};
+use crate::initial_data::TextureExtent;
+use crate::limits::{Feature, LimitError, Limits};
+use crate::subresource::{self, Box3D, Subresource};
+
+/// Bind flags that make a resource an output of the pipeline.
+const OUTPUT_BIND_FLAGS: u32 = BindFlags::STREAM_OUTPUT.0
+    | BindFlags::RENDER_TARGET.0
+    | BindFlags::DEPTH_STENCIL.0
+    | BindFlags::UNORDERED_ACCESS.0;
+
+/// Bind flags that only apply to buffers.
+const BUFFER_BIND_FLAGS: u32 = BindFlags::VERTEX_BUFFER.0
+    | BindFlags::INDEX_BUFFER.0
+    | BindFlags::CONSTANT_BUFFER.0
+    | BindFlags::STREAM_OUTPUT.0;
+
+/// The kind of a resource.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ResourceDimension {
+    Buffer,
+    Texture1D,
+    Texture2D,
+    Texture3D,
+}
+
+/// The parts of a resource's description the device context needs to check
+/// operations on it, whatever the kind of resource.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ResourceInfo {
+    pub dimension: ResourceDimension,
+    pub usage: Usage,
+    pub bind_flags: BindFlags,
+    pub cpu_access_flags: CpuAccessFlags,
+    pub misc_flags: ResourceMiscFlags,
+    /// `Format::Unknown` for buffers.
+    pub format: Format,
+    /// For buffers, the width is the size in bytes.
+    pub extent: TextureExtent,
+    pub sample_count: u32,
+    /// The element size of a structured buffer. 0 for other resources.
+    pub structure_byte_stride: u32,
+}
+
+impl ResourceInfo {
+    pub fn is_buffer(&self) -> bool {
+        self.dimension == ResourceDimension::Buffer
+    }
+
+    pub fn subresource_count(&self) -> u32 {
+        self.extent.subresource_count()
+    }
+
+    /// The width, height and depth of a subresource. For buffers, the width
+    /// is the size in bytes.
+    pub fn subresource_size(&self, subresource: u32) -> (u32, u32, u32) {
+        let mip = Subresource::from_index(subresource, self.extent.mip_count()).mip;
+        (
+            subresource::mip_size(self.extent.width, mip),
+            subresource::mip_size(self.extent.height, mip),
+            subresource::mip_size(self.extent.depth, mip),
+        )
+    }
+}
+
+/// Checks how usage, bind flags and CPU access flags may be combined. These
+/// rules apply to every kind of resource.
+pub fn validate_usage(
+    usage: Usage,
+    bind_flags: BindFlags,
+    cpu_access_flags: CpuAccessFlags,
+    has_initial_data: bool,
+) -> Result<(), DescError> {
+    let cpu_access_error = Err(DescError::UsageCpuAccess {
+        usage,
+        cpu_access_flags,
+    });
+
+    match usage {
+        Usage::Default => {
+            if cpu_access_flags != CpuAccessFlags::NONE {
+                return cpu_access_error;
+            }
+        }
+        Usage::Immutable => {
+            if !has_initial_data {
+                return Err(DescError::ImmutableWithoutInitialData);
+            }
+            if cpu_access_flags != CpuAccessFlags::NONE {
+                return cpu_access_error;
+            }
+        }
+        Usage::Dynamic => {
+            if cpu_access_flags != CpuAccessFlags::WRITE {
+                return cpu_access_error;
+            }
+        }
+        Usage::Staging => {
+            if cpu_access_flags == CpuAccessFlags::NONE {
+                return cpu_access_error;
+            }
+            if bind_flags != BindFlags::NONE {
+                return Err(DescError::StagingBindFlags { bind_flags });
+            }
+        }
+    }
+
+    if (usage == Usage::Immutable || usage == Usage::Dynamic)
+        && bind_flags.0 & OUTPUT_BIND_FLAGS != 0
+    {
+        return Err(DescError::UsageBindFlags { usage, bind_flags });
+    }
+
+    Ok(())
+}
+
+/// Checks the rules shared by textures of every dimension.
+pub fn validate_texture(
+    extent: &TextureExtent,
+    format: Format,
+    usage: Usage,
+    bind_flags: BindFlags,
+    misc_flags: ResourceMiscFlags,
+) -> Result<(), DescError> {
+    if extent.width == 0 || extent.height == 0 || extent.depth == 0 || extent.array_size == 0 {
+        return Err(DescError::ZeroSize);
+    }
+
+    let info = FormatInfo::of(format);
+    if info.is_unknown() {
+        return Err(DescError::MissingFormat);
+    }
+
+    let max_mips = subresource::full_mip_count(extent.width, extent.height, extent.depth);
+    if extent.mip_levels > max_mips {
+        return Err(DescError::TooManyMipLevels {
+            mip_levels: extent.mip_levels,
+            max: max_mips,
+        });
+    }
+
+    if usage == Usage::Dynamic && (extent.mip_count() > 1 || extent.array_size > 1) {
+        return Err(DescError::DynamicSubresources {
+            mip_levels: extent.mip_levels,
+            array_size: extent.array_size,
+        });
+    }
+
+    if bind_flags.0 & BUFFER_BIND_FLAGS != 0
+        || bind_flags.is_set(BindFlags::DEPTH_STENCIL)
+            && bind_flags.is_set(BindFlags::RENDER_TARGET)
+    {
+        return Err(DescError::InvalidBindFlags { bind_flags });
+    }
+
+    if bind_flags.is_set(BindFlags::DEPTH_STENCIL) && !info.is_depth() && !info.is_typeless() {
+        return Err(DescError::DepthStencilFormat { format });
+    }
+
+    if info.is_block_compressed()
+        && (!extent.width.is_multiple_of(info.block_width)
+            || !extent.height.is_multiple_of(info.block_height))
+    {
+        return Err(DescError::BlockCompressedSize {
+            width: extent.width,
+            height: extent.height,
+        });
+    }
+
+    if misc_flags.is_set(ResourceMiscFlags::GENERATE_MIPS)
+        && !(bind_flags.is_set(BindFlags::RENDER_TARGET)
+            && bind_flags.is_set(BindFlags::SHADER_RESOURCE))
+    {
+        return Err(DescError::GenerateMipsBindFlags { bind_flags });
+    }
+
+    Ok(())
+}
+
+/// Checks the format and bind flags of a texture against the features of a
+/// feature level. The size is checked by the `Limits::check_*` methods.
+pub fn validate_texture_limits(
+    limits: &Limits,
+    format: Format,
+    bind_flags: BindFlags,
+) -> Result<(), LimitError> {
+    match FormatInfo::of(format).block_compression() {
+        Some(BlockCompression::Bc6h) | Some(BlockCompression::Bc7) => {
+            limits.require(Feature::Bc6hBc7)?
+        }
+        _ => {}
+    }
+    if bind_flags.is_set(BindFlags::UNORDERED_ACCESS) {
+        limits.require(Feature::UnorderedAccess)?;
+    }
+    Ok(())
+}
+
+/// Checks that a resource created with `usage`, `bind_flags` and
+/// `cpu_access_flags` can be mapped with `map`.
+pub fn validate_map(
+    map: Map,
+    usage: Usage,
+    bind_flags: BindFlags,
+    cpu_access_flags: CpuAccessFlags,
+) -> Result<(), MapError> {
+    let allowed = match usage {
+        Usage::Default | Usage::Immutable => false,
+        Usage::Dynamic => map == Map::Discard || map == Map::WriteNoOverwrite,
+        Usage::Staging => map != Map::Discard && map != Map::WriteNoOverwrite,
+    };
+    if !allowed {
+        return Err(MapError::UsageMode { map, usage });
+    }
+
+    let required = match map {
+        Map::Read => CpuAccessFlags::READ.0,
+        Map::ReadWrite => CpuAccessFlags::READ.0 | CpuAccessFlags::WRITE.0,
+        Map::Write | Map::Discard | Map::WriteNoOverwrite => CpuAccessFlags::WRITE.0,
+    };
+    if cpu_access_flags.0 & required != required {
+        return Err(MapError::CpuAccess {
+            map,
+            cpu_access_flags,
+        });
+    }
+
+    if map == Map::WriteNoOverwrite && bind_flags.is_set(BindFlags::CONSTANT_BUFFER) {
+        return Err(MapError::NoOverwriteConstantBuffer);
+    }
+
+    Ok(())
+}
+
+/// Checks that `region` lies inside a subresource of `size` texels of
+/// `format` and, for block compressed formats, that it is aligned to blocks.
+pub fn validate_box(region: &Box3D, format: Format, size: (u32, u32, u32)) -> Result<(), CopyError> {
+    if region.is_empty() {
+        return Err(CopyError::EmptyBox { region: *region });
+    }
+    let (width, height, depth) = size;
+    if region.right > width || region.bottom > height || region.back > depth {
+        return Err(CopyError::BoxOutOfBounds {
+            region: *region,
+            size,
+        });
+    }
+
+    let info = FormatInfo::of(format);
+    let aligned = |start: u32, end: u32, edge: u32, block: u32| {
+        start.is_multiple_of(block) && (end.is_multiple_of(block) || end == edge)
+    };
+    if !aligned(region.left, region.right, width, info.block_width)
+        || !aligned(region.top, region.bottom, height, info.block_height)
+    {
+        return Err(CopyError::BoxAlignment {
+            region: *region,
+            format,
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks an update of a subresource of `dst_size` texels of `dst_format`
+/// from an image of `image_size` texels of `image_format`. Without a box,
+/// the whole subresource is updated.
+pub fn validate_update(
+    dst_format: Format,
+    dst_size: (u32, u32, u32),
+    dst_box: Option<Box3D>,
+    image_format: Format,
+    image_size: (u32, u32, u32),
+) -> Result<(), CopyError> {
+    check_formats(image_format, dst_format)?;
+    let region = dst_box.unwrap_or_else(|| Box3D::whole(dst_size));
+    validate_box(&region, dst_format, dst_size)?;
+    if region.size() != image_size {
+        return Err(CopyError::ImageSize {
+            expected: region.size(),
+            actual: image_size,
+        });
+    }
+    Ok(())
+}
+
+/// Checks a copy of `src_box`, or the whole source subresource, to
+/// `dst_offset` in the destination subresource.
+pub fn validate_copy_region(
+    src_format: Format,
+    src_size: (u32, u32, u32),
+    src_box: Option<Box3D>,
+    dst_format: Format,
+    dst_size: (u32, u32, u32),
+    dst_offset: (u32, u32, u32),
+) -> Result<(), CopyError> {
+    check_formats(src_format, dst_format)?;
+    let src_region = src_box.unwrap_or_else(|| Box3D::whole(src_size));
+    validate_box(&src_region, src_format, src_size)?;
+    let size = src_region.size();
+    let dst_region = Box3D::from_offset_size(dst_offset, size).ok_or_else(|| {
+        // The far edges are past u32::MAX, and so past any subresource.
+        let (x, y, z) = dst_offset;
+        let region = Box3D::new(
+            x,
+            y,
+            z,
+            x.saturating_add(size.0),
+            y.saturating_add(size.1),
+            z.saturating_add(size.2),
+        );
+        CopyError::BoxOutOfBounds {
+            region,
+            size: dst_size,
+        }
+    })?;
+    validate_box(&dst_region, dst_format, dst_size)
+}
+
+fn check_formats(src: Format, dst: Format) -> Result<(), CopyError> {
+    if !FormatInfo::of(src).is_cast_compatible(FormatInfo::of(dst)) {
+        return Err(CopyError::IncompatibleFormats { src, dst });
+    }
+    Ok(())
+}
+
+/// Checks that `src` can be copied to `dst` as a whole with `CopyResource`.
+pub fn validate_copy_resource(src: &ResourceInfo, dst: &ResourceInfo) -> Result<(), CopyError> {
+    if src.dimension != dst.dimension {
+        return Err(CopyError::DimensionMismatch);
+    }
+    if dst.usage == Usage::Immutable {
+        return Err(CopyError::DestinationUsage { usage: dst.usage });
+    }
+    let same_extent = src.extent.width == dst.extent.width
+        && src.extent.height == dst.extent.height
+        && src.extent.depth == dst.extent.depth
+        && src.extent.mip_count() == dst.extent.mip_count()
+        && src.extent.array_size == dst.extent.array_size;
+    if !same_extent {
+        return Err(CopyError::ExtentMismatch {
+            src: src.extent,
+            dst: dst.extent,
+        });
+    }
+    if src.sample_count != dst.sample_count {
+        return Err(CopyError::SampleCountMismatch {
+            src: src.sample_count,
+            dst: dst.sample_count,
+        });
+    }
+    check_formats(src.format, dst.format)
+}
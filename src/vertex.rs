@@ -0,0 +1,423 @@
+//! Descriptions of vertex buffer contents, from which input layouts are
+//! created.
+//!
+//! Vertex structs usually describe themselves with `#[derive(Vertex)]`:
+//!
+//! ```
+//! use direct3d11::vertex::Vertex;
+//!
+//! #[derive(Copy, Clone, Vertex)]
+//! #[repr(C)]
+//! struct TexturedVertex {
+//!     position: [f32; 3],
+//!     #[semantic("TEXCOORD", 0)]
+//!     uv: [f32; 2],
+//!     #[format(R8G8B8A8Unorm)]
+//!     color: u32,
+//! }
+//!
+//! let elements = TexturedVertex::elements();
+//! assert_eq!(elements[0].semantic_name, "POSITION");
+//! assert_eq!(elements[2].offset, 20);
+//! ```
+//!
+//! The attributes are:
+//!
+//! - `#[semantic("NAME", index)]` on a field gives its semantic. The index
+//!   defaults to 0. Without the attribute, the semantic is the field name in
+//!   upper case, with trailing digits as the index: `uv1` is `UV` index 1.
+//! - `#[format(Variant)]` on a field gives its `VertexFormat`, which must be
+//!   the size of the field. Without it, the format comes from the field's
+//!   `VertexAttribute` implementation.
+//! - `#[instance(step_rate)]` on the struct makes every element per-instance
+//!   data, advancing once every `step_rate` instances. `#[instance]` alone
+//!   means a step rate of 1.
+//!
+//! Offsets are those of the struct as compiled, so fields may be in any
+//! order, but the struct should be `#[repr(C)]` to have a stable layout to
+//! copy into buffers.
+//!
+//! [`validate_input_layout`] checks a list of elements against the input
+//! signature of a vertex shader, which `InputLayoutBuilder::build` does
+//! before handing the elements to Direct3D.
+//!
+//! Like the `dxbc` module, this module is available on every platform.
+
+use crate::dxbc::{ComponentType, Signature, SignatureElement, SystemValue};
+
+use std::error::Error as StdError;
+use std::fmt;
+
+pub use direct3d11_derive::Vertex;
+
+macro_rules! vertex_formats {
+    ($($(#[$attr:meta])* $name:ident = $raw:expr, $size:expr, $components:expr, $ty:ident;)*) => {
+        /// The DXGI formats the input assembler can read from a vertex
+        /// buffer.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum VertexFormat {
+            $($(#[$attr])* $name,)*
+        }
+
+        impl VertexFormat {
+            /// The raw `DXGI_FORMAT` value.
+            pub const fn to_raw(self) -> u32 {
+                match self {
+                    $(VertexFormat::$name => $raw,)*
+                }
+            }
+
+            pub fn from_raw(value: u32) -> Option<VertexFormat> {
+                match value {
+                    $($raw => Some(VertexFormat::$name),)*
+                    _ => None,
+                }
+            }
+
+            /// The size of one element in bytes.
+            pub const fn size(self) -> u32 {
+                match self {
+                    $(VertexFormat::$name => $size,)*
+                }
+            }
+
+            /// The number of components the shader receives.
+            pub const fn component_count(self) -> u32 {
+                match self {
+                    $(VertexFormat::$name => $components,)*
+                }
+            }
+
+            /// The type of the components as the shader receives them.
+            /// Normalized and float formats are all read as `Float32`.
+            pub const fn component_type(self) -> ComponentType {
+                match self {
+                    $(VertexFormat::$name => ComponentType::$ty,)*
+                }
+            }
+
+            /// The alignment the offset of an element needs: the size of one
+            /// component, or 4 for the packed formats.
+            pub const fn alignment(self) -> u32 {
+                match self {
+                    VertexFormat::R10G10B10A2Unorm
+                    | VertexFormat::R10G10B10A2Uint
+                    | VertexFormat::R11G11B10Float => 4,
+                    _ => self.size() / self.component_count(),
+                }
+            }
+        }
+    };
+}
+
+vertex_formats! {
+    R32G32B32A32Float = 2, 16, 4, Float32;
+    R32G32B32A32Uint = 3, 16, 4, UInt32;
+    R32G32B32A32Sint = 4, 16, 4, SInt32;
+    R32G32B32Float = 6, 12, 3, Float32;
+    R32G32B32Uint = 7, 12, 3, UInt32;
+    R32G32B32Sint = 8, 12, 3, SInt32;
+    R16G16B16A16Float = 10, 8, 4, Float32;
+    R16G16B16A16Unorm = 11, 8, 4, Float32;
+    R16G16B16A16Uint = 12, 8, 4, UInt32;
+    R16G16B16A16Snorm = 13, 8, 4, Float32;
+    R16G16B16A16Sint = 14, 8, 4, SInt32;
+    R32G32Float = 16, 8, 2, Float32;
+    R32G32Uint = 17, 8, 2, UInt32;
+    R32G32Sint = 18, 8, 2, SInt32;
+    R10G10B10A2Unorm = 24, 4, 4, Float32;
+    R10G10B10A2Uint = 25, 4, 4, UInt32;
+    R11G11B10Float = 26, 4, 3, Float32;
+    R8G8B8A8Unorm = 28, 4, 4, Float32;
+    R8G8B8A8Uint = 30, 4, 4, UInt32;
+    R8G8B8A8Snorm = 31, 4, 4, Float32;
+    R8G8B8A8Sint = 32, 4, 4, SInt32;
+    R16G16Float = 34, 4, 2, Float32;
+    R16G16Unorm = 35, 4, 2, Float32;
+    R16G16Uint = 36, 4, 2, UInt32;
+    R16G16Snorm = 37, 4, 2, Float32;
+    R16G16Sint = 38, 4, 2, SInt32;
+    R32Float = 41, 4, 1, Float32;
+    R32Uint = 42, 4, 1, UInt32;
+    R32Sint = 43, 4, 1, SInt32;
+    R8G8Unorm = 49, 2, 2, Float32;
+    R8G8Uint = 50, 2, 2, UInt32;
+    R8G8Snorm = 51, 2, 2, Float32;
+    R8G8Sint = 52, 2, 2, SInt32;
+    R16Float = 54, 2, 1, Float32;
+    R16Unorm = 56, 2, 1, Float32;
+    R16Uint = 57, 2, 1, UInt32;
+    R16Snorm = 58, 2, 1, Float32;
+    R16Sint = 59, 2, 1, SInt32;
+    R8Unorm = 61, 1, 1, Float32;
+    R8Uint = 62, 1, 1, UInt32;
+    R8Snorm = 63, 1, 1, Float32;
+    R8Sint = 64, 1, 1, SInt32;
+    /// Read with red and blue swapped, for colors packed as `0xAARRGGBB`.
+    B8G8R8A8Unorm = 87, 4, 4, Float32;
+}
+
+/// Whether an element advances with each vertex or with each instance.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InputClassification {
+    PerVertex,
+    PerInstance,
+}
+
+/// One element of an input layout, matched by semantic to an input of the
+/// vertex shader. Mirrors `D3D11_INPUT_ELEMENT_DESC`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct InputElement {
+    pub semantic_name: &'static str,
+    pub semantic_index: u32,
+    pub format: VertexFormat,
+    /// The vertex buffer slot the element is read from.
+    pub input_slot: u32,
+    /// The offset of the element from the start of a vertex, in bytes.
+    pub offset: u32,
+    pub classification: InputClassification,
+    /// The number of instances drawn before per-instance data advances. 0
+    /// for per-vertex data.
+    pub instance_step_rate: u32,
+}
+
+/// A type stored in vertex buffers, described as a list of input elements
+/// in slot 0. Usually derived, see the [module documentation](index.html).
+pub trait Vertex {
+    fn elements() -> Vec<InputElement>;
+}
+
+/// A field type with a natural vertex format, used by `#[derive(Vertex)]`
+/// for fields without `#[format]`. Arrays of `u8`, `i8`, `u16` and `i16`
+/// are normalized, as they usually hold colors and packed directions; use
+/// `#[format]` to read them as integers.
+pub trait VertexAttribute {
+    const FORMAT: VertexFormat;
+}
+
+macro_rules! vertex_attributes {
+    ($($ty:ty => $format:ident,)*) => {
+        $(impl VertexAttribute for $ty {
+            const FORMAT: VertexFormat = VertexFormat::$format;
+        })*
+    };
+}
+
+vertex_attributes! {
+    f32 => R32Float,
+    [f32; 1] => R32Float,
+    [f32; 2] => R32G32Float,
+    [f32; 3] => R32G32B32Float,
+    [f32; 4] => R32G32B32A32Float,
+    u32 => R32Uint,
+    [u32; 1] => R32Uint,
+    [u32; 2] => R32G32Uint,
+    [u32; 3] => R32G32B32Uint,
+    [u32; 4] => R32G32B32A32Uint,
+    i32 => R32Sint,
+    [i32; 1] => R32Sint,
+    [i32; 2] => R32G32Sint,
+    [i32; 3] => R32G32B32Sint,
+    [i32; 4] => R32G32B32A32Sint,
+    [u16; 2] => R16G16Unorm,
+    [u16; 4] => R16G16B16A16Unorm,
+    [i16; 2] => R16G16Snorm,
+    [i16; 4] => R16G16B16A16Snorm,
+    [u8; 2] => R8G8Unorm,
+    [u8; 4] => R8G8B8A8Unorm,
+    [i8; 2] => R8G8Snorm,
+    [i8; 4] => R8G8B8A8Snorm,
+}
+
+/// A semantic name and index, displayed the way HLSL writes them, e.g.
+/// `TEXCOORD1`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Semantic {
+    pub name: String,
+    pub index: u32,
+}
+
+impl Semantic {
+    fn of_element(element: &InputElement) -> Semantic {
+        Semantic {
+            name: element.semantic_name.to_string(),
+            index: element.semantic_index,
+        }
+    }
+
+    fn of_input(input: &SignatureElement) -> Semantic {
+        Semantic {
+            name: input.semantic_name.to_string(),
+            index: input.semantic_index,
+        }
+    }
+}
+
+impl fmt::Display for Semantic {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{}{}", self.name, self.index)
+    }
+}
+
+/// Describes why input elements do not match the input signature of a
+/// vertex shader.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputLayoutError {
+    /// The shader has an input no element provides.
+    MissingSemantic { semantic: Semantic },
+
+    /// Two elements have the same semantic.
+    DuplicateSemantic { semantic: Semantic },
+
+    /// The format is read as a different type than the input has, e.g. a
+    /// `Uint` format for a `float4` input.
+    ComponentType {
+        semantic: Semantic,
+        format: VertexFormat,
+        expected: ComponentType,
+    },
+
+    /// The offset of the element is not a multiple of
+    /// `VertexFormat::alignment`.
+    Misaligned {
+        semantic: Semantic,
+        offset: u32,
+        alignment: u32,
+    },
+
+    /// Two elements of the same input slot share bytes.
+    Overlap {
+        semantic: Semantic,
+        other: Semantic,
+        input_slot: u32,
+    },
+}
+
+impl fmt::Display for InputLayoutError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            InputLayoutError::MissingSemantic { ref semantic } => write!(
+                fmt,
+                "the vertex shader reads {}, but no element provides it",
+                semantic
+            ),
+            InputLayoutError::DuplicateSemantic { ref semantic } => {
+                write!(fmt, "more than one element has the semantic {}", semantic)
+            }
+            InputLayoutError::ComponentType {
+                ref semantic,
+                format,
+                expected,
+            } => write!(
+                fmt,
+                "{} is read as {:?}, but its format {:?} provides {:?}",
+                semantic,
+                expected,
+                format,
+                format.component_type()
+            ),
+            InputLayoutError::Misaligned {
+                ref semantic,
+                offset,
+                alignment,
+            } => write!(
+                fmt,
+                "{} is at offset {}, which is not a multiple of {}",
+                semantic, offset, alignment
+            ),
+            InputLayoutError::Overlap {
+                ref semantic,
+                ref other,
+                input_slot,
+            } => write!(
+                fmt,
+                "{} overlaps {} in input slot {}",
+                semantic, other, input_slot
+            ),
+        }
+    }
+}
+
+impl StdError for InputLayoutError {}
+
+/// Checks that `elements` match the input signature of a vertex shader:
+/// every input that is not a system value has exactly one element, of a
+/// format with components of the right type. Elements must also be aligned
+/// and must not overlap. Elements the shader has no input for are ignored,
+/// like they are by Direct3D, so a vertex type can be shared by shaders
+/// that only read some of its elements.
+///
+/// The format may have fewer components than the input, e.g. a
+/// `R32G32B32Float` position read as a `float4`. Direct3D fills in the
+/// missing components with 0, and the last with 1.
+///
+/// The first problem found is returned.
+pub fn validate_input_layout(
+    elements: &[InputElement],
+    signature: &Signature,
+) -> Result<(), InputLayoutError> {
+    for (i, element) in elements.iter().enumerate() {
+        let semantic = Semantic::of_element(element);
+        let same_semantic = |other: &InputElement| {
+            other.semantic_index == element.semantic_index
+                && other
+                    .semantic_name
+                    .eq_ignore_ascii_case(element.semantic_name)
+        };
+        if elements[..i].iter().any(same_semantic) {
+            return Err(InputLayoutError::DuplicateSemantic { semantic });
+        }
+
+        let format = element.format;
+        let input = signature
+            .find(element.semantic_name, element.semantic_index)
+            .filter(|input| input.system_value == SystemValue::Undefined);
+        if let Some(input) = input {
+            if input.component_type != ComponentType::Unknown
+                && format.component_type() != input.component_type
+            {
+                return Err(InputLayoutError::ComponentType {
+                    semantic,
+                    format,
+                    expected: input.component_type,
+                });
+            }
+        }
+        if element.offset % format.alignment() != 0 {
+            return Err(InputLayoutError::Misaligned {
+                semantic,
+                offset: element.offset,
+                alignment: format.alignment(),
+            });
+        }
+
+        let end = element.offset.saturating_add(format.size());
+        let overlap = elements[..i].iter().find(|other| {
+            other.input_slot == element.input_slot
+                && other.offset < end
+                && element.offset < other.offset.saturating_add(other.format.size())
+        });
+        if let Some(other) = overlap {
+            return Err(InputLayoutError::Overlap {
+                semantic,
+                other: Semantic::of_element(other),
+                input_slot: element.input_slot,
+            });
+        }
+    }
+
+    let missing = signature.elements.iter().find(|input| {
+        input.system_value == SystemValue::Undefined
+            && !elements
+                .iter()
+                .any(|e| input.is_semantic(e.semantic_name, e.semantic_index))
+    });
+    match missing {
+        Some(input) => Err(InputLayoutError::MissingSemantic {
+            semantic: Semantic::of_input(input),
+        }),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,121 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::enums::DsvFlags;
+use crate::error::ViewError;
+use crate::resource::IResource;
+use crate::view::{ViewDesc, ViewDimension, ViewKind};
+
+use std::mem;
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::{ID3D11DepthStencilView, ID3D11DeviceChild};
+use winapi::um::d3d11::{D3D11_DEPTH_STENCIL_VIEW_DESC, D3D11_TEX1D_ARRAY_DSV, D3D11_TEX1D_DSV};
+use winapi::um::d3d11::{D3D11_DSV_DIMENSION_TEXTURE1D, D3D11_DSV_DIMENSION_TEXTURE1DARRAY};
+use winapi::um::d3d11::{D3D11_DSV_DIMENSION_TEXTURE2D, D3D11_DSV_DIMENSION_TEXTURE2DARRAY};
+use winapi::um::d3d11::{D3D11_DSV_DIMENSION_TEXTURE2DMS, D3D11_DSV_DIMENSION_TEXTURE2DMSARRAY};
+use winapi::um::d3d11::{D3D11_TEX2DMS_ARRAY_DSV, D3D11_TEX2D_ARRAY_DSV, D3D11_TEX2D_DSV};
+use wio::com::ComPtr;
+
+/// A view of a texture for depth and stencil testing.
+#[derive(ComWrapper, Clone, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct DepthStencilView {
+    ptr: ComPtr<ID3D11DepthStencilView>,
+}
+
+impl DepthStencilView {
+    #[inline]
+    pub fn create<'a>(
+        device: &'a dyn IDevice,
+        resource: &'a dyn IResource,
+    ) -> DepthStencilViewBuilder<'a> {
+        DepthStencilViewBuilder::new(device, resource)
+    }
+}
+
+unsafe impl IDeviceChild for DepthStencilView {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+view_builder!(
+    DepthStencilViewBuilder,
+    ViewKind::DepthStencil,
+    flags: DsvFlags = DsvFlags::NONE
+);
+
+impl<'a> DepthStencilViewBuilder<'a> {
+    /// Makes the depth or stencil aspect read-only.
+    pub fn with_flags(mut self, flags: DsvFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    pub fn build(self) -> Result<DepthStencilView, ViewError> {
+        let desc = raw_desc(&self.desc()?, self.flags);
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateDepthStencilView(
+                self.resource.raw_res() as *const _ as *mut _,
+                &desc,
+                &mut ptr,
+            );
+            Ok(Error::map_if(hr, || DepthStencilView::from_raw(ptr))?)
+        }
+    }
+}
+
+fn raw_desc(desc: &ViewDesc, flags: DsvFlags) -> D3D11_DEPTH_STENCIL_VIEW_DESC {
+    unsafe {
+        let mut raw: D3D11_DEPTH_STENCIL_VIEW_DESC = mem::zeroed();
+        raw.Format = desc.format as u32;
+        raw.Flags = flags.0;
+        match desc.dimension {
+            ViewDimension::Texture1D { mips } => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE1D;
+                *raw.u.Texture1D_mut() = D3D11_TEX1D_DSV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture1DArray { mips, array } => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE1DARRAY;
+                *raw.u.Texture1DArray_mut() = D3D11_TEX1D_ARRAY_DSV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2D { mips } => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE2D;
+                *raw.u.Texture2D_mut() = D3D11_TEX2D_DSV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture2DArray { mips, array } => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE2DARRAY;
+                *raw.u.Texture2DArray_mut() = D3D11_TEX2D_ARRAY_DSV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2DMS => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE2DMS;
+            }
+            ViewDimension::Texture2DMSArray { array } => {
+                raw.ViewDimension = D3D11_DSV_DIMENSION_TEXTURE2DMSARRAY;
+                *raw.u.Texture2DMSArray_mut() = D3D11_TEX2DMS_ARRAY_DSV {
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            _ => unreachable!("validated view dimension"),
+        }
+        raw
+    }
+}
@@ -0,0 +1,404 @@
+use crate::enums::{BindFlags, ResourceMiscFlags};
+use crate::error::ViewError;
+use crate::format_info::{Format, FormatInfo};
+use crate::limits::{Feature, LimitError, Limits};
+use crate::subresource;
+use crate::validation::{ResourceDimension, ResourceInfo};
+
+/// The kinds of views that can be created of a resource.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewKind {
+    ShaderResource,
+    RenderTarget,
+    DepthStencil,
+    UnorderedAccess,
+}
+
+impl ViewKind {
+    /// The bind flag a resource needs to be viewed this way.
+    pub fn bind_flag(self) -> BindFlags {
+        match self {
+            ViewKind::ShaderResource => BindFlags::SHADER_RESOURCE,
+            ViewKind::RenderTarget => BindFlags::RENDER_TARGET,
+            ViewKind::DepthStencil => BindFlags::DEPTH_STENCIL,
+            ViewKind::UnorderedAccess => BindFlags::UNORDERED_ACCESS,
+        }
+    }
+
+    /// Whether this kind of view accesses a range of mip levels. Other views
+    /// access a single mip level.
+    pub fn has_mip_range(self) -> bool {
+        self == ViewKind::ShaderResource
+    }
+}
+
+/// A range of mip levels. Views other than shader resource views access a
+/// single mip level, so `levels` must be 1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MipRange {
+    pub most_detailed: u32,
+    pub levels: u32,
+}
+
+impl MipRange {
+    pub fn new(most_detailed: u32, levels: u32) -> Self {
+        MipRange {
+            most_detailed,
+            levels,
+        }
+    }
+
+    /// A single mip level.
+    pub fn single(mip: u32) -> Self {
+        MipRange::new(mip, 1)
+    }
+}
+
+/// A range of array slices, or of depth slices of a 3D texture.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArrayRange {
+    pub first: u32,
+    pub size: u32,
+}
+
+impl ArrayRange {
+    pub fn new(first: u32, size: u32) -> Self {
+        ArrayRange { first, size }
+    }
+}
+
+/// Which part of a resource a view accesses, and how.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewDimension {
+    Buffer {
+        first_element: u32,
+        num_elements: u32,
+    },
+    Texture1D {
+        mips: MipRange,
+    },
+    Texture1DArray {
+        mips: MipRange,
+        array: ArrayRange,
+    },
+    Texture2D {
+        mips: MipRange,
+    },
+    Texture2DArray {
+        mips: MipRange,
+        array: ArrayRange,
+    },
+    Texture2DMS,
+    Texture2DMSArray {
+        array: ArrayRange,
+    },
+    /// Shader resource views always access every depth slice, and ignore
+    /// `w`.
+    Texture3D {
+        mips: MipRange,
+        w: ArrayRange,
+    },
+    TextureCube {
+        mips: MipRange,
+    },
+    TextureCubeArray {
+        mips: MipRange,
+        first_face: u32,
+        num_cubes: u32,
+    },
+}
+
+impl ViewDimension {
+    /// The kind of resource this dimension views.
+    pub fn resource_dimension(&self) -> ResourceDimension {
+        match *self {
+            ViewDimension::Buffer { .. } => ResourceDimension::Buffer,
+            ViewDimension::Texture1D { .. } | ViewDimension::Texture1DArray { .. } => {
+                ResourceDimension::Texture1D
+            }
+            ViewDimension::Texture3D { .. } => ResourceDimension::Texture3D,
+            _ => ResourceDimension::Texture2D,
+        }
+    }
+
+    /// Whether views of `kind` can have this dimension.
+    pub fn is_supported_by(&self, kind: ViewKind) -> bool {
+        match (kind, *self) {
+            (ViewKind::ShaderResource, _) => true,
+            (_, ViewDimension::TextureCube { .. })
+            | (_, ViewDimension::TextureCubeArray { .. }) => false,
+            (ViewKind::DepthStencil, ViewDimension::Buffer { .. })
+            | (ViewKind::DepthStencil, ViewDimension::Texture3D { .. }) => false,
+            (ViewKind::UnorderedAccess, ViewDimension::Texture2DMS)
+            | (ViewKind::UnorderedAccess, ViewDimension::Texture2DMSArray { .. }) => false,
+            _ => true,
+        }
+    }
+
+    fn mips(&self) -> Option<MipRange> {
+        match *self {
+            ViewDimension::Texture1D { mips }
+            | ViewDimension::Texture1DArray { mips, .. }
+            | ViewDimension::Texture2D { mips }
+            | ViewDimension::Texture2DArray { mips, .. }
+            | ViewDimension::Texture3D { mips, .. }
+            | ViewDimension::TextureCube { mips }
+            | ViewDimension::TextureCubeArray { mips, .. } => Some(mips),
+            _ => None,
+        }
+    }
+
+    /// The array slices viewed, counting each face of a cube as a slice.
+    fn array(&self) -> Result<Option<ArrayRange>, ViewError> {
+        let array = match *self {
+            ViewDimension::Texture1D { .. }
+            | ViewDimension::Texture2D { .. }
+            | ViewDimension::Texture2DMS => ArrayRange::new(0, 1),
+            ViewDimension::Texture1DArray { array, .. }
+            | ViewDimension::Texture2DArray { array, .. }
+            | ViewDimension::Texture2DMSArray { array } => array,
+            ViewDimension::TextureCube { .. } => ArrayRange::new(0, 6),
+            ViewDimension::TextureCubeArray {
+                first_face,
+                num_cubes,
+                ..
+            } => {
+                let faces = num_cubes
+                    .checked_mul(6)
+                    .ok_or(ViewError::TooManyCubes { num_cubes })?;
+                ArrayRange::new(first_face, faces)
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(array))
+    }
+}
+
+/// Describes a view of a resource.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewDesc {
+    /// The format the view interprets the resource as. `Format::Unknown`
+    /// uses the format of the resource.
+    #[cfg_attr(feature = "serde", serde(with = "crate::enums::serde_impls::format"))]
+    pub format: Format,
+    pub dimension: ViewDimension,
+}
+
+impl ViewDesc {
+    /// The view of `kind` covering the whole resource, as created by passing
+    /// no description to Direct3D. 2D textures created with
+    /// `ResourceMiscFlags::TEXTURECUBE` are viewed as cubes by shader
+    /// resource views.
+    ///
+    /// `Format::Unknown` views the resource in its own format, which fails
+    /// if that format is typeless.
+    pub fn default_for(
+        kind: ViewKind,
+        format: Format,
+        resource: &ResourceInfo,
+    ) -> Result<ViewDesc, ViewError> {
+        let extent = &resource.extent;
+        let mips = if kind.has_mip_range() {
+            MipRange::new(0, extent.mip_count())
+        } else {
+            MipRange::single(0)
+        };
+        let array = ArrayRange::new(0, extent.array_size);
+        let is_array = extent.array_size > 1;
+
+        let dimension = match resource.dimension {
+            ResourceDimension::Buffer => ViewDimension::Buffer {
+                first_element: 0,
+                num_elements: extent.width / element_size(format, resource)?,
+            },
+            ResourceDimension::Texture1D if is_array => {
+                ViewDimension::Texture1DArray { mips, array }
+            }
+            ResourceDimension::Texture1D => ViewDimension::Texture1D { mips },
+            ResourceDimension::Texture2D if resource.sample_count > 1 => {
+                if is_array {
+                    ViewDimension::Texture2DMSArray { array }
+                } else {
+                    ViewDimension::Texture2DMS
+                }
+            }
+            ResourceDimension::Texture2D
+                if kind == ViewKind::ShaderResource
+                    && resource.misc_flags.is_set(ResourceMiscFlags::TEXTURECUBE) =>
+            {
+                if extent.array_size > 6 {
+                    ViewDimension::TextureCubeArray {
+                        mips,
+                        first_face: 0,
+                        num_cubes: extent.array_size / 6,
+                    }
+                } else {
+                    ViewDimension::TextureCube { mips }
+                }
+            }
+            ResourceDimension::Texture2D if is_array => {
+                ViewDimension::Texture2DArray { mips, array }
+            }
+            ResourceDimension::Texture2D => ViewDimension::Texture2D { mips },
+            ResourceDimension::Texture3D => ViewDimension::Texture3D {
+                mips,
+                w: ArrayRange::new(0, extent.depth),
+            },
+        };
+
+        let desc = ViewDesc { format, dimension };
+        desc.validate(kind, resource)?;
+        Ok(desc)
+    }
+
+    /// The format of the view, with `Format::Unknown` replaced by the
+    /// format of the resource.
+    pub fn resolved_format(&self, resource: &ResourceInfo) -> Format {
+        match self.format {
+            Format::Unknown => resource.format,
+            format => format,
+        }
+    }
+
+    /// Checks that a view of `kind` with this description can be created of
+    /// `resource`.
+    pub fn validate(&self, kind: ViewKind, resource: &ResourceInfo) -> Result<(), ViewError> {
+        if !resource.bind_flags.is_set(kind.bind_flag()) {
+            return Err(ViewError::MissingBindFlag {
+                kind,
+                bind_flags: resource.bind_flags,
+            });
+        }
+
+        let dimension = self.dimension;
+        if !dimension.is_supported_by(kind) {
+            return Err(ViewError::UnsupportedDimension { kind, dimension });
+        }
+        if dimension.resource_dimension() != resource.dimension {
+            return Err(ViewError::ResourceMismatch {
+                dimension,
+                resource: resource.dimension,
+            });
+        }
+        let multisampled_view = matches!(
+            dimension,
+            ViewDimension::Texture2DMS | ViewDimension::Texture2DMSArray { .. }
+        );
+        if multisampled_view != (resource.sample_count > 1) {
+            return Err(ViewError::SampleCount {
+                dimension,
+                sample_count: resource.sample_count,
+            });
+        }
+        let cube_view = matches!(
+            dimension,
+            ViewDimension::TextureCube { .. } | ViewDimension::TextureCubeArray { .. }
+        );
+        if cube_view && !resource.misc_flags.is_set(ResourceMiscFlags::TEXTURECUBE) {
+            return Err(ViewError::NotCube);
+        }
+
+        let format = self.resolved_format(resource);
+        if let ViewDimension::Buffer {
+            first_element,
+            num_elements,
+        } = dimension
+        {
+            let element_count = resource.extent.width / element_size(format, resource)?;
+            let end = first_element.checked_add(num_elements);
+            if num_elements == 0 || end.is_none_or(|end| end > element_count) {
+                return Err(ViewError::BufferRange {
+                    first_element,
+                    num_elements,
+                    element_count,
+                });
+            }
+            return Ok(());
+        }
+
+        self.validate_texture_format(kind, format, resource)?;
+
+        let mip_count = resource.extent.mip_count();
+        if let Some(mips) = dimension.mips() {
+            let levels_ok = kind.has_mip_range() || mips.levels == 1;
+            let end = mips.most_detailed.checked_add(mips.levels);
+            if !levels_ok || mips.levels == 0 || end.is_none_or(|end| end > mip_count) {
+                return Err(ViewError::MipRange { mips, mip_count });
+            }
+        }
+
+        if let Some(array) = dimension.array()? {
+            check_range(array, resource.extent.array_size)?;
+        }
+        if let ViewDimension::Texture3D { mips, w } = dimension {
+            if kind != ViewKind::ShaderResource {
+                let depth = subresource::mip_size(resource.extent.depth, mips.most_detailed);
+                check_range(w, depth)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks the view against the features of a feature level, see
+    /// `FeatureLevel::limits`.
+    pub fn validate_limits(&self, kind: ViewKind, limits: &Limits) -> Result<(), LimitError> {
+        if kind == ViewKind::UnorderedAccess
+            && self.dimension.resource_dimension() != ResourceDimension::Buffer
+        {
+            limits.require(Feature::UnorderedAccess)?;
+        }
+        if let ViewDimension::TextureCubeArray { .. } = self.dimension {
+            limits.require(Feature::CubeArrays)?;
+        }
+        Ok(())
+    }
+
+    fn validate_texture_format(
+        &self,
+        kind: ViewKind,
+        format: Format,
+        resource: &ResourceInfo,
+    ) -> Result<(), ViewError> {
+        let info = FormatInfo::of(format);
+        if info.is_typeless() || info.is_unknown() {
+            return Err(ViewError::TypelessFormat { format });
+        }
+        if !info.is_cast_compatible(FormatInfo::of(resource.format)) {
+            return Err(ViewError::IncompatibleFormat {
+                format,
+                resource_format: resource.format,
+            });
+        }
+        if info.is_depth() != (kind == ViewKind::DepthStencil) {
+            return Err(ViewError::FormatAspect { kind, format });
+        }
+        Ok(())
+    }
+}
+
+/// The size of the elements of a buffer viewed with `format`. Structured
+/// buffers are viewed without a format.
+fn element_size(format: Format, resource: &ResourceInfo) -> Result<u32, ViewError> {
+    let size = match format {
+        Format::Unknown => resource.structure_byte_stride,
+        format => FormatInfo::of(format).bytes_per_block,
+    };
+    match size {
+        0 => Err(ViewError::UnknownElementSize),
+        size => Ok(size),
+    }
+}
+
+fn check_range(range: ArrayRange, size: u32) -> Result<(), ViewError> {
+    let end = range.first.checked_add(range.size);
+    if range.size == 0 || end.is_none_or(|end| end > size) {
+        return Err(ViewError::ArrayRange { range, size });
+    }
+    Ok(())
+}
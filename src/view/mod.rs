@@ -0,0 +1,111 @@
+//! Views through which the pipeline accesses resources. The views are only
+//! available on Windows; their descriptions, and the checks done on them,
+//! on every platform.
+
+#[cfg(windows)]
+use crate::error::ViewError;
+#[cfg(windows)]
+use crate::limits::Limits;
+#[cfg(windows)]
+use crate::resource::ResourceInfo;
+
+#[cfg(windows)]
+use dxgi::enums::Format;
+#[cfg(windows)]
+use winapi::um::d3d11::ID3D11Resource;
+
+#[cfg(windows)]
+macro_rules! view_builder {
+    ($builder:ident, $kind:expr $(, $field:ident: $ty:ty = $default:expr)*) => {
+        pub struct $builder<'a> {
+            device: &'a dyn IDevice,
+            resource: &'a dyn IResource,
+            format: Format,
+            dimension: Option<ViewDimension>,
+            $($field: $ty,)*
+        }
+
+        impl<'a> $builder<'a> {
+            pub fn new(device: &'a dyn IDevice, resource: &'a dyn IResource) -> Self {
+                $builder {
+                    device,
+                    resource,
+                    format: Format::Unknown,
+                    dimension: None,
+                    $($field: $default,)*
+                }
+            }
+
+            /// The format the view interprets the resource as. Defaults to
+            /// the format of the resource, which must be set for resources
+            /// with a typeless format.
+            pub fn with_format(mut self, format: Format) -> Self {
+                self.format = format;
+                self
+            }
+
+            /// The part of the resource the view accesses. Defaults to the
+            /// whole resource.
+            pub fn with_dimension(mut self, dimension: ViewDimension) -> Self {
+                self.dimension = Some(dimension);
+                self
+            }
+
+            /// The description the view will be created with.
+            pub fn desc(&self) -> Result<ViewDesc, ViewError> {
+                unsafe {
+                    crate::view::resolve_desc(
+                        $kind,
+                        self.resource.raw_res(),
+                        self.format,
+                        self.dimension,
+                        &self.device.feature_level().limits(),
+                    )
+                }
+            }
+        }
+    };
+}
+
+#[cfg(windows)]
+pub use self::depth_stencil::{DepthStencilView, DepthStencilViewBuilder};
+pub use self::desc::{ArrayRange, MipRange, ViewDesc, ViewDimension, ViewKind};
+#[cfg(windows)]
+pub use self::render_target::{RenderTargetView, RenderTargetViewBuilder};
+#[cfg(windows)]
+pub use self::shader_resource::{ShaderResourceView, ShaderResourceViewBuilder};
+#[cfg(windows)]
+pub use self::unordered_access::{UnorderedAccessView, UnorderedAccessViewBuilder};
+
+#[cfg(windows)]
+pub mod depth_stencil;
+pub mod desc;
+#[cfg(windows)]
+pub mod render_target;
+#[cfg(windows)]
+pub mod shader_resource;
+#[cfg(windows)]
+pub mod unordered_access;
+
+/// Completes the description given to a view builder and checks it against
+/// the resource and the limits of the device.
+#[cfg(windows)]
+unsafe fn resolve_desc(
+    kind: ViewKind,
+    resource: &ID3D11Resource,
+    format: Format,
+    dimension: Option<ViewDimension>,
+    limits: &Limits,
+) -> Result<ViewDesc, ViewError> {
+    let info = ResourceInfo::of(resource)?;
+    let mut desc = match dimension {
+        Some(dimension) => ViewDesc { format, dimension },
+        None => ViewDesc::default_for(kind, format, &info)?,
+    };
+    desc.validate(kind, &info)?;
+    desc.validate_limits(kind, limits)?;
+    if !info.is_buffer() {
+        desc.format = desc.resolved_format(&info);
+    }
+    Ok(desc)
+}
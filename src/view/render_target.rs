@@ -0,0 +1,130 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::error::ViewError;
+use crate::resource::IResource;
+use crate::view::{ViewDesc, ViewDimension, ViewKind};
+
+use std::mem;
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::D3D11_RTV_DIMENSION_TEXTURE3D;
+use winapi::um::d3d11::{ID3D11DeviceChild, ID3D11RenderTargetView};
+use winapi::um::d3d11::{D3D11_RENDER_TARGET_VIEW_DESC, D3D11_TEX1D_ARRAY_RTV, D3D11_TEX1D_RTV};
+use winapi::um::d3d11::{D3D11_RTV_DIMENSION_BUFFER, D3D11_TEX3D_RTV};
+use winapi::um::d3d11::{D3D11_RTV_DIMENSION_TEXTURE1D, D3D11_RTV_DIMENSION_TEXTURE1DARRAY};
+use winapi::um::d3d11::{D3D11_RTV_DIMENSION_TEXTURE2D, D3D11_RTV_DIMENSION_TEXTURE2DARRAY};
+use winapi::um::d3d11::{D3D11_RTV_DIMENSION_TEXTURE2DMS, D3D11_RTV_DIMENSION_TEXTURE2DMSARRAY};
+use winapi::um::d3d11::{D3D11_TEX2DMS_ARRAY_RTV, D3D11_TEX2D_ARRAY_RTV, D3D11_TEX2D_RTV};
+use wio::com::ComPtr;
+
+/// A view of a resource for rendering to it.
+#[derive(ComWrapper, Clone, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct RenderTargetView {
+    ptr: ComPtr<ID3D11RenderTargetView>,
+}
+
+impl RenderTargetView {
+    #[inline]
+    pub fn create<'a>(
+        device: &'a dyn IDevice,
+        resource: &'a dyn IResource,
+    ) -> RenderTargetViewBuilder<'a> {
+        RenderTargetViewBuilder::new(device, resource)
+    }
+}
+
+unsafe impl IDeviceChild for RenderTargetView {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+view_builder!(RenderTargetViewBuilder, ViewKind::RenderTarget);
+
+impl<'a> RenderTargetViewBuilder<'a> {
+    pub fn build(self) -> Result<RenderTargetView, ViewError> {
+        let desc = raw_desc(&self.desc()?);
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateRenderTargetView(
+                self.resource.raw_res() as *const _ as *mut _,
+                &desc,
+                &mut ptr,
+            );
+            Ok(Error::map_if(hr, || RenderTargetView::from_raw(ptr))?)
+        }
+    }
+}
+
+fn raw_desc(desc: &ViewDesc) -> D3D11_RENDER_TARGET_VIEW_DESC {
+    unsafe {
+        let mut raw: D3D11_RENDER_TARGET_VIEW_DESC = mem::zeroed();
+        raw.Format = desc.format as u32;
+        match desc.dimension {
+            ViewDimension::Buffer {
+                first_element,
+                num_elements,
+            } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_BUFFER;
+                let buffer = raw.u.Buffer_mut();
+                *buffer.u1.FirstElement_mut() = first_element;
+                *buffer.u2.NumElements_mut() = num_elements;
+            }
+            ViewDimension::Texture1D { mips } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE1D;
+                *raw.u.Texture1D_mut() = D3D11_TEX1D_RTV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture1DArray { mips, array } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE1DARRAY;
+                *raw.u.Texture1DArray_mut() = D3D11_TEX1D_ARRAY_RTV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2D { mips } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE2D;
+                *raw.u.Texture2D_mut() = D3D11_TEX2D_RTV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture2DArray { mips, array } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE2DARRAY;
+                *raw.u.Texture2DArray_mut() = D3D11_TEX2D_ARRAY_RTV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2DMS => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE2DMS;
+            }
+            ViewDimension::Texture2DMSArray { array } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE2DMSARRAY;
+                *raw.u.Texture2DMSArray_mut() = D3D11_TEX2DMS_ARRAY_RTV {
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture3D { mips, w } => {
+                raw.ViewDimension = D3D11_RTV_DIMENSION_TEXTURE3D;
+                *raw.u.Texture3D_mut() = D3D11_TEX3D_RTV {
+                    MipSlice: mips.most_detailed,
+                    FirstWSlice: w.first,
+                    WSize: w.size,
+                };
+            }
+            ViewDimension::TextureCube { .. } | ViewDimension::TextureCubeArray { .. } => {
+                unreachable!("validated view dimension")
+            }
+        }
+        raw
+    }
+}
@@ -0,0 +1,153 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::error::ViewError;
+use crate::resource::IResource;
+use crate::view::{ViewDesc, ViewDimension, ViewKind};
+
+use std::mem;
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::{ID3D11DeviceChild, ID3D11ShaderResourceView};
+use winapi::um::d3d11::{D3D11_SHADER_RESOURCE_VIEW_DESC, D3D11_TEX1D_ARRAY_SRV, D3D11_TEX1D_SRV};
+use winapi::um::d3d11::{D3D11_TEX2DMS_ARRAY_SRV, D3D11_TEX2D_ARRAY_SRV, D3D11_TEX2D_SRV};
+use winapi::um::d3d11::{D3D11_TEX3D_SRV, D3D11_TEXCUBE_ARRAY_SRV, D3D11_TEXCUBE_SRV};
+use winapi::um::d3dcommon::{D3D11_SRV_DIMENSION_BUFFER, D3D11_SRV_DIMENSION_TEXTURE1D};
+use winapi::um::d3dcommon::{D3D11_SRV_DIMENSION_TEXTURE1DARRAY, D3D11_SRV_DIMENSION_TEXTURE2D};
+use winapi::um::d3dcommon::{D3D11_SRV_DIMENSION_TEXTURE2DARRAY, D3D11_SRV_DIMENSION_TEXTURE2DMS};
+use winapi::um::d3dcommon::{D3D11_SRV_DIMENSION_TEXTURE2DMSARRAY, D3D11_SRV_DIMENSION_TEXTURE3D};
+use winapi::um::d3dcommon::{
+    D3D11_SRV_DIMENSION_TEXTURECUBE, D3D11_SRV_DIMENSION_TEXTURECUBEARRAY,
+};
+use wio::com::ComPtr;
+
+/// A view of a resource for reading it from shaders.
+#[derive(ComWrapper, Clone, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct ShaderResourceView {
+    ptr: ComPtr<ID3D11ShaderResourceView>,
+}
+
+impl ShaderResourceView {
+    #[inline]
+    pub fn create<'a>(
+        device: &'a dyn IDevice,
+        resource: &'a dyn IResource,
+    ) -> ShaderResourceViewBuilder<'a> {
+        ShaderResourceViewBuilder::new(device, resource)
+    }
+}
+
+unsafe impl IDeviceChild for ShaderResourceView {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+view_builder!(ShaderResourceViewBuilder, ViewKind::ShaderResource);
+
+impl<'a> ShaderResourceViewBuilder<'a> {
+    pub fn build(self) -> Result<ShaderResourceView, ViewError> {
+        let desc = raw_desc(&self.desc()?);
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateShaderResourceView(
+                self.resource.raw_res() as *const _ as *mut _,
+                &desc,
+                &mut ptr,
+            );
+            Ok(Error::map_if(hr, || ShaderResourceView::from_raw(ptr))?)
+        }
+    }
+}
+
+fn raw_desc(desc: &ViewDesc) -> D3D11_SHADER_RESOURCE_VIEW_DESC {
+    unsafe {
+        let mut raw: D3D11_SHADER_RESOURCE_VIEW_DESC = mem::zeroed();
+        raw.Format = desc.format as u32;
+        match desc.dimension {
+            ViewDimension::Buffer {
+                first_element,
+                num_elements,
+            } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_BUFFER;
+                let buffer = raw.u.Buffer_mut();
+                *buffer.u1.FirstElement_mut() = first_element;
+                *buffer.u2.NumElements_mut() = num_elements;
+            }
+            ViewDimension::Texture1D { mips } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE1D;
+                *raw.u.Texture1D_mut() = D3D11_TEX1D_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                };
+            }
+            ViewDimension::Texture1DArray { mips, array } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE1DARRAY;
+                *raw.u.Texture1DArray_mut() = D3D11_TEX1D_ARRAY_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2D { mips } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE2D;
+                *raw.u.Texture2D_mut() = D3D11_TEX2D_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                };
+            }
+            ViewDimension::Texture2DArray { mips, array } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE2DARRAY;
+                *raw.u.Texture2DArray_mut() = D3D11_TEX2D_ARRAY_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2DMS => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE2DMS;
+            }
+            ViewDimension::Texture2DMSArray { array } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE2DMSARRAY;
+                *raw.u.Texture2DMSArray_mut() = D3D11_TEX2DMS_ARRAY_SRV {
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture3D { mips, .. } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURE3D;
+                *raw.u.Texture3D_mut() = D3D11_TEX3D_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                };
+            }
+            ViewDimension::TextureCube { mips } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURECUBE;
+                *raw.u.TextureCube_mut() = D3D11_TEXCUBE_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                };
+            }
+            ViewDimension::TextureCubeArray {
+                mips,
+                first_face,
+                num_cubes,
+            } => {
+                raw.ViewDimension = D3D11_SRV_DIMENSION_TEXTURECUBEARRAY;
+                *raw.u.TextureCubeArray_mut() = D3D11_TEXCUBE_ARRAY_SRV {
+                    MostDetailedMip: mips.most_detailed,
+                    MipLevels: mips.levels,
+                    First2DArrayFace: first_face,
+                    NumCubes: num_cubes,
+                };
+            }
+        }
+        raw
+    }
+}
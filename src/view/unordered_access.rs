@@ -0,0 +1,131 @@
+use crate::device::IDevice;
+use crate::device_child::IDeviceChild;
+use crate::enums::BufferUavFlags;
+use crate::error::ViewError;
+use crate::resource::IResource;
+use crate::view::{ViewDesc, ViewDimension, ViewKind};
+
+use std::mem;
+use std::ptr;
+
+use com_wrapper::ComWrapper;
+use dcommon::error::Error;
+use dxgi::enums::Format;
+use winapi::um::d3d11::D3D11_UAV_DIMENSION_TEXTURE3D;
+use winapi::um::d3d11::{ID3D11DeviceChild, ID3D11UnorderedAccessView};
+use winapi::um::d3d11::{D3D11_BUFFER_UAV, D3D11_UNORDERED_ACCESS_VIEW_DESC};
+use winapi::um::d3d11::{D3D11_TEX1D_ARRAY_UAV, D3D11_TEX1D_UAV, D3D11_TEX2D_ARRAY_UAV};
+use winapi::um::d3d11::{D3D11_TEX2D_UAV, D3D11_TEX3D_UAV, D3D11_UAV_DIMENSION_BUFFER};
+use winapi::um::d3d11::{D3D11_UAV_DIMENSION_TEXTURE1D, D3D11_UAV_DIMENSION_TEXTURE1DARRAY};
+use winapi::um::d3d11::{D3D11_UAV_DIMENSION_TEXTURE2D, D3D11_UAV_DIMENSION_TEXTURE2DARRAY};
+use wio::com::ComPtr;
+
+/// A view of a resource for unordered reads and writes from pixel and
+/// compute shaders.
+#[derive(ComWrapper, Clone, PartialEq)]
+#[com(send, sync, debug)]
+#[repr(transparent)]
+pub struct UnorderedAccessView {
+    ptr: ComPtr<ID3D11UnorderedAccessView>,
+}
+
+impl UnorderedAccessView {
+    #[inline]
+    pub fn create<'a>(
+        device: &'a dyn IDevice,
+        resource: &'a dyn IResource,
+    ) -> UnorderedAccessViewBuilder<'a> {
+        UnorderedAccessViewBuilder::new(device, resource)
+    }
+}
+
+unsafe impl IDeviceChild for UnorderedAccessView {
+    unsafe fn raw_device_child(&self) -> &ID3D11DeviceChild {
+        &self.ptr
+    }
+}
+
+view_builder!(
+    UnorderedAccessViewBuilder,
+    ViewKind::UnorderedAccess,
+    buffer_flags: BufferUavFlags = BufferUavFlags::NONE
+);
+
+impl<'a> UnorderedAccessViewBuilder<'a> {
+    /// Options for views of buffers. Ignored for textures.
+    pub fn with_buffer_flags(mut self, flags: BufferUavFlags) -> Self {
+        self.buffer_flags = flags;
+        self
+    }
+
+    pub fn build(self) -> Result<UnorderedAccessView, ViewError> {
+        let desc = raw_desc(&self.desc()?, self.buffer_flags);
+        unsafe {
+            let mut ptr = ptr::null_mut();
+            let hr = self.device.raw_dev().CreateUnorderedAccessView(
+                self.resource.raw_res() as *const _ as *mut _,
+                &desc,
+                &mut ptr,
+            );
+            Ok(Error::map_if(hr, || UnorderedAccessView::from_raw(ptr))?)
+        }
+    }
+}
+
+fn raw_desc(desc: &ViewDesc, buffer_flags: BufferUavFlags) -> D3D11_UNORDERED_ACCESS_VIEW_DESC {
+    unsafe {
+        let mut raw: D3D11_UNORDERED_ACCESS_VIEW_DESC = mem::zeroed();
+        raw.Format = desc.format as u32;
+        match desc.dimension {
+            ViewDimension::Buffer {
+                first_element,
+                num_elements,
+            } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_BUFFER;
+                *raw.u.Buffer_mut() = D3D11_BUFFER_UAV {
+                    FirstElement: first_element,
+                    NumElements: num_elements,
+                    Flags: buffer_flags.0,
+                };
+            }
+            ViewDimension::Texture1D { mips } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_TEXTURE1D;
+                *raw.u.Texture1D_mut() = D3D11_TEX1D_UAV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture1DArray { mips, array } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_TEXTURE1DARRAY;
+                *raw.u.Texture1DArray_mut() = D3D11_TEX1D_ARRAY_UAV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture2D { mips } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_TEXTURE2D;
+                *raw.u.Texture2D_mut() = D3D11_TEX2D_UAV {
+                    MipSlice: mips.most_detailed,
+                };
+            }
+            ViewDimension::Texture2DArray { mips, array } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_TEXTURE2DARRAY;
+                *raw.u.Texture2DArray_mut() = D3D11_TEX2D_ARRAY_UAV {
+                    MipSlice: mips.most_detailed,
+                    FirstArraySlice: array.first,
+                    ArraySize: array.size,
+                };
+            }
+            ViewDimension::Texture3D { mips, w } => {
+                raw.ViewDimension = D3D11_UAV_DIMENSION_TEXTURE3D;
+                *raw.u.Texture3D_mut() = D3D11_TEX3D_UAV {
+                    MipSlice: mips.most_detailed,
+                    FirstWSlice: w.first,
+                    WSize: w.size,
+                };
+            }
+            _ => unreachable!("validated view dimension"),
+        }
+        raw
+    }
+}
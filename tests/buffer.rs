@@ -0,0 +1,104 @@
+extern crate direct3d11;
+
+use direct3d11::buffer::BufferDesc;
+use direct3d11::enums::{BindFlags, ResourceMiscFlags};
+use direct3d11::error::DescError;
+
+#[test]
+fn constant_buffer_size() {
+    let desc = BufferDesc {
+        byte_width: 24,
+        bind_flags: BindFlags::CONSTANT_BUFFER,
+        ..Default::default()
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::ConstantBufferSize { byte_width: 24 })
+    );
+
+    let desc = BufferDesc {
+        byte_width: 32,
+        ..desc
+    };
+    assert_eq!(desc.validate(false), Ok(()));
+}
+
+#[test]
+fn constant_buffer_exclusive() {
+    let bind_flags = BindFlags::CONSTANT_BUFFER | BindFlags::SHADER_RESOURCE;
+    let desc = BufferDesc {
+        byte_width: 64,
+        bind_flags,
+        ..Default::default()
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::ConstantBufferBindFlags { bind_flags })
+    );
+}
+
+#[test]
+fn structured_buffer_stride() {
+    let desc = BufferDesc {
+        byte_width: 48,
+        bind_flags: BindFlags::SHADER_RESOURCE,
+        misc_flags: ResourceMiscFlags::BUFFER_STRUCTURED,
+        ..Default::default()
+    };
+    assert_eq!(desc.validate(false), Err(DescError::MissingStructureStride));
+
+    let desc = BufferDesc {
+        structure_byte_stride: 20,
+        ..desc
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::StructureStrideMismatch {
+            byte_width: 48,
+            stride: 20
+        })
+    );
+
+    let desc = BufferDesc {
+        structure_byte_stride: 12,
+        ..desc
+    };
+    assert_eq!(desc.validate(false), Ok(()));
+}
+
+#[test]
+fn zero_size() {
+    assert_eq!(BufferDesc::default().validate(false), Err(DescError::ZeroSize));
+}
+
+#[test]
+fn initial_data_size() {
+    let desc = BufferDesc {
+        byte_width: 16,
+        ..Default::default()
+    };
+    assert_eq!(
+        desc.validate_initial_data(12),
+        Err(DescError::InitialDataTooSmall {
+            expected: 16,
+            actual: 12
+        })
+    );
+    assert_eq!(desc.validate_initial_data(16), Ok(()));
+}
+
+#[cfg(windows)]
+#[test]
+fn create_vertex_buffer() {
+    use direct3d11::buffer::Buffer;
+    use direct3d11::device::Device;
+
+    let (_, device, _) = Device::create().build().unwrap();
+
+    let vertices: &[[f32; 3]] = &[[0.0, 0.5, 0.0], [0.5, -0.5, 0.0], [-0.5, -0.5, 0.0]];
+    let _buffer = Buffer::create(&device)
+        .with_bind_flags(BindFlags::VERTEX_BUFFER)
+        .with_initial_data(vertices)
+        .build()
+        .unwrap();
+}
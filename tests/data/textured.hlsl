@@ -0,0 +1,47 @@
+// The shaders the samples in this directory stand for: `vs_main` is
+// textured.vs.dxbc (vs_5_0) and `ps_main` is textured.ps.dxbc (ps_4_0).
+// The samples were not compiled from this file. They were assembled chunk
+// by chunk, following the layout fxc gives this source, which is why the
+// creator in their RDEF chunks is "direct3d11-rs sample assembler". fxc
+// output would differ in details such as the STAT chunk and the checksums.
+// Compiler output for this file comes from
+//
+//     fxc /nologo /T vs_5_0 /E vs_main /Fo textured.vs.dxbc textured.hlsl
+//     fxc /nologo /T ps_4_0 /E ps_main /Fo textured.ps.dxbc textured.hlsl
+
+cbuffer Transform : register(b0)
+{
+    float4x4 world_view_proj;
+    float4 tint;
+};
+
+Texture2D<float4> albedo : register(t0);
+SamplerState linear_sampler : register(s0);
+
+struct VSInput
+{
+    float3 position : POSITION;
+    float2 uv : TEXCOORD0;
+    float4 color : COLOR0;
+};
+
+struct VSOutput
+{
+    float4 position : SV_Position;
+    float2 uv : TEXCOORD0;
+    float4 color : COLOR0;
+};
+
+VSOutput vs_main(VSInput input)
+{
+    VSOutput output;
+    output.position = mul(float4(input.position, 1.0), world_view_proj);
+    output.uv = input.uv;
+    output.color = input.color * tint;
+    return output;
+}
+
+float4 ps_main(VSOutput input) : SV_Target
+{
+    return albedo.Sample(linear_sampler, input.uv) * input.color;
+}
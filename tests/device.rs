@@ -1,7 +1,11 @@
+#![cfg(windows)]
+
 extern crate direct3d11;
 
+use direct3d11::buffer::Buffer;
 use direct3d11::device::Device;
-use direct3d11::enums::{CreateDeviceFlags, DriverType, FeatureLevel};
+use direct3d11::enums::{BindFlags, CreateDeviceFlags, DriverType, FeatureLevel};
+use direct3d11::error::DeviceBuilderError;
 
 #[test]
 fn create_default() {
@@ -82,3 +86,103 @@ fn create_bgra() {
         .unwrap();
     assert!(feature_level == FeatureLevel::LEVEL_11_0);
 }
+
+#[test]
+fn config_defaults() {
+    let config = Device::create().config().unwrap();
+    assert_eq!(config.driver_type, DriverType::Hardware);
+    assert_eq!(config.flags, CreateDeviceFlags::NONE);
+    assert!(config.feature_levels.is_empty());
+}
+
+#[test]
+fn config_missing_software_module() {
+    match Device::create().with_driver_type(DriverType::Software).config() {
+        Err(DeviceBuilderError::MissingSoftwareModule) => (),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn config_software_module_driver_type() {
+    // The module is never loaded because the device is not created.
+    let module = std::ptr::dangling_mut();
+    let builder = unsafe { Device::create().with_software_module(module) };
+    assert_eq!(builder.config().unwrap().driver_type, DriverType::Software);
+
+    let builder = unsafe {
+        Device::create()
+            .with_driver_type(DriverType::Warp)
+            .with_software_module(module)
+    };
+    match builder.config() {
+        Err(DeviceBuilderError::ConflictingDriverType {
+            requested: DriverType::Warp,
+            required: DriverType::Software,
+        }) => (),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn config_unsupported_flags() {
+    let builder = Device::create()
+        .with_flags(CreateDeviceFlags::SWITCH_TO_REF | CreateDeviceFlags::DEBUG);
+    match builder.config() {
+        Err(DeviceBuilderError::UnsupportedFlags(flags)) => {
+            assert_eq!(flags, CreateDeviceFlags::SWITCH_TO_REF)
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn config_min_feature_level() {
+    let config = Device::create()
+        .with_min_feature_level(FeatureLevel::LEVEL_10_0)
+        .config()
+        .unwrap();
+    assert_eq!(
+        config.feature_levels,
+        [
+            FeatureLevel::LEVEL_11_0,
+            FeatureLevel::LEVEL_10_1,
+            FeatureLevel::LEVEL_10_0
+        ]
+    );
+
+    let builder = Device::create()
+        .with_feature_levels(&[FeatureLevel::LEVEL_9_3, FeatureLevel::LEVEL_9_1])
+        .with_min_feature_level(FeatureLevel::LEVEL_10_0);
+    match builder.config() {
+        Err(DeviceBuilderError::NoFeatureLevels) => (),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
+
+#[test]
+fn single_threaded_flag() {
+    let builder = Device::create().with_flags(CreateDeviceFlags::SINGLETHREADED);
+    assert_eq!(
+        builder.config().unwrap().flags,
+        CreateDeviceFlags::SINGLETHREADED
+    );
+    match builder.build() {
+        Err(DeviceBuilderError::SingleThreaded) => (),
+        Err(err) => panic!("unexpected error {:?}", err),
+        Ok(_) => panic!("build() created a single threaded device"),
+    }
+}
+
+#[test]
+fn create_single_threaded() {
+    let (_, dev, _ctx) = Device::create()
+        .with_driver_type(DriverType::Warp)
+        .build_single_threaded()
+        .unwrap();
+    let buffer = Buffer::create(&dev)
+        .with_bind_flags(BindFlags::CONSTANT_BUFFER)
+        .with_size(16)
+        .build();
+    assert!(buffer.is_ok());
+}
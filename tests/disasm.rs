@@ -0,0 +1,197 @@
+extern crate direct3d11;
+
+use direct3d11::dxbc::{disassemble, Container, DxbcError, Program, ProgramType, ShaderVersion};
+
+use std::process::Command;
+
+static PS: &[u8] = include_bytes!("data/textured.ps.dxbc");
+
+fn op(code: u32, controls: u32, operands: &[u32]) -> Vec<u32> {
+    let mut tokens = vec![code | controls << 11 | (operands.len() as u32 + 1) << 24];
+    tokens.extend_from_slice(operands);
+    tokens
+}
+
+/// A register of the given type with a one dimensional immediate index.
+fn register(ty: u32, components: u32) -> u32 {
+    components | ty << 12 | 1 << 20
+}
+
+fn mask(ty: u32, mask: u32) -> u32 {
+    register(ty, 2 | mask << 4)
+}
+
+fn swizzle(ty: u32, [x, y, z, w]: [u32; 4]) -> u32 {
+    register(ty, 2 | 1 << 2 | (x | y << 2 | z << 4 | w << 6) << 4)
+}
+
+fn select(ty: u32, component: u32) -> u32 {
+    register(ty, 2 | 2 << 2 | component << 4)
+}
+
+const TEMP: u32 = 0;
+const IMMEDIATE: u32 = 1 | 4 << 12;
+const XYZW: [u32; 4] = [0, 1, 2, 3];
+
+fn compute_shader(instructions: &[Vec<u32>]) -> Program {
+    Program {
+        version: ShaderVersion {
+            program_type: ProgramType::Compute,
+            major: 5,
+            minor: 0,
+        },
+        tokens: instructions.concat(),
+    }
+}
+
+#[test]
+fn declarations() {
+    let program = compute_shader(&[
+        op(106, 0b1001, &[]),
+        op(156, 3, &[register(30, 0), 0, 0x5555]),
+        op(158, 0, &[register(30, 0), 1, 16]),
+        op(90, 1, &[register(6, 0), 0]),
+        op(159, 0, &[register(31, 0), 0, 1024]),
+        op(155, 0, &[8, 8, 1]),
+        op(104, 0, &[2]),
+        op(105, 0, &[0, 4, 4]),
+    ]);
+    assert_eq!(
+        disassemble(&program).unwrap(),
+        "cs_5_0\n\
+         dcl_globalFlags refactoringAllowed | enableRawAndStructuredBuffers\n\
+         dcl_uav_typed_texture2d (float,float,float,float) u0\n\
+         dcl_uav_structured u1, 16\n\
+         dcl_sampler s0, mode_comparison\n\
+         dcl_tgsm_raw g0, 1024\n\
+         dcl_thread_group 8, 8, 1\n\
+         dcl_temps 2\n\
+         dcl_indexableTemp x0[4], 4\n"
+    );
+}
+
+#[test]
+fn instructions() {
+    // x0[r0.x + 1]: an indexable temp with a relative second index.
+    let relative = 2 | 1 << 2 | 0b0100 << 4 | 3 << 12 | 2 << 20 | 3 << 25;
+    // Sample offsets of (-1, 0, 1) in an extended opcode token.
+    let offsets = 1 | 0xF << 9 | 1 << 17;
+    let mut sample = op(
+        69,
+        0,
+        &[
+            offsets,
+            mask(TEMP, 0xF),
+            0,
+            swizzle(TEMP, [0, 1, 0, 0]),
+            1,
+            swizzle(7, XYZW),
+            0,
+            register(6, 0),
+            0,
+        ],
+    );
+    sample[0] |= 0x8000_0000;
+    // vThreadID has no index.
+    let thread_id = swizzle(32, [0, 1, 1, 1]) & !(3 << 20);
+
+    let program = compute_shader(&[
+        op(
+            54,
+            4,
+            &[
+                mask(TEMP, 1),
+                0,
+                select(TEMP, 1) | 0x8000_0000,
+                3 << 6 | 1,
+                1,
+            ],
+        ),
+        op(
+            30,
+            0,
+            &[mask(TEMP, 2), 0, select(TEMP, 0), 0, IMMEDIATE, u32::MAX],
+        ),
+        op(
+            1,
+            0,
+            &[mask(TEMP, 4), 0, select(TEMP, 0), 0, IMMEDIATE, 0x7FFF_FFFF],
+        ),
+        op(31, 0x80, &[select(TEMP, 0), 0]),
+        op(
+            54,
+            0,
+            &[mask(TEMP, 3), 1, relative, 0, 1, select(TEMP, 0), 0],
+        ),
+        op(18, 0, &[]),
+        op(54, 0, &[mask(TEMP, 1), 1, IMMEDIATE, 0]),
+        op(21, 0, &[]),
+        sample,
+        op(
+            164,
+            0,
+            &[mask(30, 0xF), 0, thread_id, swizzle(TEMP, XYZW), 0],
+        ),
+        op(190, 3, &[]),
+        op(62, 0, &[]),
+    ]);
+    assert_eq!(
+        disassemble(&program).unwrap(),
+        "cs_5_0\n\
+         mov_sat r0.x, -|r1.y|\n\
+         iadd r0.y, r0.x, l(-1)\n\
+         and r0.z, r0.x, l(0x7fffffff)\n\
+         if_nz r0.x\n  \
+           mov r1.xy, x0[r0.x + 1].xyxx\n\
+         else\n  \
+           mov r1.x, l(0)\n\
+         endif\n\
+         sample_aoffimmi(-1,0,1) r0.xyzw, r1.xyxx, t0.xyzw, s0\n\
+         store_uav_typed u0.xyzw, vThreadID.xyyy, r0.xyzw\n\
+         sync_g_t\n\
+         ret\n"
+    );
+}
+
+#[test]
+fn invalid_programs() {
+    let unknown = compute_shader(&[op(62, 0, &[]), op(250, 0, &[])]);
+    assert_eq!(
+        disassemble(&unknown),
+        Err(DxbcError::InvalidInstruction {
+            offset: 1,
+            reason: "unknown opcode"
+        })
+    );
+
+    // An immediate missing its value.
+    let truncated = compute_shader(&[op(54, 0, &[mask(TEMP, 1), 0, IMMEDIATE])]);
+    assert!(disassemble(&truncated).is_err());
+
+    let zero_length = compute_shader(&[vec![62]]);
+    assert!(disassemble(&zero_length).is_err());
+}
+
+#[test]
+fn dxbc_dis() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/textured.ps.dxbc");
+    let output = Command::new(env!("CARGO_BIN_EXE_dxbc-dis"))
+        .arg(path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let ps = Container::parse(PS).unwrap().program().unwrap().unwrap();
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        disassemble(&ps).unwrap()
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dxbc-dis"))
+        .arg(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/data/textured.hlsl"
+        ))
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+}
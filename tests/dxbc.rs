@@ -0,0 +1,300 @@
+extern crate direct3d11;
+
+use direct3d11::dxbc::{
+    checksum, strip, ComponentType, Container, ContainerWriter, DxbcError, FourCC, ProgramType,
+    ShaderFeatures, ShaderVersion, StripFlags, SystemValue,
+};
+
+static VS: &[u8] = include_bytes!("data/textured.vs.dxbc");
+static PS: &[u8] = include_bytes!("data/textured.ps.dxbc");
+
+#[test]
+fn chunks() {
+    let vs = Container::parse(VS).unwrap();
+    let fourccs: Vec<_> = vs.chunks().iter().map(|chunk| chunk.fourcc).collect();
+    assert_eq!(
+        fourccs,
+        [
+            FourCC::RDEF,
+            FourCC::ISGN,
+            FourCC::OSGN,
+            FourCC::SHEX,
+            FourCC::STAT
+        ]
+    );
+    assert_eq!(vs.data().len(), VS.len());
+    assert!(vs.chunk(FourCC::SDBG).is_none());
+    assert_eq!(vs.features(), Ok(ShaderFeatures::NONE));
+    assert_eq!(FourCC::SHEX.to_string(), "SHEX");
+
+    let ps = Container::parse(PS).unwrap();
+    assert!(ps.chunk(FourCC::SHDR).is_some());
+    assert!(ps.chunk(FourCC::SHEX).is_none());
+}
+
+#[test]
+fn signatures() {
+    let vs = Container::parse(VS).unwrap();
+    let input = vs.input_signature().unwrap().unwrap();
+    assert_eq!(input.elements.len(), 3);
+    let position = input.find("position", 0).unwrap();
+    assert_eq!(position.semantic_name, "POSITION");
+    assert_eq!(position.component_type, ComponentType::Float32);
+    assert_eq!(position.register, 0);
+    assert_eq!(position.mask, 0b0111);
+    assert_eq!(position.component_count(), 3);
+    assert_eq!(input.find("TEXCOORD", 0).unwrap().register, 1);
+    assert!(input.find("TEXCOORD", 1).is_none());
+
+    let output = vs.output_signature().unwrap().unwrap();
+    assert_eq!(output.elements[0].semantic_name, "SV_Position");
+    assert_eq!(output.elements[0].system_value, SystemValue::Position);
+    assert!(vs.patch_constant_signature().unwrap().is_none());
+
+    let ps = Container::parse(PS).unwrap();
+    let output = ps.output_signature().unwrap().unwrap();
+    assert_eq!(output.elements.len(), 1);
+    assert_eq!(output.elements[0].semantic_name, "SV_Target");
+}
+
+#[test]
+fn resource_definitions() {
+    let vs = Container::parse(VS).unwrap();
+    let rdef = vs.resource_definitions().unwrap().unwrap();
+    assert_eq!(rdef.version.to_string(), "vs_5_0");
+    assert_eq!(rdef.bindings.len(), 1);
+    let transform = rdef.constant_buffer("Transform").unwrap();
+    assert_eq!(transform.size, 80);
+    let matrix = transform.variable("world_view_proj").unwrap();
+    assert_eq!((matrix.offset, matrix.size), (0, 64));
+    assert_eq!((matrix.ty.rows, matrix.ty.columns), (4, 4));
+    assert_eq!(matrix.ty.name, Some("float4x4"));
+    assert_eq!(matrix.start_texture, u32::MAX);
+    let tint = transform.variable("tint").unwrap();
+    assert_eq!((tint.offset, tint.size), (64, 16));
+    assert_eq!(tint.default_value, None);
+
+    let ps = Container::parse(PS).unwrap();
+    let rdef = ps.resource_definitions().unwrap().unwrap();
+    assert_eq!(rdef.version.program_type, ProgramType::Pixel);
+    assert!(rdef.constant_buffers.is_empty());
+    let albedo = rdef.binding("albedo").unwrap();
+    assert_eq!((albedo.bind_point, albedo.bind_count), (0, 1));
+    assert_eq!(rdef.binding("linear_sampler").unwrap().input_type, 3);
+}
+
+#[test]
+fn program_and_statistics() {
+    let vs = Container::parse(VS).unwrap();
+    let program = vs.program().unwrap().unwrap();
+    assert_eq!(
+        program.version,
+        ShaderVersion {
+            program_type: ProgramType::Vertex,
+            major: 5,
+            minor: 0,
+        }
+    );
+    // The last instruction is a `ret`.
+    assert_eq!(program.tokens.last(), Some(&0x0100_003E));
+    let stats = vs.statistics().unwrap().unwrap();
+    assert_eq!(stats.instruction_count, 9);
+    assert_eq!(stats.temp_register_count, 1);
+
+    let ps = Container::parse(PS).unwrap();
+    assert_eq!(ps.program().unwrap().unwrap().version.to_string(), "ps_4_0");
+}
+
+#[test]
+fn checksums() {
+    for &data in &[VS, PS] {
+        let container = Container::parse(data).unwrap();
+        assert_eq!(checksum(data), container.checksum());
+        assert_eq!(container.verify_checksum(), Ok(()));
+    }
+
+    // The checksum covers the header after itself.
+    let mut data = VS.to_vec();
+    data[31] ^= 0x80;
+    assert_ne!(checksum(&data), checksum(VS));
+
+    let mut data = PS.to_vec();
+    let last = data.len() - 1;
+    data[last] ^= 1;
+    let container = Container::parse(&data).unwrap();
+    match container.verify_checksum() {
+        Err(DxbcError::ChecksumMismatch { stored, computed }) => {
+            assert_eq!(stored, container.checksum());
+            assert_eq!(computed, checksum(&data));
+        }
+        result => panic!("unexpected {:?}", result),
+    }
+
+    // Both forms of the final block: a leftover of 56 bytes or more needs
+    // an extra one.
+    for &len in &[21, 75, 76, 84, 85, 147] {
+        let data = vec![0x5A; len];
+        assert_ne!(checksum(&data), checksum(&data[..len - 1]));
+    }
+}
+
+#[test]
+fn rewrite_containers() {
+    // An unmodified container is written back byte for byte.
+    let container = Container::parse(VS).unwrap();
+    assert_eq!(ContainerWriter::from_container(&container).write(), VS);
+
+    let mut writer = ContainerWriter::from_container(&container);
+    assert!(writer.remove(FourCC::STAT));
+    assert!(!writer.remove(FourCC::SDBG));
+    writer
+        .replace(FourCC::ISGN, &b"odd"[..])
+        .push(FourCC::PRIV, vec![1, 2, 3, 4, 5]);
+    assert_eq!(
+        writer.fourccs(),
+        [
+            FourCC::RDEF,
+            FourCC::ISGN,
+            FourCC::OSGN,
+            FourCC::SHEX,
+            FourCC::PRIV
+        ]
+    );
+
+    let data = writer.write();
+    let rewritten = Container::parse(&data).unwrap();
+    assert_eq!(rewritten.verify_checksum(), Ok(()));
+    assert_eq!(rewritten.chunk(FourCC::ISGN).unwrap().data, b"odd");
+    assert_eq!(rewritten.chunk(FourCC::PRIV).unwrap().data, [1, 2, 3, 4, 5]);
+    assert_eq!(rewritten.chunk(FourCC::SHEX), container.chunk(FourCC::SHEX));
+    // Chunks stay aligned after odd sizes.
+    for chunk in rewritten.chunks() {
+        assert_eq!(
+            (chunk.data.as_ptr() as usize - data.as_ptr() as usize) % 4,
+            0
+        );
+    }
+
+    let mut writer = ContainerWriter::new();
+    writer
+        .push(FourCC::PRIV, &b"a"[..])
+        .push(FourCC::PRIV, &b"b"[..])
+        .replace(FourCC::PRIV, &b"c"[..]);
+    let data = writer.write();
+    let container = Container::parse(&data).unwrap();
+    assert_eq!(container.chunks().len(), 1);
+    assert_eq!(container.chunks()[0].data, b"c");
+}
+
+#[test]
+fn strip_chunks() {
+    let stripped = strip(PS, StripFlags::REFLECTION_DATA | StripFlags::DEBUG_INFO).unwrap();
+    let container = Container::parse(&stripped).unwrap();
+    assert_eq!(container.verify_checksum(), Ok(()));
+    let fourccs: Vec<_> = container
+        .chunks()
+        .iter()
+        .map(|chunk| chunk.fourcc)
+        .collect();
+    assert_eq!(fourccs, [FourCC::ISGN, FourCC::OSGN, FourCC::SHDR]);
+    assert_eq!(strip(PS, StripFlags::NONE).unwrap(), PS);
+
+    let mut corrupted = PS.to_vec();
+    let last = corrupted.len() - 1;
+    corrupted[last] ^= 1;
+    match strip(&corrupted, StripFlags::DEBUG_INFO) {
+        Err(DxbcError::ChecksumMismatch { .. }) => (),
+        result => panic!("unexpected {:?}", result),
+    }
+}
+
+#[test]
+fn malformed_containers() {
+    assert_eq!(
+        Container::parse(&VS[..16]),
+        Err(DxbcError::UnexpectedEnd {
+            chunk: None,
+            offset: 4
+        })
+    );
+
+    let mut data = VS.to_vec();
+    data[0] = b'X';
+    assert_eq!(Container::parse(&data), Err(DxbcError::BadMagic));
+
+    assert_eq!(
+        Container::parse(&VS[..VS.len() - 4]),
+        Err(DxbcError::SizeMismatch {
+            header: VS.len() as u32,
+            actual: VS.len() - 4
+        })
+    );
+
+    let mut data = VS.to_vec();
+    data[20] = 2;
+    assert_eq!(
+        Container::parse(&data),
+        Err(DxbcError::UnsupportedVersion(2))
+    );
+
+    // Point the second chunk past the end of the container.
+    let mut data = VS.to_vec();
+    data[36..40].copy_from_slice(&(VS.len() as u32 - 4).to_le_bytes());
+    assert_eq!(
+        Container::parse(&data),
+        Err(DxbcError::ChunkOutOfBounds {
+            index: 1,
+            offset: VS.len() as u32 - 4
+        })
+    );
+
+    // Claim more chunks than there are offsets.
+    let mut data = VS.to_vec();
+    data[28..32].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert!(Container::parse(&data).is_err());
+}
+
+#[test]
+fn malformed_chunks() {
+    let container = Container::parse(VS).unwrap();
+    let isgn = container.chunk(FourCC::ISGN).unwrap().data;
+    let isgn_offset = isgn.as_ptr() as usize - VS.as_ptr() as usize;
+
+    // An element count running past the end of the chunk.
+    let mut data = VS.to_vec();
+    data[isgn_offset] = 200;
+    let container = Container::parse(&data).unwrap();
+    match container.input_signature() {
+        Err(DxbcError::UnexpectedEnd { chunk, .. }) => assert_eq!(chunk, Some(FourCC::ISGN)),
+        result => panic!("unexpected {:?}", result),
+    }
+
+    // An unknown component type in the first element.
+    let mut data = VS.to_vec();
+    data[isgn_offset + 8 + 12] = 9;
+    let container = Container::parse(&data).unwrap();
+    assert_eq!(
+        container.input_signature(),
+        Err(DxbcError::InvalidValue {
+            chunk: Some(FourCC::ISGN),
+            field: "component type",
+            value: 9
+        })
+    );
+
+    // A semantic name without a terminator.
+    let mut data = VS.to_vec();
+    let name = isgn.len() - 8;
+    data[isgn_offset + 8..isgn_offset + 12].copy_from_slice(&(name as u32).to_le_bytes());
+    for byte in &mut data[isgn_offset + name..isgn_offset + isgn.len()] {
+        *byte = b'A';
+    }
+    let container = Container::parse(&data).unwrap();
+    assert_eq!(
+        container.input_signature(),
+        Err(DxbcError::InvalidString {
+            chunk: Some(FourCC::ISGN),
+            offset: name
+        })
+    );
+}
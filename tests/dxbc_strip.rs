@@ -0,0 +1,51 @@
+use std::env;
+use std::fs;
+use std::process::Command;
+
+static VS: &[u8] = include_bytes!("data/textured.vs.dxbc");
+
+fn strip(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_dxbc-strip"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn strip_files() {
+    let dir = env::temp_dir().join(format!("dxbc-strip-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("textured.vs.dxbc");
+    let output = dir.join("stripped.dxbc");
+    fs::write(&input, VS).unwrap();
+
+    let status = strip(&[
+        "--chunk",
+        "STAT",
+        input.to_str().unwrap(),
+        output.to_str().unwrap(),
+    ]);
+    assert!(status.status.success());
+    let stripped = fs::read(&output).unwrap();
+    assert_eq!(stripped.len(), VS.len() - 8 - 37 * 4 - 4);
+    assert_eq!(fs::read(&input).unwrap(), VS);
+
+    // Without options and an output, the reflection data goes and the
+    // input is overwritten.
+    assert!(strip(&[input.to_str().unwrap()]).status.success());
+    let stripped = fs::read(&input).unwrap();
+    assert!(stripped.len() < VS.len());
+    assert!(!stripped.windows(4).any(|window| window == b"RDEF"));
+
+    // Corrupted inputs are refused rather than given a valid checksum.
+    let mut corrupted = VS.to_vec();
+    corrupted[VS.len() - 1] ^= 1;
+    fs::write(&input, corrupted).unwrap();
+    let result = strip(&[input.to_str().unwrap()]);
+    assert_eq!(result.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&result.stderr).contains("checksum"));
+
+    assert_eq!(strip(&["--bogus"]).status.code(), Some(2));
+    assert_eq!(strip(&["--chunk", "TOOLONG", "x"]).status.code(), Some(2));
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,97 @@
+extern crate direct3d11;
+
+use direct3d11::enums::{BindFlags, CpuAccessFlags, CreateDeviceFlags, DriverType};
+use direct3d11::enums::{FeatureLevel, Map, ParseEnumError, ResourceMiscFlags, Usage};
+use direct3d11::state::ColorWriteMask;
+
+#[test]
+fn feature_levels() {
+    assert_eq!(FeatureLevel::LEVEL_11_0.to_string(), "11_0");
+    assert_eq!(FeatureLevel::LEVEL_9_3.to_string(), "9_3");
+    assert_eq!("11_0".parse(), Ok(FeatureLevel::LEVEL_11_0));
+    assert_eq!("11.1".parse(), Ok(FeatureLevel::LEVEL_11_1));
+    assert_eq!("12_1".parse(), Ok(FeatureLevel::LEVEL_12_1));
+
+    for invalid in &["11", "11_3", "11-0", " 11_0", "LEVEL_11_0", ""] {
+        assert_eq!(
+            invalid.parse::<FeatureLevel>(),
+            Err(ParseEnumError {
+                type_name: "FeatureLevel",
+                value: invalid.to_string(),
+            })
+        );
+    }
+}
+
+#[test]
+fn enums() {
+    assert_eq!(DriverType::Warp.to_string(), "Warp");
+    assert_eq!(Usage::Immutable.to_string(), "Immutable");
+    assert_eq!(Map::WriteNoOverwrite.to_string(), "WriteNoOverwrite");
+
+    assert_eq!("warp".parse(), Ok(DriverType::Warp));
+    assert_eq!("Hardware".parse(), Ok(DriverType::Hardware));
+    assert_eq!("STAGING".parse(), Ok(Usage::Staging));
+    assert!("write_no_overwrite".parse::<Map>().is_err());
+
+    let err = "gpu".parse::<DriverType>().unwrap_err();
+    assert_eq!(err.to_string(), "`gpu` is not a valid DriverType");
+    assert!(" warp".parse::<DriverType>().is_err());
+}
+
+#[test]
+fn flags_display() {
+    assert_eq!(
+        (BindFlags::RENDER_TARGET | BindFlags::SHADER_RESOURCE).to_string(),
+        "SHADER_RESOURCE | RENDER_TARGET"
+    );
+    assert_eq!(BindFlags::NONE.to_string(), "NONE");
+    assert_eq!(CpuAccessFlags::READWRITE.to_string(), "WRITE | READ");
+    assert_eq!(CreateDeviceFlags::DEBUG.to_string(), "DEBUG");
+    assert_eq!(
+        BindFlags(BindFlags::DECODER.0 | 0x8000_0000).to_string(),
+        "DECODER | 0x80000000"
+    );
+    assert_eq!(
+        ColorWriteMask::ALL.to_string(),
+        "RED | GREEN | BLUE | ALPHA"
+    );
+}
+
+#[test]
+fn flags_parse() {
+    assert_eq!(
+        "RENDER_TARGET | SHADER_RESOURCE".parse(),
+        Ok(BindFlags::RENDER_TARGET | BindFlags::SHADER_RESOURCE)
+    );
+    assert_eq!(
+        "render_target|shader_resource".parse(),
+        Ok(BindFlags::RENDER_TARGET | BindFlags::SHADER_RESOURCE)
+    );
+    assert_eq!("NONE".parse(), Ok(BindFlags::NONE));
+    assert_eq!("READWRITE".parse(), Ok(CpuAccessFlags::READWRITE));
+    assert_eq!(
+        "TEXTURECUBE | GENERATE_MIPS".parse(),
+        Ok(ResourceMiscFlags::TEXTURECUBE | ResourceMiscFlags::GENERATE_MIPS)
+    );
+
+    // Every flag set displays as something it parses back from.
+    let flags = CreateDeviceFlags::DEBUG | CreateDeviceFlags::BGRA_SUPPORT;
+    assert_eq!(flags.to_string().parse(), Ok(flags));
+
+    let err = |value: &str| ParseEnumError {
+        type_name: "BindFlags",
+        value: value.to_string(),
+    };
+    assert_eq!(
+        "RENDER_TARGETS".parse::<BindFlags>(),
+        Err(err("RENDER_TARGETS"))
+    );
+    assert_eq!("RENDER_TARGET |".parse::<BindFlags>(), Err(err("")));
+    assert_eq!("".parse::<BindFlags>(), Err(err("")));
+    assert_eq!(
+        "RENDER_TARGET, DEPTH_STENCIL".parse::<BindFlags>(),
+        Err(err("RENDER_TARGET, DEPTH_STENCIL"))
+    );
+    assert_eq!("0x20".parse::<BindFlags>(), Err(err("0x20")));
+}
@@ -0,0 +1,190 @@
+extern crate direct3d11;
+
+use std::collections::HashSet;
+
+use direct3d11::error::DescError;
+use direct3d11::format_info::{Aspect, BlockCompression, Format, FormatInfo};
+use direct3d11::initial_data::{self, SubresourceData, TextureExtent};
+
+#[test]
+fn table_is_indexed_by_value() {
+    let all = FormatInfo::all();
+    assert_eq!(all.len(), 119);
+
+    for info in all {
+        assert_eq!(FormatInfo::from_raw(info.format), Some(info));
+    }
+    for raw in (116..130).chain(133..200) {
+        assert_eq!(FormatInfo::from_raw(raw), None);
+    }
+
+    let names: HashSet<_> = all.iter().map(|info| info.name).collect();
+    assert_eq!(names.len(), all.len());
+}
+
+#[test]
+fn block_sizes_match_bits_per_pixel() {
+    for info in FormatInfo::all().iter().filter(|info| !info.is_planar()) {
+        assert_eq!(
+            info.bits_per_pixel * info.block_width * info.block_height,
+            info.bytes_per_block * 8,
+            "{}",
+            info.name
+        );
+    }
+}
+
+#[test]
+fn typeless_families() {
+    for info in FormatInfo::all() {
+        if let Some(typeless) = info.raw_typeless() {
+            let family = FormatInfo::from_raw(typeless).unwrap();
+            assert!(family.is_typeless(), "{}", info.name);
+            assert_eq!(family.bits_per_pixel, info.bits_per_pixel, "{}", info.name);
+            assert!(info.is_cast_compatible(family));
+        }
+        let typeless_name = info.name.ends_with("_TYPELESS") && !info.name.contains("_X");
+        assert_eq!(info.is_typeless(), typeless_name, "{}", info.name);
+    }
+
+    let rgba = FormatInfo::of(Format::R8G8B8A8Unorm);
+    let bgra = FormatInfo::of(Format::B8G8R8A8UnormSrgb);
+    assert!(rgba.is_cast_compatible(FormatInfo::of(Format::R8G8B8A8UnormSrgb)));
+    assert!(!rgba.is_cast_compatible(bgra));
+}
+
+#[test]
+fn srgb_pairs() {
+    for info in FormatInfo::all() {
+        if let Some(pair) = info.raw_srgb_pair() {
+            let pair = FormatInfo::from_raw(pair).unwrap();
+            assert_eq!(pair.raw_srgb_pair(), Some(info.format));
+            assert_ne!(info.is_srgb(), pair.is_srgb());
+            assert!(info.is_cast_compatible(pair));
+        } else {
+            assert!(!info.is_srgb(), "{}", info.name);
+        }
+    }
+
+    assert_eq!(
+        FormatInfo::of(Format::R8G8B8A8Unorm).raw_srgb_pair(),
+        Some(FormatInfo::of(Format::R8G8B8A8UnormSrgb).format)
+    );
+}
+
+#[test]
+fn typed_formats() {
+    let rgba = FormatInfo::of(Format::R8G8B8A8Unorm);
+    assert_eq!(rgba.name, "R8G8B8A8_UNORM");
+    assert_eq!(rgba.typeless(), Some(Format::R8G8B8A8Typeless));
+    assert_eq!(rgba.srgb_pair(), Some(Format::R8G8B8A8UnormSrgb));
+    assert_eq!(
+        FormatInfo::of(Format::Bc7UnormSrgb).srgb_pair(),
+        Some(Format::Bc7Unorm)
+    );
+    assert_eq!(
+        FormatInfo::of(Format::R32Float).typeless(),
+        Some(Format::R32Typeless)
+    );
+    assert_eq!(FormatInfo::of(Format::Nv12).typeless(), None);
+}
+
+#[test]
+fn depth_stencil_formats() {
+    let depth: Vec<_> = FormatInfo::all()
+        .iter()
+        .filter(|info| info.is_depth())
+        .map(|info| info.name)
+        .collect();
+    assert_eq!(
+        depth,
+        ["D32_FLOAT_S8X24_UINT", "D32_FLOAT", "D24_UNORM_S8_UINT", "D16_UNORM"]
+    );
+
+    let stencil: Vec<_> = FormatInfo::all()
+        .iter()
+        .filter(|info| info.is_stencil())
+        .map(|info| info.name)
+        .collect();
+    assert_eq!(stencil, ["D32_FLOAT_S8X24_UINT", "D24_UNORM_S8_UINT"]);
+    assert_eq!(FormatInfo::from_raw(40).unwrap().aspect, Aspect::Depth);
+}
+
+#[test]
+fn surface_sizes() {
+    let bc1 = FormatInfo::of(Format::Bc1Unorm);
+    assert!(bc1.is_block_compressed());
+    assert_eq!(bc1.row_bytes(10), 24);
+    assert_eq!(bc1.row_count(10), 3);
+    assert_eq!(bc1.surface_bytes(1, 1), 8);
+
+    let bc7 = FormatInfo::of(Format::Bc7UnormSrgb);
+    assert_eq!(bc7.surface_bytes(16, 16), 256);
+
+    // R8G8_B8G8_UNORM packs two texels into 4 bytes
+    let packed = FormatInfo::from_raw(68).unwrap();
+    assert_eq!(packed.row_bytes(3), 8);
+
+    // R1_UNORM packs 8 texels into a byte
+    assert_eq!(FormatInfo::from_raw(66).unwrap().row_bytes(9), 2);
+
+    // NV12 is a full resolution luma plane and a half resolution chroma plane
+    let nv12 = FormatInfo::from_raw(103).unwrap();
+    assert!(nv12.is_planar());
+    assert_eq!(nv12.surface_bytes(4, 4), 24);
+    assert_eq!(nv12.row_count(5), 8);
+}
+
+#[test]
+fn block_compression() {
+    for info in FormatInfo::all() {
+        assert_eq!(
+            info.block_compression().is_some(),
+            info.is_block_compressed(),
+            "{}",
+            info.name
+        );
+    }
+
+    let bc = |format| FormatInfo::of(format).block_compression();
+    assert_eq!(bc(Format::Bc1UnormSrgb), Some(BlockCompression::Bc1));
+    assert_eq!(bc(Format::Bc4Snorm), Some(BlockCompression::Bc4));
+    assert_eq!(bc(Format::Bc6hSf16), Some(BlockCompression::Bc6h));
+    assert_eq!(bc(Format::Bc7Typeless), Some(BlockCompression::Bc7));
+    assert_eq!(bc(Format::R8G8B8A8Unorm), None);
+}
+
+#[test]
+fn block_compressed_initial_data() {
+    let extent = TextureExtent {
+        width: 8,
+        height: 8,
+        depth: 1,
+        mip_levels: 1,
+        array_size: 1,
+    };
+
+    // Two rows of two 8 byte blocks.
+    let bytes = [0u8; 32];
+    let data = [SubresourceData::new(&bytes, 16, 0)];
+    assert_eq!(
+        initial_data::check_subresources(&data, Format::Bc1Unorm, &extent),
+        Ok(())
+    );
+
+    // A pitch computed per texel row instead of per block row.
+    let data = [SubresourceData::new(&bytes, 4, 0)];
+    assert!(initial_data::check_subresources(&data, Format::Bc1Unorm, &extent).is_err());
+
+    let data = [SubresourceData::new(&bytes[..24], 16, 0)];
+    match initial_data::check_subresources(&data, Format::Bc1Unorm, &extent) {
+        Err(DescError::InvalidSubresourceData { error, .. }) => assert_eq!(
+            *error,
+            DescError::InitialDataTooSmall {
+                expected: 32,
+                actual: 24
+            }
+        ),
+        result => panic!("unexpected result {:?}", result),
+    }
+}
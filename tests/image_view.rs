@@ -0,0 +1,112 @@
+extern crate direct3d11;
+
+use direct3d11::error::DescError;
+use direct3d11::format_info::Format;
+use direct3d11::image_view::{ImageView, ImageViewMut};
+
+// A 2x2 RGBA8 image with rows padded to 12 bytes. The padding bytes are 0xEE.
+static PADDED_2X2: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0xEE, 0xEE, 0xEE, 0xEE, //
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+];
+
+static PACKED_2X2: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, //
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+];
+
+#[test]
+fn rows_skip_padding() {
+    let view = ImageView::new(PADDED_2X2, Format::R8G8B8A8Unorm, 2, 2, 1, 12, 0).unwrap();
+    assert_eq!(view.row_bytes(), 8);
+    assert_eq!(view.row_count(), 2);
+    assert_eq!(view.row(1, 0), &PACKED_2X2[8..]);
+
+    let rows: Vec<&[u8]> = view.rows().collect();
+    assert_eq!(rows, [&PACKED_2X2[..8], &PACKED_2X2[8..]]);
+    assert_eq!(view.to_packed_vec(), PACKED_2X2);
+}
+
+#[test]
+fn texel_access() {
+    let view = ImageView::new(PADDED_2X2, Format::R8G8B8A8Unorm, 2, 2, 1, 12, 0).unwrap();
+    assert_eq!(view.texel(1, 1, 0), Some(&[0x14, 0x15, 0x16, 0x17][..]));
+    assert_eq!(view.texel(2, 0, 0), None);
+    assert_eq!(view.texel(0, 0, 1), None);
+
+    let bc1 = [0u8; 8];
+    let view = ImageView::packed(&bc1, Format::Bc1Unorm, 4, 4, 1).unwrap();
+    assert_eq!(view.row_count(), 1);
+    assert_eq!(view.texel(0, 0, 0), None);
+}
+
+#[test]
+fn copy_in_keeps_padding() {
+    let mut data = vec![0xEE; PADDED_2X2.len()];
+    {
+        let mut view = ImageViewMut::new(&mut data, Format::R8G8B8A8Unorm, 2, 2, 1, 12, 0).unwrap();
+        view.copy_from_packed(PACKED_2X2);
+    }
+    assert_eq!(data, PADDED_2X2);
+
+    let mut packed = vec![0; PACKED_2X2.len()];
+    {
+        let src = ImageView::new(PADDED_2X2, Format::R8G8B8A8Unorm, 2, 2, 1, 12, 0).unwrap();
+        let mut dst = ImageViewMut::packed(&mut packed, Format::R8G8B8A8Unorm, 2, 2, 1).unwrap();
+        dst.copy_from(&src);
+    }
+    assert_eq!(packed, PACKED_2X2);
+}
+
+#[test]
+fn mutable_rows_and_texels() {
+    let mut data = vec![0xEE; 32 + 16 + 8];
+    {
+        // 2x2x2 volume with 8 byte rows padded to 16 and slices 32 bytes
+        // apart.
+        let mut view =
+            ImageViewMut::new(&mut data, Format::R8G8B8A8Unorm, 2, 2, 2, 16, 32).unwrap();
+        for (i, row) in view.rows_mut().enumerate() {
+            for byte in row.iter_mut() {
+                *byte = i as u8;
+            }
+        }
+        view.texel_mut(1, 0, 1)
+            .unwrap()
+            .copy_from_slice(&[9, 9, 9, 9]);
+        assert_eq!(view.as_view().row(0, 1), &[2, 2, 2, 2, 9, 9, 9, 9]);
+    }
+    assert_eq!(&data[8..16], &[0xEE; 8]);
+    assert_eq!(&data[16..24], &[1; 8]);
+    assert_eq!(&data[24..32], &[0xEE; 8]);
+    assert_eq!(&data[48..56], &[3; 8]);
+}
+
+#[test]
+fn invalid_layouts() {
+    let result = ImageView::new(PADDED_2X2, Format::R8G8B8A8Unorm, 2, 2, 1, 4, 0);
+    assert_eq!(
+        result.err(),
+        Some(DescError::RowPitchTooSmall {
+            row_pitch: 4,
+            row_bytes: 8
+        })
+    );
+
+    let result = ImageView::new(&PADDED_2X2[..16], Format::R8G8B8A8Unorm, 2, 2, 1, 12, 0);
+    assert_eq!(
+        result.err(),
+        Some(DescError::InitialDataTooSmall {
+            expected: 20,
+            actual: 16
+        })
+    );
+
+    let result = ImageView::packed(PACKED_2X2, Format::Unknown, 2, 2, 1);
+    assert_eq!(
+        result.err(),
+        Some(DescError::UnknownFormatSize {
+            format: Format::Unknown
+        })
+    );
+}
@@ -0,0 +1,75 @@
+extern crate direct3d11;
+
+use direct3d11::error::DescError;
+use direct3d11::format_info::Format;
+use direct3d11::initial_data::{self, SubresourceData};
+
+#[test]
+fn row_bytes() {
+    assert_eq!(initial_data::row_bytes(Format::R8G8B8A8Unorm, 3), Ok(12));
+    assert_eq!(
+        initial_data::row_bytes(Format::Unknown, 3),
+        Err(DescError::UnknownFormatSize {
+            format: Format::Unknown
+        })
+    );
+}
+
+#[test]
+fn pitched_image() {
+    // 3 rows of 12 bytes, padded to 16, without padding after the last row.
+    let bytes = [0u8; 16 * 2 + 12];
+    let data = SubresourceData::new(&bytes, 16, 0);
+    assert_eq!(initial_data::check_subresource_size(&data, 12, 3, 1), Ok(()));
+
+    let data = SubresourceData::new(&bytes[..40], 16, 0);
+    assert_eq!(
+        initial_data::check_subresource_size(&data, 12, 3, 1),
+        Err(DescError::InitialDataTooSmall {
+            expected: 44,
+            actual: 40
+        })
+    );
+}
+
+#[test]
+fn row_pitch_too_small() {
+    let bytes = [0u8; 64];
+    let data = SubresourceData::new(&bytes, 8, 0);
+    assert_eq!(
+        initial_data::check_subresource_size(&data, 12, 2, 1),
+        Err(DescError::RowPitchTooSmall {
+            row_pitch: 8,
+            row_bytes: 12
+        })
+    );
+}
+
+#[test]
+fn volume() {
+    // 2 slices of 2 rows of 8 bytes.
+    let bytes = [0u8; 32];
+    let data = SubresourceData::new(&bytes, 8, 16);
+    assert_eq!(initial_data::check_subresource_size(&data, 8, 2, 2), Ok(()));
+
+    let data = SubresourceData::new(&bytes, 8, 12);
+    assert_eq!(
+        initial_data::check_subresource_size(&data, 8, 2, 2),
+        Err(DescError::SlicePitchTooSmall {
+            slice_pitch: 12,
+            slice_bytes: 16
+        })
+    );
+}
+
+#[test]
+fn subresource_count() {
+    assert_eq!(initial_data::check_subresource_count(6, 6), Ok(()));
+    assert_eq!(
+        initial_data::check_subresource_count(1, 6),
+        Err(DescError::InitialDataCount {
+            expected: 6,
+            actual: 1
+        })
+    );
+}
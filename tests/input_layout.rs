@@ -0,0 +1,60 @@
+#![cfg(windows)]
+
+extern crate direct3d11;
+
+use direct3d11::device::Device;
+use direct3d11::device_context::IDeviceContext;
+use direct3d11::error::ShaderError;
+use direct3d11::input_layout::InputLayout;
+use direct3d11::vertex::{InputLayoutError, Vertex};
+
+static VS: &[u8] = include_bytes!("data/textured.vs.dxbc");
+
+#[derive(Copy, Clone, Vertex)]
+#[repr(C)]
+struct TexturedVertex {
+    position: [f32; 3],
+    #[semantic("TEXCOORD", 0)]
+    uv: [f32; 2],
+    #[format(R8G8B8A8Unorm)]
+    color: u32,
+}
+
+#[test]
+fn create_from_vertex() {
+    let (_, device, ctx) = Device::create().build().unwrap();
+    let builder = InputLayout::create(&device, VS).with_vertex::<TexturedVertex>(1);
+    assert!(builder.elements().iter().all(|e| e.input_slot == 1));
+    let layout = builder.build().unwrap();
+
+    ctx.set_input_layout(Some(&layout));
+    ctx.set_input_layout(None);
+}
+
+#[test]
+fn reject_mismatched_layout() {
+    let (_, device, _) = Device::create().build().unwrap();
+    let mut elements = TexturedVertex::elements();
+    elements.retain(|e| e.semantic_name != "COLOR");
+    match InputLayout::create(&device, VS)
+        .with_elements(&elements)
+        .build()
+    {
+        Err(ShaderError::InputLayout(InputLayoutError::MissingSemantic { semantic })) => {
+            assert_eq!(semantic.to_string(), "COLOR0")
+        }
+        result => panic!("unexpected {:?}", result.map(|_| ())),
+    }
+
+    // Without the check, Direct3D rejects the layout itself.
+    assert!(InputLayout::create(&device, VS)
+        .with_elements(&elements)
+        .with_unchecked_layout()
+        .build()
+        .is_err());
+
+    match InputLayout::create(&device, &VS[..40]).build() {
+        Err(ShaderError::Bytecode(_)) => (),
+        result => panic!("unexpected {:?}", result.map(|_| ())),
+    }
+}
@@ -0,0 +1,202 @@
+extern crate direct3d11;
+
+use direct3d11::enums::FeatureLevel;
+use direct3d11::format_info::Format;
+use direct3d11::limits::{Feature, LimitError};
+use direct3d11::texture2d::Texture2DDesc;
+
+#[test]
+fn table() {
+    let limits = FeatureLevel::LEVEL_9_1.limits();
+    assert_eq!(limits.max_texture2d_dimension, 2048);
+    assert_eq!(limits.max_cube_dimension, 512);
+    assert_eq!(limits.max_array_size, 1);
+    assert_eq!(limits.max_render_targets, 1);
+    assert!(!limits.supports(Feature::UnorderedAccess));
+
+    let limits = FeatureLevel::LEVEL_9_3.limits();
+    assert_eq!(limits.max_texture2d_dimension, 4096);
+    assert_eq!(limits.max_render_targets, 4);
+
+    let limits = FeatureLevel::LEVEL_10_1.limits();
+    assert_eq!(limits.max_texture2d_dimension, 8192);
+    assert_eq!(limits.max_array_size, 512);
+    assert!(limits.supports(Feature::CubeArrays));
+    assert!(!limits.supports(Feature::ComputeShaders));
+    assert!(!limits.supports(Feature::Bc6hBc7));
+
+    let limits = FeatureLevel::LEVEL_11_0.limits();
+    assert_eq!(limits.max_texture2d_dimension, 16384);
+    assert_eq!(limits.max_texture3d_dimension, 2048);
+    assert_eq!(limits.max_array_size, 2048);
+    assert_eq!(limits.max_uav_slots, 8);
+    assert!(limits.supports(Feature::Bc6hBc7));
+    assert_eq!(FeatureLevel::LEVEL_11_1.limits().max_uav_slots, 64);
+}
+
+#[test]
+fn levels_without_a_row() {
+    let limits = FeatureLevel::LEVEL_12_1.limits();
+    assert_eq!(limits.level, FeatureLevel::LEVEL_12_1);
+    assert_eq!(
+        limits.max_uav_slots,
+        FeatureLevel::LEVEL_11_1.limits().max_uav_slots
+    );
+    assert_eq!(
+        FeatureLevel(0x1000).limits().max_texture2d_dimension,
+        FeatureLevel::LEVEL_9_1.limits().max_texture2d_dimension
+    );
+}
+
+#[test]
+fn texture_checks() {
+    let limits = FeatureLevel::LEVEL_10_0.limits();
+    assert_eq!(limits.check_texture2d(8192, 8192, 512, false), Ok(()));
+    assert_eq!(
+        limits.check_texture2d(8192, 8193, 1, false),
+        Err(LimitError::TextureSize {
+            size: 8193,
+            max: 8192,
+            level: FeatureLevel::LEVEL_10_0,
+        })
+    );
+    assert_eq!(
+        limits.check_texture2d(16, 16, 513, false),
+        Err(LimitError::ArraySize {
+            array_size: 513,
+            max: 512,
+            level: FeatureLevel::LEVEL_10_0,
+        })
+    );
+    assert_eq!(
+        limits.check_texture2d(16, 16, 12, true),
+        Err(LimitError::Unsupported {
+            feature: Feature::CubeArrays,
+            level: FeatureLevel::LEVEL_10_0,
+        })
+    );
+    assert_eq!(limits.check_texture1d(8192, 1), Ok(()));
+    assert!(limits.check_texture3d(2048, 2048, 2049).is_err());
+
+    // Cubes are not arrays, even where arrays are not supported.
+    let limits = FeatureLevel::LEVEL_9_1.limits();
+    assert_eq!(limits.check_texture2d(512, 512, 6, true), Ok(()));
+    assert_eq!(
+        limits.check_texture2d(1024, 1024, 6, true),
+        Err(LimitError::CubeSize {
+            size: 1024,
+            max: 512,
+            level: FeatureLevel::LEVEL_9_1,
+        })
+    );
+    assert!(limits.check_texture2d(16, 16, 2, false).is_err());
+    assert!(FeatureLevel::LEVEL_10_1
+        .limits()
+        .check_texture2d(16, 16, 12, true)
+        .is_ok());
+}
+
+#[test]
+fn error_messages() {
+    let err = FeatureLevel::LEVEL_9_3
+        .limits()
+        .check_texture2d(8192, 16, 1, false)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "texture size 8192 is larger than the maximum of 4096 at feature level 9_3"
+    );
+    let err = FeatureLevel::LEVEL_10_1
+        .limits()
+        .require(Feature::Bc6hBc7)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "BC6H and BC7 formats are not supported at feature level 10_1"
+    );
+}
+
+#[test]
+fn texture_formats() {
+    let limits = FeatureLevel::LEVEL_10_1.limits();
+    for &format in &[Format::Bc6hUf16, Format::Bc7Typeless, Format::Bc7UnormSrgb] {
+        let desc = Texture2DDesc {
+            width: 4,
+            height: 4,
+            format,
+            ..Texture2DDesc::default()
+        };
+        assert_eq!(
+            desc.validate_limits(&limits),
+            Err(LimitError::Unsupported {
+                feature: Feature::Bc6hBc7,
+                level: FeatureLevel::LEVEL_10_1
+            })
+        );
+        assert!(desc
+            .validate_limits(&FeatureLevel::LEVEL_11_0.limits())
+            .is_ok());
+    }
+
+    let desc = Texture2DDesc {
+        width: 4,
+        height: 4,
+        format: Format::Bc5Unorm,
+        ..Texture2DDesc::default()
+    };
+    assert!(desc.validate_limits(&limits).is_ok());
+}
+
+#[cfg(windows)]
+extern crate dxgi;
+
+#[cfg(windows)]
+#[test]
+fn builders_check_the_device_level() {
+    use direct3d11::device::Device;
+    use direct3d11::enums::{BindFlags, DriverType};
+    use direct3d11::error::{BuildError, DescError};
+    use direct3d11::texture2d::Texture2D;
+    use dxgi::enums::Format;
+
+    let (level, device, _) = Device::create()
+        .with_driver_type(DriverType::Warp)
+        .with_feature_levels(&[FeatureLevel::LEVEL_10_0])
+        .build()
+        .unwrap();
+    assert_eq!(level, FeatureLevel::LEVEL_10_0);
+
+    let result = Texture2D::create(&device)
+        .with_size(16384, 16)
+        .with_format(Format::R8G8B8A8Unorm)
+        .build();
+    match result {
+        Err(BuildError::Desc(DescError::Limit(LimitError::TextureSize { size, max, .. }))) => {
+            assert_eq!((size, max), (16384, 8192))
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+
+    let result = Texture2D::create(&device)
+        .with_size(16, 16)
+        .with_format(Format::Bc7Unorm)
+        .build();
+    match result {
+        Err(BuildError::Desc(DescError::Limit(LimitError::Unsupported { feature, .. }))) => {
+            assert_eq!(feature, Feature::Bc6hBc7)
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+
+    let result = Texture2D::create(&device)
+        .with_size(16, 16)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::UNORDERED_ACCESS)
+        .build();
+    match result {
+        Err(BuildError::Desc(DescError::Limit(LimitError::Unsupported { feature, .. }))) => {
+            assert_eq!(feature, Feature::UnorderedAccess)
+        }
+        other => panic!("unexpected result {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,223 @@
+extern crate direct3d11;
+
+use direct3d11::dxbc::reflection::{
+    CBufferType, ResourceReturnType, ShaderInputType, SrvDimension, VariableClass, VariableType,
+};
+use direct3d11::dxbc::{
+    Container, ContainerWriter, DxbcError, FourCC, ShaderReflection, SystemValue,
+};
+
+static VS: &[u8] = include_bytes!("data/textured.vs.dxbc");
+static PS: &[u8] = include_bytes!("data/textured.ps.dxbc");
+
+/// A shader model 4 `RDEF` chunk for
+///
+/// ```hlsl
+/// cbuffer Lights : register(b1) { float3 direction; float intensity; float2 offsets[2]; };
+/// Texture2DArray<float4> shadow : register(t3);
+/// ```
+///
+/// laid out record by record, independently of the samples.
+fn lights_rdef() -> Vec<u8> {
+    const CBUFFERS: u32 = 28;
+    const BINDINGS: u32 = CBUFFERS + 24;
+    const VARIABLES: u32 = BINDINGS + 2 * 32;
+    const TYPES: u32 = VARIABLES + 3 * 24;
+    const STRINGS: u32 = TYPES + 3 * 16;
+
+    let names = [
+        "Lights",
+        "direction",
+        "intensity",
+        "offsets",
+        "shadow",
+        "tests",
+    ];
+    let mut strings = Vec::new();
+    let mut name = Vec::new();
+    for s in names {
+        name.push(STRINGS + strings.len() as u32);
+        strings.extend_from_slice(s.as_bytes());
+        strings.push(0);
+    }
+    // A float type without members, from its class, rows, columns and
+    // elements.
+    let ty = |class: u32, rows: u32, columns: u32, elements: u32| {
+        [class | 3 << 16, rows | columns << 16, elements, 0]
+    };
+
+    let words = [
+        // The header of a ps_4_0 shader.
+        &[1, CBUFFERS, 2, BINDINGS, 0xFFFF_0400, 0, name[5]][..],
+        // The constant buffer, used and 48 bytes long.
+        &[name[0], 3, VARIABLES, 48, 0, 0],
+        // The bindings: the constant buffer and a texture with 4 components.
+        &[name[0], 0, 0, 0, 0, 1, 1, 1],
+        &[name[4], 2, 5, 5, u32::MAX, 3, 1, 0xC],
+        // The variables, all used.
+        &[name[1], 0, 12, 2, TYPES, 0],
+        &[name[2], 12, 4, 2, TYPES + 16, 0],
+        &[name[3], 16, 24, 2, TYPES + 32, 0],
+        &ty(1, 1, 3, 0),
+        &ty(0, 1, 1, 0),
+        &ty(1, 1, 2, 2),
+    ]
+    .concat();
+    let mut data: Vec<u8> = words.iter().flat_map(|w| w.to_le_bytes()).collect();
+    assert_eq!(data.len(), STRINGS as usize);
+    data.extend_from_slice(&strings);
+    data
+}
+
+#[test]
+fn desc() {
+    let vs = ShaderReflection::new(VS).unwrap();
+    let desc = vs.desc();
+    assert_eq!(desc.version.to_string(), "vs_5_0");
+    assert_eq!(desc.constant_buffers, 1);
+    assert_eq!(desc.bound_resources, 1);
+    assert_eq!(desc.input_parameters, 3);
+    assert_eq!(desc.output_parameters, 3);
+    assert_eq!(desc.patch_constant_parameters, 0);
+    assert_eq!(desc.statistics.instruction_count, 9);
+    assert_eq!(vs.thread_group_size(), None);
+
+    let ps = ShaderReflection::new(PS).unwrap();
+    assert_eq!(ps.desc().bound_resources, 2);
+    assert_eq!(ps.desc().statistics.texture_normal_instructions, 1);
+}
+
+#[test]
+fn constant_buffers() {
+    let vs = ShaderReflection::new(VS).unwrap();
+    let transform = vs.constant_buffer_by_name("Transform").unwrap();
+    assert_eq!(transform.kind, CBufferType::CBuffer);
+    assert_eq!(transform.size, 80);
+    assert_eq!(transform.variables.len(), 2);
+
+    let matrix = &transform.variables[0];
+    assert_eq!(matrix.name, "world_view_proj");
+    assert_eq!((matrix.start_offset, matrix.size), (0, 64));
+    assert_eq!(matrix.ty.class, VariableClass::MatrixColumns);
+    assert_eq!(matrix.ty.ty, VariableType::Float);
+    assert_eq!((matrix.ty.rows, matrix.ty.columns), (4, 4));
+
+    let tint = vs.variable_by_name("tint").unwrap();
+    assert_eq!(tint.start_offset, 64);
+    assert_eq!(tint.ty.class, VariableClass::Vector);
+    assert_eq!(tint.ty.name, Some("float4"));
+    assert!(vs.variable_by_name("missing").is_none());
+}
+
+#[test]
+fn hand_built_definitions() {
+    let rdef = lights_rdef();
+    let bytecode = ContainerWriter::new().push(FourCC::RDEF, &rdef[..]).write();
+    let reflection = ShaderReflection::new(&bytecode).unwrap();
+    let desc = reflection.desc();
+    assert_eq!(desc.version.to_string(), "ps_4_0");
+    assert_eq!(desc.creator, "tests");
+    assert_eq!((desc.constant_buffers, desc.bound_resources), (1, 2));
+    assert_eq!(desc.input_parameters, 0);
+
+    let lights = reflection.constant_buffer_by_name("Lights").unwrap();
+    assert_eq!(lights.kind, CBufferType::CBuffer);
+    assert_eq!(lights.size, 48);
+    let layout: Vec<_> = lights
+        .variables
+        .iter()
+        .map(|v| (v.name, v.start_offset, v.size))
+        .collect();
+    assert_eq!(
+        layout,
+        [
+            ("direction", 0, 12),
+            ("intensity", 12, 4),
+            ("offsets", 16, 24)
+        ]
+    );
+
+    let intensity = reflection.variable_by_name("intensity").unwrap();
+    assert_eq!(intensity.ty.class, VariableClass::Scalar);
+    assert_eq!(intensity.ty.ty, VariableType::Float);
+    assert_eq!(intensity.start_texture, u32::MAX);
+    let offsets = reflection.variable_by_name("offsets").unwrap();
+    assert_eq!(offsets.ty.class, VariableClass::Vector);
+    assert_eq!((offsets.ty.columns, offsets.ty.elements), (2, 2));
+    assert_eq!(offsets.ty.name, None);
+
+    let binding = reflection.resource_binding_by_name("Lights").unwrap();
+    assert_eq!(binding.input_type, ShaderInputType::CBuffer);
+    assert_eq!((binding.bind_point, binding.bind_count), (1, 1));
+    let shadow = reflection.resource_binding_by_name("shadow").unwrap();
+    assert_eq!(shadow.input_type, ShaderInputType::Texture);
+    assert_eq!(shadow.return_type, Some(ResourceReturnType::Float));
+    assert_eq!(shadow.dimension, SrvDimension::Texture2DArray);
+    assert_eq!(shadow.num_samples, None);
+    assert_eq!((shadow.bind_point, shadow.flags), (3, 0xC));
+}
+
+#[test]
+fn resource_bindings() {
+    let vs = ShaderReflection::new(VS).unwrap();
+    let transform = vs.resource_binding_by_name("Transform").unwrap();
+    assert_eq!(transform.input_type, ShaderInputType::CBuffer);
+    assert_eq!(transform.return_type, None);
+    assert_eq!(transform.dimension, SrvDimension::Unknown);
+
+    let ps = ShaderReflection::new(PS).unwrap();
+    let sampler = ps.resource_binding_by_name("linear_sampler").unwrap();
+    assert_eq!(sampler.input_type, ShaderInputType::Sampler);
+    assert_eq!(sampler.bind_point, 0);
+    let albedo = ps.resource_binding_by_name("albedo").unwrap();
+    assert_eq!(albedo.input_type, ShaderInputType::Texture);
+    assert_eq!(albedo.return_type, Some(ResourceReturnType::Float));
+    assert_eq!(albedo.dimension, SrvDimension::Texture2D);
+    assert_eq!(albedo.num_samples, None);
+    assert_eq!((albedo.bind_point, albedo.bind_count), (0, 1));
+}
+
+#[test]
+fn signatures() {
+    let vs = ShaderReflection::new(VS).unwrap();
+    assert_eq!(vs.input_parameters()[2].semantic_name, "COLOR");
+    assert_eq!(
+        vs.output_parameters()[0].system_value,
+        SystemValue::Position
+    );
+
+    // The container stores `SV_Target` as an undefined system value.
+    let container = Container::parse(PS).unwrap();
+    let raw = container.output_signature().unwrap().unwrap();
+    assert_eq!(raw.elements[0].system_value, SystemValue::Undefined);
+    let ps = ShaderReflection::new(PS).unwrap();
+    assert_eq!(ps.output_parameters()[0].system_value, SystemValue::Target);
+    assert_eq!(ps.input_parameters()[0].system_value, SystemValue::Position);
+}
+
+#[test]
+fn invalid_containers() {
+    // Rename the RDEF chunk so the container appears stripped.
+    let container = Container::parse(PS).unwrap();
+    let rdef = container.chunk(FourCC::RDEF).unwrap().data;
+    let offset = rdef.as_ptr() as usize - PS.as_ptr() as usize - 8;
+    let mut data = PS.to_vec();
+    data[offset..offset + 4].copy_from_slice(b"XDEF");
+    assert_eq!(
+        ShaderReflection::new(&data).map(|_| ()),
+        Err(DxbcError::MissingChunk(FourCC::RDEF))
+    );
+
+    // An unknown shader input type in the first binding.
+    let mut data = PS.to_vec();
+    let bindings = u32::from_le_bytes([rdef[12], rdef[13], rdef[14], rdef[15]]) as usize;
+    data[offset + 8 + bindings + 4] = 42;
+    assert_eq!(
+        ShaderReflection::new(&data).map(|_| ()),
+        Err(DxbcError::InvalidValue {
+            chunk: Some(FourCC::RDEF),
+            field: "shader input type",
+            value: 42
+        })
+    );
+}
@@ -0,0 +1,136 @@
+#![cfg(feature = "serde")]
+
+extern crate direct3d11;
+
+use direct3d11::enums::{BindFlags, CpuAccessFlags, CreateDeviceFlags, DriverType};
+use direct3d11::enums::{FeatureLevel, Map, ResourceMiscFlags, Usage};
+use direct3d11::state::{BlendDesc, ColorWriteMask, ComparisonFunc, DepthStencilDesc};
+use direct3d11::state::{RasterizerDesc, SamplerDesc};
+use direct3d11::vertex::VertexFormat;
+
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T, json: &str) {
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+}
+
+#[test]
+fn flags() {
+    round_trip(
+        BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET,
+        r#"["SHADER_RESOURCE","RENDER_TARGET"]"#,
+    );
+    round_trip(BindFlags::NONE, "[]");
+    round_trip(CpuAccessFlags::READWRITE, r#"["WRITE","READ"]"#);
+    round_trip(
+        CreateDeviceFlags::DEBUG | CreateDeviceFlags::BGRA_SUPPORT,
+        r#"["DEBUG","BGRA_SUPPORT"]"#,
+    );
+    round_trip(ResourceMiscFlags::TEXTURECUBE, r#"["TEXTURECUBE"]"#);
+    round_trip(
+        ColorWriteMask::RED | ColorWriteMask::ALPHA,
+        r#"["RED","ALPHA"]"#,
+    );
+
+    // Combinations can be named, and flags repeated.
+    let flags: CpuAccessFlags = serde_json::from_str(r#"["READWRITE","READ"]"#).unwrap();
+    assert_eq!(flags, CpuAccessFlags::READWRITE);
+}
+
+#[test]
+fn invalid_flags() {
+    assert!(serde_json::from_str::<BindFlags>(r#"["RENDER_TARGETS"]"#).is_err());
+    assert!(serde_json::from_str::<BindFlags>(r#""RENDER_TARGET""#).is_err());
+    assert!(serde_json::from_str::<BindFlags>("32").is_err());
+    assert!(serde_json::to_string(&BindFlags(0x8000_0000)).is_err());
+}
+
+#[test]
+fn enums() {
+    round_trip(Usage::Dynamic, r#""Dynamic""#);
+    round_trip(DriverType::Warp, r#""Warp""#);
+    round_trip(Map::WriteNoOverwrite, r#""WriteNoOverwrite""#);
+    round_trip(ComparisonFunc::LessEqual, r#""LessEqual""#);
+    round_trip(VertexFormat::R32G32B32Float, r#""R32G32B32Float""#);
+    assert!(serde_json::from_str::<Usage>(r#""dynamic""#).is_err());
+    assert!(serde_json::from_str::<Usage>("2").is_err());
+}
+
+#[test]
+fn feature_levels() {
+    round_trip(FeatureLevel::LEVEL_11_0, r#""11_0""#);
+    round_trip(FeatureLevel::LEVEL_9_3, r#""9_3""#);
+    let level: FeatureLevel = serde_json::from_str(r#""10.1""#).unwrap();
+    assert_eq!(level, FeatureLevel::LEVEL_10_1);
+    assert!(serde_json::from_str::<FeatureLevel>(r#""11_3""#).is_err());
+    assert!(serde_json::from_str::<FeatureLevel>(r#""11""#).is_err());
+}
+
+#[test]
+fn state_descriptions() {
+    for desc in &[
+        BlendDesc::OPAQUE,
+        BlendDesc::ALPHA_BLEND,
+        BlendDesc::ADDITIVE,
+    ] {
+        let json = serde_json::to_string(desc).unwrap();
+        assert_eq!(serde_json::from_str::<BlendDesc>(&json).unwrap(), *desc);
+    }
+    for desc in &[
+        DepthStencilDesc::DEPTH_DEFAULT,
+        DepthStencilDesc::DEPTH_REVERSE_Z,
+    ] {
+        let json = serde_json::to_string(desc).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DepthStencilDesc>(&json).unwrap(),
+            *desc
+        );
+    }
+    let json = serde_json::to_string(&RasterizerDesc::WIREFRAME).unwrap();
+    assert_eq!(
+        serde_json::from_str::<RasterizerDesc>(&json).unwrap(),
+        RasterizerDesc::WIREFRAME
+    );
+    let json = serde_json::to_string(&SamplerDesc::SHADOW).unwrap();
+    assert_eq!(
+        serde_json::from_str::<SamplerDesc>(&json).unwrap(),
+        SamplerDesc::SHADOW
+    );
+}
+
+#[test]
+fn missing_fields_use_defaults() {
+    let desc: RasterizerDesc = serde_json::from_str(r#"{"cull_mode":"None"}"#).unwrap();
+    assert_eq!(desc, RasterizerDesc::CULL_NONE);
+    let desc: SamplerDesc = serde_json::from_str(r#"{"max_anisotropy":4}"#).unwrap();
+    assert_eq!(desc.max_anisotropy, 4);
+    assert_eq!(desc.filter, SamplerDesc::default().filter);
+}
+
+#[test]
+fn texture_descriptions() {
+    use direct3d11::format_info::Format;
+    use direct3d11::texture2d::Texture2DDesc;
+
+    let desc = Texture2DDesc {
+        width: 256,
+        height: 128,
+        format: Format::R8G8B8A8Unorm,
+        bind_flags: BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET,
+        ..Texture2DDesc::default()
+    };
+    let json = serde_json::to_string(&desc).unwrap();
+    assert!(json.contains(r#""format":"R8G8B8A8_UNORM""#));
+    assert!(json.contains(r#""bind_flags":["SHADER_RESOURCE","RENDER_TARGET"]"#));
+    assert_eq!(serde_json::from_str::<Texture2DDesc>(&json).unwrap(), desc);
+
+    let desc: Texture2DDesc =
+        serde_json::from_str(r#"{"width":4,"height":4,"format":"BC7_UNORM"}"#).unwrap();
+    assert_eq!(desc.format, Format::Bc7Unorm);
+    assert_eq!(desc.mip_levels, 1);
+    assert!(serde_json::from_str::<Texture2DDesc>(r#"{"format":"RGBA8"}"#).is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(windows)]
+
+extern crate direct3d11;
+
+use direct3d11::buffer::Buffer;
+use direct3d11::device::Device;
+use direct3d11::device_context::{DeviceContext, IDeviceContext};
+use direct3d11::dxbc::DxbcError;
+use direct3d11::enums::BindFlags;
+use direct3d11::error::ShaderError;
+use direct3d11::shader::{ComputeShader, PixelShader, ShaderStage, VertexShader};
+
+static PS: &[u8] = include_bytes!("data/textured.ps.dxbc");
+
+fn bind_constants<S: ShaderStage>(ctx: &DeviceContext, buffer: &Buffer) {
+    ctx.set_shader::<S>(None);
+    ctx.set_constant_buffers::<S>(0, &[Some(buffer), None]);
+    ctx.set_shader_resources::<S>(0, &[None]);
+}
+
+#[test]
+fn reject_invalid_bytecode() {
+    let (_, device, _) = Device::create().build().unwrap();
+    assert!(VertexShader::create(&device, &[]).is_err());
+    assert!(PixelShader::create(&device, b"DXBC not really a shader").is_err());
+
+    // Flip a byte of the code so that only the checksum catches it.
+    let mut bytecode = PS.to_vec();
+    let last = bytecode.len() - 1;
+    bytecode[last] ^= 1;
+    match PixelShader::create_verified(&device, &bytecode) {
+        Err(ShaderError::Bytecode(DxbcError::ChecksumMismatch { .. })) => (),
+        result => panic!("unexpected {:?}", result.map(|_| ())),
+    }
+    PixelShader::create_verified(&device, PS).unwrap();
+    PixelShader::create(&device, PS).unwrap();
+}
+
+#[test]
+fn bind_per_stage() {
+    let (_, device, ctx) = Device::create().build().unwrap();
+    let buffer = Buffer::create(&device)
+        .with_size(16)
+        .with_bind_flags(BindFlags::CONSTANT_BUFFER)
+        .build()
+        .unwrap();
+
+    bind_constants::<VertexShader>(&ctx, &buffer);
+    bind_constants::<PixelShader>(&ctx, &buffer);
+    bind_constants::<ComputeShader>(&ctx, &buffer);
+}
+
+#[test]
+#[should_panic]
+fn constant_buffer_slots_out_of_range() {
+    let (_, device, ctx) = Device::create().build().unwrap();
+    let buffer = Buffer::create(&device)
+        .with_size(16)
+        .with_bind_flags(BindFlags::CONSTANT_BUFFER)
+        .build()
+        .unwrap();
+    ctx.set_constant_buffers::<PixelShader>(13, &[Some(&buffer), Some(&buffer)]);
+}
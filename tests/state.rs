@@ -0,0 +1,155 @@
+extern crate direct3d11;
+
+use direct3d11::state::{Blend, BlendDesc, BlendOp, ColorWriteMask, ComparisonFunc};
+use direct3d11::state::{CullMode, FillMode, RasterizerDesc, RenderTargetBlend};
+use direct3d11::state::{DepthStencilDesc, DepthWriteMask, StencilOp};
+use direct3d11::state::{Filter, SamplerDesc, TextureAddressMode};
+
+#[test]
+fn raw_values() {
+    // Spot checks against the values of the Direct3D headers.
+    assert_eq!(Blend::Zero.to_raw(), 1);
+    assert_eq!(Blend::InvSrcAlpha.to_raw(), 6);
+    assert_eq!(Blend::BlendFactor.to_raw(), 14);
+    assert_eq!(Blend::InvSrc1Alpha.to_raw(), 19);
+    assert_eq!(BlendOp::RevSubtract.to_raw(), 3);
+    assert_eq!(ComparisonFunc::LessEqual.to_raw(), 4);
+    assert_eq!(ComparisonFunc::Always.to_raw(), 8);
+    assert_eq!(StencilOp::Decr.to_raw(), 8);
+    assert_eq!(DepthWriteMask::All.to_raw(), 1);
+    assert_eq!(FillMode::Solid.to_raw(), 3);
+    assert_eq!(CullMode::Back.to_raw(), 3);
+    assert_eq!(Filter::MinMagMipLinear.to_raw(), 0x15);
+    assert_eq!(Filter::ComparisonAnisotropic.to_raw(), 0xD5);
+    assert_eq!(TextureAddressMode::MirrorOnce.to_raw(), 5);
+
+    assert_eq!(Blend::from_raw(11), Some(Blend::SrcAlphaSat));
+    assert_eq!(Blend::from_raw(12), None);
+    assert_eq!(
+        Filter::from_raw(0x94),
+        Some(Filter::ComparisonMinMagLinearMipPoint)
+    );
+    assert_eq!(Filter::from_raw(0x02), None);
+    assert_eq!(CullMode::from_raw(0), None);
+    for raw in 0..0x100 {
+        if let Some(filter) = Filter::from_raw(raw) {
+            assert_eq!(filter.to_raw(), raw);
+        }
+    }
+
+    assert!(Filter::ComparisonMinMagMipPoint.is_comparison());
+    assert!(!Filter::Anisotropic.is_comparison());
+    assert!(Filter::ComparisonAnisotropic.is_anisotropic());
+    assert!(!Filter::MinMagMipLinear.is_anisotropic());
+
+    let mask = ColorWriteMask::RED | ColorWriteMask::ALPHA;
+    assert_eq!(mask.0, 0x9);
+    assert!(mask.is_set(ColorWriteMask::ALPHA));
+    assert!(!mask.is_set(ColorWriteMask::ALL));
+}
+
+#[test]
+fn blend_presets() {
+    assert_eq!(BlendDesc::default(), BlendDesc::OPAQUE);
+    assert!(!BlendDesc::OPAQUE.render_target[0].blend_enable);
+
+    let alpha = BlendDesc::ALPHA_BLEND;
+    assert!(!alpha.independent_blend_enable);
+    assert!(alpha
+        .render_target
+        .iter()
+        .all(|rt| *rt == alpha.render_target[0]));
+    let rt = alpha.render_target[7];
+    assert!(rt.blend_enable);
+    assert_eq!(
+        (rt.src_blend, rt.dest_blend),
+        (Blend::SrcAlpha, Blend::InvSrcAlpha)
+    );
+    assert_eq!(rt.write_mask, ColorWriteMask::ALL);
+
+    let rt = BlendDesc::PREMULTIPLIED.render_target[0];
+    assert_eq!(
+        (rt.src_blend, rt.dest_blend),
+        (Blend::One, Blend::InvSrcAlpha)
+    );
+    let rt = BlendDesc::ADDITIVE.render_target[0];
+    assert_eq!((rt.src_blend, rt.dest_blend), (Blend::SrcAlpha, Blend::One));
+
+    let mut desc = BlendDesc::uniform(RenderTargetBlend::ADDITIVE);
+    assert_eq!(desc, BlendDesc::ADDITIVE);
+    desc.render_target[1] = RenderTargetBlend {
+        write_mask: ColorWriteMask::NONE,
+        ..RenderTargetBlend::OPAQUE
+    };
+    assert_ne!(desc, BlendDesc::ADDITIVE);
+}
+
+#[test]
+fn depth_stencil_presets() {
+    assert_eq!(DepthStencilDesc::default(), DepthStencilDesc::DEPTH_DEFAULT);
+    let desc = DepthStencilDesc::DEPTH_DEFAULT;
+    assert!(desc.depth_enable);
+    assert_eq!(desc.depth_write_mask, DepthWriteMask::All);
+    assert_eq!(desc.depth_func, ComparisonFunc::Less);
+    assert!(!desc.stencil_enable);
+    assert_eq!(
+        (desc.stencil_read_mask, desc.stencil_write_mask),
+        (0xFF, 0xFF)
+    );
+    assert_eq!(desc.front_face.stencil_func, ComparisonFunc::Always);
+    assert_eq!(desc.back_face.stencil_pass_op, StencilOp::Keep);
+
+    let none = DepthStencilDesc::DEPTH_NONE;
+    assert!(!none.depth_enable);
+    let read = DepthStencilDesc::DEPTH_READ;
+    assert_eq!(read.depth_write_mask, DepthWriteMask::Zero);
+    assert_eq!(read.depth_func, ComparisonFunc::LessEqual);
+    assert_eq!(
+        DepthStencilDesc::DEPTH_REVERSE_Z.depth_func,
+        ComparisonFunc::Greater
+    );
+}
+
+#[test]
+fn rasterizer_presets() {
+    assert_eq!(RasterizerDesc::default(), RasterizerDesc::CULL_BACK);
+    let desc = RasterizerDesc::CULL_BACK;
+    assert_eq!(desc.fill_mode, FillMode::Solid);
+    assert!(!desc.front_counter_clockwise);
+    assert!(desc.depth_clip_enable);
+    assert_eq!(RasterizerDesc::CULL_NONE.cull_mode, CullMode::None);
+    assert_eq!(RasterizerDesc::CULL_FRONT.cull_mode, CullMode::Front);
+    assert_eq!(RasterizerDesc::WIREFRAME.fill_mode, FillMode::Wireframe);
+    assert_eq!(RasterizerDesc::WIREFRAME.cull_mode, CullMode::None);
+}
+
+#[test]
+fn sampler_presets() {
+    assert_eq!(SamplerDesc::default(), SamplerDesc::LINEAR_CLAMP);
+    let desc = SamplerDesc::LINEAR_CLAMP;
+    assert_eq!(desc.filter, Filter::MinMagMipLinear);
+    assert_eq!(desc.address_w, TextureAddressMode::Clamp);
+    assert_eq!(desc.max_anisotropy, 1);
+    assert_eq!(desc.comparison_func, ComparisonFunc::Never);
+    assert_eq!(desc.border_color, [1.0; 4]);
+    assert_eq!((desc.min_lod, desc.max_lod), (-f32::MAX, f32::MAX));
+
+    let desc = SamplerDesc::POINT_WRAP;
+    assert_eq!(desc.filter, Filter::MinMagMipPoint);
+    assert_eq!(
+        [desc.address_u, desc.address_v, desc.address_w],
+        [TextureAddressMode::Wrap; 3]
+    );
+    assert_eq!(
+        SamplerDesc::POINT_CLAMP.address_u,
+        TextureAddressMode::Clamp
+    );
+    assert_eq!(SamplerDesc::LINEAR_WRAP.address_v, TextureAddressMode::Wrap);
+    assert_eq!(SamplerDesc::ANISOTROPIC_WRAP.max_anisotropy, 16);
+    assert!(SamplerDesc::ANISOTROPIC_CLAMP.filter.is_anisotropic());
+
+    let shadow = SamplerDesc::SHADOW;
+    assert!(shadow.filter.is_comparison());
+    assert_eq!(shadow.comparison_func, ComparisonFunc::LessEqual);
+    assert_eq!(shadow.address_u, TextureAddressMode::Border);
+}
@@ -0,0 +1,82 @@
+#![cfg(windows)]
+
+extern crate direct3d11;
+
+use direct3d11::device::{Device, IDevice};
+use direct3d11::device_context::IDeviceContext;
+use direct3d11::shader::{PixelShader, VertexShader};
+use direct3d11::state::{BlendDesc, DepthStencilDesc, RasterizerDesc, SamplerDesc};
+
+#[test]
+fn create_and_bind_presets() {
+    let (_, device, ctx) = Device::create().build().unwrap();
+
+    for desc in &[
+        BlendDesc::OPAQUE,
+        BlendDesc::ALPHA_BLEND,
+        BlendDesc::PREMULTIPLIED,
+        BlendDesc::ADDITIVE,
+    ] {
+        let state = device.create_blend_state(desc).unwrap();
+        ctx.set_blend_state(Some(&state), [0.0; 4], 0xFFFF_FFFF);
+    }
+    ctx.set_blend_state(None, [0.0; 4], 0xFFFF_FFFF);
+
+    for desc in &[
+        DepthStencilDesc::DEPTH_NONE,
+        DepthStencilDesc::DEPTH_DEFAULT,
+        DepthStencilDesc::DEPTH_READ,
+        DepthStencilDesc::DEPTH_REVERSE_Z,
+    ] {
+        let state = device.create_depth_stencil_state(desc).unwrap();
+        ctx.set_depth_stencil_state(Some(&state), 0);
+    }
+
+    for desc in &[
+        RasterizerDesc::CULL_NONE,
+        RasterizerDesc::CULL_BACK,
+        RasterizerDesc::CULL_FRONT,
+        RasterizerDesc::WIREFRAME,
+    ] {
+        let state = device.create_rasterizer_state(desc).unwrap();
+        ctx.set_rasterizer_state(Some(&state));
+    }
+
+    let linear = device
+        .create_sampler_state(&SamplerDesc::LINEAR_CLAMP)
+        .unwrap();
+    let point = device
+        .create_sampler_state(&SamplerDesc::POINT_WRAP)
+        .unwrap();
+    let shadow = device.create_sampler_state(&SamplerDesc::SHADOW).unwrap();
+    ctx.set_samplers::<PixelShader>(0, &[Some(&linear), Some(&point), Some(&shadow)]);
+    ctx.set_samplers::<VertexShader>(15, &[None]);
+}
+
+#[test]
+fn identical_descriptions_share_objects() {
+    let (_, device, _) = Device::create().build().unwrap();
+    let a = device.create_blend_state(&BlendDesc::ALPHA_BLEND).unwrap();
+    let b = device.create_blend_state(&BlendDesc::ALPHA_BLEND).unwrap();
+    assert!(a == b);
+}
+
+#[test]
+fn reject_invalid_sampler() {
+    let (_, device, _) = Device::create().build().unwrap();
+    let desc = SamplerDesc {
+        max_anisotropy: 17,
+        ..SamplerDesc::ANISOTROPIC_WRAP
+    };
+    assert!(device.create_sampler_state(&desc).is_err());
+}
+
+#[test]
+#[should_panic]
+fn sampler_slots_out_of_range() {
+    let (_, device, ctx) = Device::create().build().unwrap();
+    let sampler = device
+        .create_sampler_state(&SamplerDesc::default())
+        .unwrap();
+    ctx.set_samplers::<PixelShader>(15, &[Some(&sampler), Some(&sampler)]);
+}
@@ -0,0 +1,84 @@
+extern crate direct3d11;
+
+use direct3d11::error::DescError;
+use direct3d11::format_info::Format;
+use direct3d11::initial_data::{self, SubresourceData, TextureExtent};
+use direct3d11::subresource::{self, Subresource};
+
+#[test]
+fn calc_subresource() {
+    // D3D11CalcSubresource(MipSlice, ArraySlice, MipLevels)
+    assert_eq!(Subresource::new(0, 0).index(4), 0);
+    assert_eq!(Subresource::new(3, 0).index(4), 3);
+    assert_eq!(Subresource::new(1, 2).index(4), 9);
+    assert_eq!(Subresource::from_index(9, 4), Subresource::new(1, 2));
+}
+
+#[test]
+#[should_panic(expected = "at least one mip level")]
+fn from_index_without_mips() {
+    Subresource::from_index(0, 0);
+}
+
+#[test]
+fn mip_sizes() {
+    assert_eq!(subresource::mip_size(256, 0), 256);
+    assert_eq!(subresource::mip_size(256, 3), 32);
+    assert_eq!(subresource::mip_size(5, 2), 1);
+    assert_eq!(subresource::mip_size(5, 40), 1);
+
+    assert_eq!(subresource::full_mip_count(1, 1, 1), 1);
+    assert_eq!(subresource::full_mip_count(256, 128, 1), 9);
+    assert_eq!(subresource::full_mip_count(300, 1, 1), 9);
+}
+
+#[test]
+fn mip_chain() {
+    let extent = TextureExtent {
+        width: 4,
+        height: 2,
+        depth: 1,
+        mip_levels: 0,
+        array_size: 2,
+    };
+    assert_eq!(extent.mip_count(), 3);
+    assert_eq!(extent.subresource_count(), 6);
+
+    let mip0 = [0u8; 4 * 4 * 2];
+    let mip1 = [0u8; 2 * 4];
+    let mip2 = [0u8; 4];
+    let items = [
+        SubresourceData::new(&mip0, 16, 0),
+        SubresourceData::new(&mip1, 8, 0),
+        SubresourceData::new(&mip2, 4, 0),
+        SubresourceData::new(&mip0, 16, 0),
+        SubresourceData::new(&mip1, 8, 0),
+        SubresourceData::new(&mip2, 4, 0),
+    ];
+    assert_eq!(
+        initial_data::check_subresources(&items, Format::R8G8B8A8Unorm, &extent),
+        Ok(())
+    );
+
+    // The second slice's first mip is given the data of a smaller level.
+    let mut bad = items;
+    bad[3] = SubresourceData::new(&mip1, 8, 0);
+    assert_eq!(
+        initial_data::check_subresources(&bad, Format::R8G8B8A8Unorm, &extent),
+        Err(DescError::InvalidSubresourceData {
+            subresource: Subresource::new(0, 1),
+            error: Box::new(DescError::RowPitchTooSmall {
+                row_pitch: 8,
+                row_bytes: 16
+            }),
+        })
+    );
+
+    assert_eq!(
+        initial_data::check_subresources(&items[..2], Format::R8G8B8A8Unorm, &extent),
+        Err(DescError::InitialDataCount {
+            expected: 6,
+            actual: 2
+        })
+    );
+}
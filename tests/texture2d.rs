@@ -1,11 +1,24 @@
+#![cfg(windows)]
+
 extern crate direct3d11;
 extern crate dxgi;
+extern crate winapi;
+
+use std::convert::TryFrom;
 
 use dxgi::enums::Format;
+use winapi::um::d3d11::D3D11_TEXTURE2D_DESC;
 
 use direct3d11::device::Device;
-use direct3d11::enums::CreateDeviceFlags;
-use direct3d11::texture2d::Texture2D;
+use direct3d11::device_context::IDeviceContext;
+use direct3d11::enums::{
+    BindFlags, CpuAccessFlags, CreateDeviceFlags, Map, ResourceMiscFlags, Usage,
+};
+use direct3d11::error::{BuildError, CopyError, DescError, MapError};
+use direct3d11::image_view::ImageView;
+use direct3d11::subresource::{Box3D, Subresource};
+use direct3d11::texture2d::builder::Texture2DBuilder;
+use direct3d11::texture2d::{Texture2D, Texture2DDesc};
 
 static SIMPLE_2X2_IMAGE: &'static [u8] = &[
     0xFF, 0x00, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
@@ -25,3 +38,243 @@ fn create_basic_texture() {
         .build()
         .unwrap();
 }
+
+#[test]
+fn desc_round_trip() {
+    let desc = Texture2DDesc {
+        width: 256,
+        height: 128,
+        mip_levels: 9,
+        array_size: 6,
+        format: Format::B8G8R8A8UnormSrgb,
+        sample_count: 1,
+        sample_quality: 0,
+        usage: Usage::Default,
+        bind_flags: BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET,
+        cpu_access_flags: CpuAccessFlags::NONE,
+        misc_flags: ResourceMiscFlags::TEXTURECUBE | ResourceMiscFlags::GENERATE_MIPS,
+    };
+
+    let raw: D3D11_TEXTURE2D_DESC = desc.into();
+    assert_eq!(raw.Width, 256);
+    assert_eq!(raw.Height, 128);
+    assert_eq!(raw.MipLevels, 9);
+    assert_eq!(raw.ArraySize, 6);
+    assert_eq!(raw.Format, Format::B8G8R8A8UnormSrgb as u32);
+    assert_eq!(raw.SampleDesc.Count, 1);
+    assert_eq!(raw.Usage, Usage::Default as u32);
+    assert_eq!(raw.BindFlags, 0x28);
+    assert_eq!(raw.MiscFlags, 0x5);
+
+    assert_eq!(Texture2DDesc::try_from(raw), Ok(desc));
+}
+
+#[test]
+fn desc_unknown_usage() {
+    let mut raw: D3D11_TEXTURE2D_DESC = Texture2DDesc::default().into();
+    raw.Usage = 7;
+    assert_eq!(
+        Texture2DDesc::try_from(raw),
+        Err(DescError::UnknownEnumValue {
+            field: "Usage",
+            value: 7
+        })
+    );
+}
+
+#[test]
+fn staging_copy_from_desc() {
+    let (_, device, _) = Device::create().build().unwrap();
+
+    let texture = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::SHADER_RESOURCE)
+        .with_initial_data(SIMPLE_2X2_IMAGE, 8)
+        .build()
+        .unwrap();
+
+    let desc = texture.desc().unwrap();
+    assert_eq!((desc.width, desc.height), (2, 2));
+    assert_eq!(desc.format, Format::R8G8B8A8Unorm);
+
+    let staging = Texture2DBuilder::from_desc(&device, &desc)
+        .with_usage(Usage::Staging)
+        .with_bind_flags(BindFlags::NONE)
+        .with_cpu_access(CpuAccessFlags::READ)
+        .build()
+        .unwrap();
+    assert_eq!(staging.desc().unwrap().usage, Usage::Staging);
+}
+
+#[test]
+fn create_mipped_texture() {
+    let (_, device, _) = Device::create().build().unwrap();
+
+    let mip1 = &SIMPLE_2X2_IMAGE[..4];
+    let _texture = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_mip_levels(2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_subresource_data(Subresource::new(1, 0), mip1, 4)
+        .with_subresource_data(Subresource::new(0, 0), SIMPLE_2X2_IMAGE, 8)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn missing_mip_data() {
+    let (_, device, _) = Device::create().build().unwrap();
+
+    let result = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_mip_levels(2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_subresource_data(Subresource::new(0, 0), SIMPLE_2X2_IMAGE, 8)
+        .build();
+    match result {
+        Err(BuildError::Desc(DescError::MissingSubresourceData { subresource })) => {
+            assert_eq!(subresource, Subresource::new(1, 0))
+        }
+        _ => panic!("expected missing subresource data"),
+    }
+}
+
+#[test]
+fn read_back_staging_texture() {
+    let (_, device, context) = Device::create().build().unwrap();
+
+    let texture = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_usage(Usage::Staging)
+        .with_cpu_access(CpuAccessFlags::READ)
+        .with_initial_data(SIMPLE_2X2_IMAGE, 8)
+        .build()
+        .unwrap();
+
+    match context.map(&texture, 0, Map::Write) {
+        Err(MapError::CpuAccess { .. }) => (),
+        Err(err) => panic!("unexpected error {:?}", err),
+        Ok(_) => panic!("mapped a read-only texture for writing"),
+    }
+
+    let mut mapped = context.map(&texture, 0, Map::Read).unwrap();
+    match context.map(&texture, 0, Map::Read) {
+        Err(MapError::AlreadyMapped { subresource: 0 }) => (),
+        Err(err) => panic!("unexpected error {:?}", err),
+        Ok(_) => panic!("mapped a subresource twice"),
+    }
+    assert!(mapped.data_mut().is_none());
+    let pitch = mapped.row_pitch() as usize;
+    let data = mapped.data().unwrap();
+    assert_eq!(data.len(), pitch + 8);
+    assert_eq!(&data[..8], &SIMPLE_2X2_IMAGE[..8]);
+    assert_eq!(&data[pitch..pitch + 8], &SIMPLE_2X2_IMAGE[8..]);
+    context.unmap(mapped);
+}
+
+#[test]
+fn write_only_mapping() {
+    let (_, device, context) = Device::create().build().unwrap();
+
+    let texture = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_usage(Usage::Dynamic)
+        .with_bind_flags(BindFlags::SHADER_RESOURCE)
+        .with_cpu_access(CpuAccessFlags::WRITE)
+        .build()
+        .unwrap();
+
+    let mut mapped = context.map(&texture, 0, Map::Discard).unwrap();
+    assert!(mapped.data().is_none());
+    assert_eq!(
+        mapped.image_view().err(),
+        Some(MapError::Access { map: Map::Discard })
+    );
+    mapped
+        .image_view_mut()
+        .unwrap()
+        .copy_from_packed(SIMPLE_2X2_IMAGE);
+    context.unmap(mapped);
+}
+
+#[test]
+fn update_and_copy_region() {
+    let (_, device, context) = Device::create().build().unwrap();
+
+    let texture = Texture2D::create(&device)
+        .with_size(4, 4)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::SHADER_RESOURCE)
+        .build()
+        .unwrap();
+    let staging = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_usage(Usage::Staging)
+        .with_cpu_access(CpuAccessFlags::READ)
+        .build()
+        .unwrap();
+
+    let image = ImageView::packed(SIMPLE_2X2_IMAGE, Format::R8G8B8A8Unorm, 2, 2, 1).unwrap();
+    let region = Box3D::new(2, 2, 0, 4, 4, 1);
+    context
+        .update_subresource(&texture, 0, Some(region), &image)
+        .unwrap();
+
+    match context.update_subresource(&texture, 0, None, &image) {
+        Err(CopyError::ImageSize { .. }) => (),
+        result => panic!("unexpected result {:?}", result),
+    }
+
+    context
+        .copy_subresource_region(&staging, 0, (0, 0, 0), &texture, 0, Some(region))
+        .unwrap();
+    assert_eq!(
+        context.copy_subresource_region(&texture, 0, (0, 0, 0), &texture, 0, Some(region)),
+        Err(CopyError::SameSubresource { subresource: 0 })
+    );
+
+    let mapped = context.map(&staging, 0, Map::Read).unwrap();
+    let view = mapped.image_view().unwrap();
+    assert_eq!(view.to_packed_vec(), SIMPLE_2X2_IMAGE);
+    assert_eq!(
+        context.copy_subresource_region(&staging, 0, (0, 0, 0), &texture, 0, Some(region)),
+        Err(CopyError::Mapped)
+    );
+}
+
+#[test]
+fn copy_whole_texture() {
+    let (_, device, context) = Device::create().build().unwrap();
+
+    let texture = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::SHADER_RESOURCE)
+        .with_initial_data(SIMPLE_2X2_IMAGE, 8)
+        .build()
+        .unwrap();
+    let staging = Texture2D::create(&device)
+        .with_size(2, 2)
+        .with_format(Format::R8G8B8A8UnormSrgb)
+        .with_usage(Usage::Staging)
+        .with_cpu_access(CpuAccessFlags::READ)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        context.copy_resource(&texture, &texture),
+        Err(CopyError::SameResource)
+    );
+    context.copy_resource(&texture, &staging).unwrap();
+
+    let mapped = context.map(&staging, 0, Map::Read).unwrap();
+    assert_eq!(mapped.image_view().unwrap().to_packed_vec(), SIMPLE_2X2_IMAGE);
+    assert_eq!(
+        context.copy_resource(&texture, &staging),
+        Err(CopyError::Mapped)
+    );
+}
@@ -0,0 +1,39 @@
+#![cfg(windows)]
+
+extern crate direct3d11;
+extern crate dxgi;
+
+use dxgi::enums::Format;
+
+use direct3d11::device::Device;
+use direct3d11::texture1d::Texture1D;
+use direct3d11::texture3d::Texture3D;
+
+static LUT_4: &[u8] = &[
+    0x00, 0x00, 0x00, 0xFF, 0x55, 0x55, 0x55, 0xFF, 0xAA, 0xAA, 0xAA, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+];
+
+#[test]
+fn create_lut_texture() {
+    let (_, device, _) = Device::create().build().unwrap();
+
+    let _texture = Texture1D::create(&device)
+        .with_size(4)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_initial_data(LUT_4)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn create_volume_texture() {
+    let (_, device, _) = Device::create().build().unwrap();
+
+    // 2x1x2 volume of RGBA8 texels, so both pitches are 8 bytes.
+    let _texture = Texture3D::create(&device)
+        .with_size(2, 1, 2)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_initial_data(LUT_4, 8, 8)
+        .build()
+        .unwrap();
+}
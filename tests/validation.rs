@@ -0,0 +1,526 @@
+extern crate direct3d11;
+
+use direct3d11::enums::{BindFlags, CpuAccessFlags, Map, ResourceMiscFlags, Usage};
+use direct3d11::error::{CopyError, DescError, MapError};
+use direct3d11::format_info::Format;
+use direct3d11::initial_data::TextureExtent;
+use direct3d11::subresource::Box3D;
+use direct3d11::texture2d::Texture2DDesc;
+use direct3d11::texture3d::Texture3DDesc;
+use direct3d11::validation::{self, ResourceDimension, ResourceInfo};
+
+fn texture(width: u32, height: u32) -> Texture2DDesc {
+    Texture2DDesc {
+        width,
+        height,
+        format: Format::R8G8B8A8Unorm,
+        bind_flags: BindFlags::SHADER_RESOURCE,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn immutable_requires_initial_data() {
+    let desc = Texture2DDesc {
+        usage: Usage::Immutable,
+        ..texture(4, 4)
+    };
+    assert_eq!(desc.validate(false), Err(DescError::ImmutableWithoutInitialData));
+    assert_eq!(desc.validate(true), Ok(()));
+}
+
+#[test]
+fn cpu_access() {
+    let none = BindFlags::NONE;
+    assert_eq!(
+        validation::validate_usage(Usage::Dynamic, none, CpuAccessFlags::NONE, false),
+        Err(DescError::UsageCpuAccess {
+            usage: Usage::Dynamic,
+            cpu_access_flags: CpuAccessFlags::NONE
+        })
+    );
+    assert!(validation::validate_usage(Usage::Dynamic, none, CpuAccessFlags::READ, false).is_err());
+    assert!(validation::validate_usage(Usage::Default, none, CpuAccessFlags::WRITE, false).is_err());
+    assert_eq!(
+        validation::validate_usage(Usage::Dynamic, none, CpuAccessFlags::WRITE, false),
+        Ok(())
+    );
+    assert_eq!(
+        validation::validate_usage(Usage::Staging, none, CpuAccessFlags::READWRITE, false),
+        Ok(())
+    );
+    assert_eq!(
+        validation::validate_usage(Usage::Staging, none, CpuAccessFlags::NONE, false),
+        Err(DescError::UsageCpuAccess {
+            usage: Usage::Staging,
+            cpu_access_flags: CpuAccessFlags::NONE
+        })
+    );
+}
+
+#[test]
+fn staging_bind_flags() {
+    let desc = Texture2DDesc {
+        usage: Usage::Staging,
+        cpu_access_flags: CpuAccessFlags::READ,
+        ..texture(4, 4)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::StagingBindFlags {
+            bind_flags: BindFlags::SHADER_RESOURCE
+        })
+    );
+}
+
+#[test]
+fn dynamic_outputs() {
+    let bind_flags = BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET;
+    let desc = Texture2DDesc {
+        usage: Usage::Dynamic,
+        cpu_access_flags: CpuAccessFlags::WRITE,
+        bind_flags,
+        ..texture(4, 4)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::UsageBindFlags {
+            usage: Usage::Dynamic,
+            bind_flags
+        })
+    );
+}
+
+#[test]
+fn dynamic_subresources() {
+    let dynamic = Texture2DDesc {
+        usage: Usage::Dynamic,
+        cpu_access_flags: CpuAccessFlags::WRITE,
+        ..texture(4, 4)
+    };
+    assert_eq!(dynamic.validate(false), Ok(()));
+
+    let desc = Texture2DDesc {
+        mip_levels: 0,
+        ..dynamic
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::DynamicSubresources {
+            mip_levels: 0,
+            array_size: 1
+        })
+    );
+
+    let desc = Texture2DDesc {
+        array_size: 2,
+        ..dynamic
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::DynamicSubresources {
+            mip_levels: 1,
+            array_size: 2
+        })
+    );
+}
+
+#[test]
+fn generate_mips() {
+    let desc = Texture2DDesc {
+        mip_levels: 0,
+        misc_flags: ResourceMiscFlags::GENERATE_MIPS,
+        ..texture(256, 256)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::GenerateMipsBindFlags {
+            bind_flags: BindFlags::SHADER_RESOURCE
+        })
+    );
+
+    let desc = Texture2DDesc {
+        bind_flags: BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET,
+        ..desc
+    };
+    assert_eq!(desc.validate(false), Ok(()));
+}
+
+#[test]
+fn cube_array_size() {
+    let desc = Texture2DDesc {
+        array_size: 4,
+        misc_flags: ResourceMiscFlags::TEXTURECUBE,
+        ..texture(64, 64)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::CubeArraySize { array_size: 4 })
+    );
+
+    let desc = Texture2DDesc {
+        array_size: 12,
+        ..desc
+    };
+    assert_eq!(desc.validate(false), Ok(()));
+}
+
+#[test]
+fn mip_levels() {
+    let desc = Texture2DDesc {
+        mip_levels: 4,
+        ..texture(4, 4)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::TooManyMipLevels {
+            mip_levels: 4,
+            max: 3
+        })
+    );
+
+    let desc = Texture2DDesc {
+        mip_levels: 2,
+        sample_count: 4,
+        ..texture(4, 4)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::MultisampledMipLevels { mip_levels: 2 })
+    );
+}
+
+#[test]
+fn depth_stencil() {
+    let desc = Texture2DDesc {
+        bind_flags: BindFlags::DEPTH_STENCIL,
+        ..texture(4, 4)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::DepthStencilFormat {
+            format: Format::R8G8B8A8Unorm
+        })
+    );
+
+    let desc = Texture2DDesc {
+        format: Format::D24UnormS8Uint,
+        ..desc
+    };
+    assert_eq!(desc.validate(false), Ok(()));
+
+    let desc = Texture3DDesc {
+        width: 4,
+        height: 4,
+        depth: 4,
+        format: Format::R32Typeless,
+        bind_flags: BindFlags::DEPTH_STENCIL,
+        ..Default::default()
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::InvalidBindFlags {
+            bind_flags: BindFlags::DEPTH_STENCIL
+        })
+    );
+}
+
+#[test]
+fn block_compressed_size() {
+    let desc = Texture2DDesc {
+        format: Format::Bc1Unorm,
+        ..texture(6, 8)
+    };
+    assert_eq!(
+        desc.validate(false),
+        Err(DescError::BlockCompressedSize {
+            width: 6,
+            height: 8
+        })
+    );
+}
+
+#[test]
+fn missing_format() {
+    let desc = Texture2DDesc {
+        format: Format::Unknown,
+        ..texture(4, 4)
+    };
+    assert_eq!(desc.validate(false), Err(DescError::MissingFormat));
+}
+
+#[test]
+fn map_modes_follow_usage() {
+    let read_write = CpuAccessFlags::READ | CpuAccessFlags::WRITE;
+    for &map in &[Map::Read, Map::Write, Map::ReadWrite] {
+        assert_eq!(
+            validation::validate_map(map, Usage::Staging, BindFlags::NONE, read_write),
+            Ok(())
+        );
+        assert_eq!(
+            validation::validate_map(
+                map,
+                Usage::Dynamic,
+                BindFlags::VERTEX_BUFFER,
+                CpuAccessFlags::WRITE
+            ),
+            Err(MapError::UsageMode {
+                map,
+                usage: Usage::Dynamic
+            })
+        );
+    }
+
+    for &map in &[Map::Discard, Map::WriteNoOverwrite] {
+        assert_eq!(
+            validation::validate_map(
+                map,
+                Usage::Dynamic,
+                BindFlags::VERTEX_BUFFER,
+                CpuAccessFlags::WRITE
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            validation::validate_map(map, Usage::Staging, BindFlags::NONE, read_write),
+            Err(MapError::UsageMode {
+                map,
+                usage: Usage::Staging
+            })
+        );
+    }
+
+    for &usage in &[Usage::Default, Usage::Immutable] {
+        assert_eq!(
+            validation::validate_map(Map::Read, usage, BindFlags::NONE, CpuAccessFlags::NONE),
+            Err(MapError::UsageMode {
+                map: Map::Read,
+                usage
+            })
+        );
+    }
+}
+
+#[test]
+fn map_modes_follow_cpu_access() {
+    let result = validation::validate_map(
+        Map::Read,
+        Usage::Staging,
+        BindFlags::NONE,
+        CpuAccessFlags::WRITE,
+    );
+    assert_eq!(
+        result,
+        Err(MapError::CpuAccess {
+            map: Map::Read,
+            cpu_access_flags: CpuAccessFlags::WRITE
+        })
+    );
+
+    let result = validation::validate_map(
+        Map::ReadWrite,
+        Usage::Staging,
+        BindFlags::NONE,
+        CpuAccessFlags::READ,
+    );
+    assert!(result.is_err());
+
+    let result = validation::validate_map(
+        Map::WriteNoOverwrite,
+        Usage::Dynamic,
+        BindFlags::CONSTANT_BUFFER,
+        CpuAccessFlags::WRITE,
+    );
+    assert_eq!(result, Err(MapError::NoOverwriteConstantBuffer));
+}
+
+#[test]
+fn box_bounds() {
+    let size = (8, 8, 1);
+    let region = Box3D::new(2, 2, 0, 6, 6, 1);
+    assert_eq!(
+        validation::validate_box(&region, Format::R8G8B8A8Unorm, size),
+        Ok(())
+    );
+
+    let region = Box3D::new(4, 0, 0, 9, 8, 1);
+    assert_eq!(
+        validation::validate_box(&region, Format::R8G8B8A8Unorm, size),
+        Err(CopyError::BoxOutOfBounds { region, size })
+    );
+
+    let region = Box3D::new(4, 0, 0, 4, 8, 1);
+    assert_eq!(
+        validation::validate_box(&region, Format::R8G8B8A8Unorm, size),
+        Err(CopyError::EmptyBox { region })
+    );
+}
+
+#[test]
+fn box_block_alignment() {
+    // A 4x4 block in the middle of a 12x10 BC1 texture is fine, and so is a
+    // region ending on the unaligned bottom edge.
+    let size = (12, 10, 1);
+    for region in &[Box3D::new(4, 4, 0, 8, 8, 1), Box3D::new(0, 8, 0, 12, 10, 1)] {
+        assert_eq!(
+            validation::validate_box(region, Format::Bc1Unorm, size),
+            Ok(())
+        );
+    }
+
+    let region = Box3D::new(2, 0, 0, 6, 4, 1);
+    assert_eq!(
+        validation::validate_box(&region, Format::Bc1Unorm, size),
+        Err(CopyError::BoxAlignment {
+            region,
+            format: Format::Bc1Unorm
+        })
+    );
+}
+
+#[test]
+fn update_region_size() {
+    let result = validation::validate_update(
+        Format::R8G8B8A8Unorm,
+        (8, 8, 1),
+        Some(Box3D::new(0, 0, 0, 4, 4, 1)),
+        Format::R8G8B8A8UnormSrgb,
+        (4, 4, 1),
+    );
+    assert_eq!(result, Ok(()));
+
+    let result = validation::validate_update(
+        Format::R8G8B8A8Unorm,
+        (8, 8, 1),
+        None,
+        Format::R8G8B8A8Unorm,
+        (4, 4, 1),
+    );
+    assert_eq!(
+        result,
+        Err(CopyError::ImageSize {
+            expected: (8, 8, 1),
+            actual: (4, 4, 1)
+        })
+    );
+}
+
+#[test]
+fn copy_region_checks() {
+    let result = validation::validate_copy_region(
+        Format::R8G8B8A8Unorm,
+        (4, 4, 1),
+        None,
+        Format::R8G8B8A8Unorm,
+        (8, 8, 1),
+        (4, 4, 0),
+    );
+    assert_eq!(result, Ok(()));
+
+    let result = validation::validate_copy_region(
+        Format::R8G8B8A8Unorm,
+        (4, 4, 1),
+        None,
+        Format::R8G8B8A8Unorm,
+        (8, 8, 1),
+        (6, 0, 0),
+    );
+    assert_eq!(
+        result,
+        Err(CopyError::BoxOutOfBounds {
+            region: Box3D::new(6, 0, 0, 10, 4, 1),
+            size: (8, 8, 1)
+        })
+    );
+
+    let result = validation::validate_copy_region(
+        Format::R8G8B8A8Unorm,
+        (4, 4, 1),
+        None,
+        Format::R8G8B8A8Unorm,
+        (8, 8, 1),
+        (u32::MAX - 1, 0, 0),
+    );
+    assert_eq!(
+        result,
+        Err(CopyError::BoxOutOfBounds {
+            region: Box3D::new(u32::MAX - 1, 0, 0, u32::MAX, 4, 1),
+            size: (8, 8, 1)
+        })
+    );
+
+    let result = validation::validate_copy_region(
+        Format::R8G8B8A8Unorm,
+        (4, 4, 1),
+        None,
+        Format::R32Float,
+        (4, 4, 1),
+        (0, 0, 0),
+    );
+    assert_eq!(
+        result,
+        Err(CopyError::IncompatibleFormats {
+            src: Format::R8G8B8A8Unorm,
+            dst: Format::R32Float
+        })
+    );
+}
+
+fn texture_info(width: u32, format: Format) -> ResourceInfo {
+    ResourceInfo {
+        dimension: ResourceDimension::Texture2D,
+        usage: Usage::Default,
+        bind_flags: BindFlags::SHADER_RESOURCE,
+        cpu_access_flags: CpuAccessFlags::NONE,
+        misc_flags: ResourceMiscFlags::NONE,
+        format,
+        extent: TextureExtent {
+            width,
+            height: 4,
+            depth: 1,
+            mip_levels: 1,
+            array_size: 1,
+        },
+        sample_count: 1,
+        structure_byte_stride: 0,
+    }
+}
+
+#[test]
+fn copy_resource_checks() {
+    let src = texture_info(4, Format::R8G8B8A8UnormSrgb);
+    let dst = texture_info(4, Format::R8G8B8A8Unorm);
+    assert_eq!(validation::validate_copy_resource(&src, &dst), Ok(()));
+
+    let dst = texture_info(8, Format::R8G8B8A8Unorm);
+    assert_eq!(
+        validation::validate_copy_resource(&src, &dst),
+        Err(CopyError::ExtentMismatch {
+            src: src.extent,
+            dst: dst.extent
+        })
+    );
+
+    let dst = texture_info(4, Format::R32Float);
+    assert!(validation::validate_copy_resource(&src, &dst).is_err());
+
+    let dst = ResourceInfo {
+        dimension: ResourceDimension::Texture1D,
+        ..texture_info(4, Format::R8G8B8A8Unorm)
+    };
+    assert_eq!(
+        validation::validate_copy_resource(&src, &dst),
+        Err(CopyError::DimensionMismatch)
+    );
+
+    let dst = ResourceInfo {
+        usage: Usage::Immutable,
+        ..texture_info(4, Format::R8G8B8A8Unorm)
+    };
+    assert_eq!(
+        validation::validate_copy_resource(&src, &dst),
+        Err(CopyError::DestinationUsage {
+            usage: Usage::Immutable
+        })
+    );
+}
@@ -0,0 +1,230 @@
+extern crate direct3d11;
+
+use direct3d11::dxbc::{ComponentType, Container};
+use direct3d11::vertex::{validate_input_layout, InputLayoutError, Semantic};
+use direct3d11::vertex::{InputClassification, InputElement, Vertex, VertexFormat};
+
+static VS: &[u8] = include_bytes!("data/textured.vs.dxbc");
+
+#[derive(Copy, Clone, Vertex)]
+#[repr(C)]
+struct TexturedVertex {
+    position: [f32; 3],
+    #[semantic("TEXCOORD")]
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+#[derive(Copy, Clone, Vertex)]
+#[repr(C)]
+#[instance(2)]
+pub struct Instance {
+    /// Per-instance rows of a transform.
+    #[semantic("TRANSFORM", 1)]
+    pub row1: [f32; 4],
+    pub(crate) tint: [u8; 4],
+    #[format(R8G8B8A8Uint)]
+    material: u32,
+    uv1: [i16; 2],
+    weight: f32,
+}
+
+#[derive(Copy, Clone, Vertex)]
+#[instance]
+struct StepOnce {
+    #[format(R16G16Float)]
+    half: [u16; 2],
+}
+
+fn element(name: &'static str, index: u32, format: VertexFormat, offset: u32) -> InputElement {
+    InputElement {
+        semantic_name: name,
+        semantic_index: index,
+        format,
+        input_slot: 0,
+        offset,
+        classification: InputClassification::PerVertex,
+        instance_step_rate: 0,
+    }
+}
+
+#[test]
+fn derive_elements() {
+    assert_eq!(
+        TexturedVertex::elements(),
+        vec![
+            element("POSITION", 0, VertexFormat::R32G32B32Float, 0),
+            element("TEXCOORD", 0, VertexFormat::R32G32Float, 12),
+            element("COLOR", 0, VertexFormat::R32G32B32A32Float, 20),
+        ]
+    );
+
+    let per_instance = |name, index, format, offset| InputElement {
+        classification: InputClassification::PerInstance,
+        instance_step_rate: 2,
+        ..element(name, index, format, offset)
+    };
+    assert_eq!(
+        Instance::elements(),
+        vec![
+            per_instance("TRANSFORM", 1, VertexFormat::R32G32B32A32Float, 0),
+            per_instance("TINT", 0, VertexFormat::R8G8B8A8Unorm, 16),
+            per_instance("MATERIAL", 0, VertexFormat::R8G8B8A8Uint, 20),
+            per_instance("UV", 1, VertexFormat::R16G16Snorm, 24),
+            per_instance("WEIGHT", 0, VertexFormat::R32Float, 28),
+        ]
+    );
+
+    let elements = StepOnce::elements();
+    assert_eq!(elements[0].classification, InputClassification::PerInstance);
+    assert_eq!(elements[0].instance_step_rate, 1);
+    assert_eq!(elements[0].format, VertexFormat::R16G16Float);
+}
+
+#[test]
+fn offsets_follow_layout() {
+    // Without repr(C) the fields may be reordered; the offsets follow.
+    #[derive(Vertex)]
+    struct Unordered {
+        a: [u8; 2],
+        b: [f32; 4],
+        c: [u8; 2],
+    }
+
+    let elements = Unordered::elements();
+    let offsets = [
+        std::mem::offset_of!(Unordered, a),
+        std::mem::offset_of!(Unordered, b),
+        std::mem::offset_of!(Unordered, c),
+    ];
+    for (element, &offset) in elements.iter().zip(&offsets) {
+        assert_eq!(element.offset as usize, offset);
+        assert!(element.offset + element.format.size() <= std::mem::size_of::<Unordered>() as u32);
+    }
+}
+
+#[test]
+fn vertex_formats() {
+    let format = VertexFormat::R8G8B8A8Snorm;
+    assert_eq!(format.to_raw(), 31);
+    assert_eq!(VertexFormat::from_raw(31), Some(format));
+    assert_eq!(format.size(), 4);
+    assert_eq!(format.component_count(), 4);
+    assert_eq!(format.component_type(), ComponentType::Float32);
+
+    assert_eq!(VertexFormat::R11G11B10Float.component_count(), 3);
+    assert_eq!(
+        VertexFormat::R16G16Sint.component_type(),
+        ComponentType::SInt32
+    );
+    assert_eq!(VertexFormat::B8G8R8A8Unorm.to_raw(), 87);
+    assert_eq!(VertexFormat::from_raw(0), None);
+    assert_eq!(VertexFormat::from_raw(29), None);
+
+    assert_eq!(VertexFormat::R32G32B32Float.alignment(), 4);
+    assert_eq!(VertexFormat::R16G16Unorm.alignment(), 2);
+    assert_eq!(VertexFormat::R8G8B8A8Uint.alignment(), 1);
+    assert_eq!(VertexFormat::R10G10B10A2Unorm.alignment(), 4);
+}
+
+fn validate(elements: &[InputElement]) -> Result<(), InputLayoutError> {
+    let container = Container::parse(VS).unwrap();
+    let signature = container.input_signature().unwrap().unwrap();
+    validate_input_layout(elements, &signature)
+}
+
+fn semantic(name: &str, index: u32) -> Semantic {
+    Semantic {
+        name: name.into(),
+        index,
+    }
+}
+
+#[test]
+fn validate_against_signature() {
+    let mut elements = TexturedVertex::elements();
+    validate(&elements).unwrap();
+
+    // Semantics are matched case-insensitively, in any order.
+    elements.swap(0, 2);
+    elements[0].semantic_name = "color";
+    validate(&elements).unwrap();
+
+    // A per-instance color in another slot.
+    elements[0].input_slot = 1;
+    elements[0].offset = 0;
+    elements[0].classification = InputClassification::PerInstance;
+    elements[0].instance_step_rate = 1;
+    validate(&elements).unwrap();
+}
+
+#[test]
+fn invalid_layouts() {
+    let elements = TexturedVertex::elements();
+    assert_eq!(
+        validate(&elements[..2]),
+        Err(InputLayoutError::MissingSemantic {
+            semantic: semantic("COLOR", 0)
+        })
+    );
+
+    // Elements the shader does not read are ignored.
+    let mut extra = elements.clone();
+    extra.push(element("NORMAL", 0, VertexFormat::R32G32B32Float, 36));
+    validate(&extra).unwrap();
+
+    let mut duplicate = elements.clone();
+    duplicate.push(element("TexCoord", 0, VertexFormat::R32G32Float, 36));
+    assert_eq!(
+        validate(&duplicate),
+        Err(InputLayoutError::DuplicateSemantic {
+            semantic: semantic("TexCoord", 0)
+        })
+    );
+
+    // Missing components are filled in by the input assembler.
+    let mut short = elements.clone();
+    short[0].format = VertexFormat::R32G32Float;
+    validate(&short).unwrap();
+
+    let mut integer = elements.clone();
+    integer[2].format = VertexFormat::R8G8B8A8Uint;
+    let err = validate(&integer).unwrap_err();
+    assert_eq!(
+        err,
+        InputLayoutError::ComponentType {
+            semantic: semantic("COLOR", 0),
+            format: VertexFormat::R8G8B8A8Uint,
+            expected: ComponentType::Float32,
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "COLOR0 is read as Float32, but its format R8G8B8A8Uint provides UInt32"
+    );
+
+    let mut misaligned = elements.clone();
+    misaligned[1].offset = 14;
+    assert_eq!(
+        validate(&misaligned),
+        Err(InputLayoutError::Misaligned {
+            semantic: semantic("TEXCOORD", 0),
+            offset: 14,
+            alignment: 4,
+        })
+    );
+
+    let mut overlapping = elements.clone();
+    overlapping[2].offset = 16;
+    assert_eq!(
+        validate(&overlapping),
+        Err(InputLayoutError::Overlap {
+            semantic: semantic("COLOR", 0),
+            other: semantic("TEXCOORD", 0),
+            input_slot: 0,
+        })
+    );
+    // The same offsets in different slots do not overlap.
+    overlapping[2].input_slot = 1;
+    validate(&overlapping).unwrap();
+}
@@ -0,0 +1,277 @@
+extern crate direct3d11;
+
+use direct3d11::enums::{BindFlags, CpuAccessFlags, ResourceMiscFlags, Usage};
+use direct3d11::error::ViewError;
+use direct3d11::format_info::Format;
+use direct3d11::initial_data::TextureExtent;
+use direct3d11::validation::{ResourceDimension, ResourceInfo};
+use direct3d11::view::{ArrayRange, MipRange, ViewDesc, ViewDimension, ViewKind};
+
+fn texture_info(format: Format, mip_levels: u32, array_size: u32) -> ResourceInfo {
+    ResourceInfo {
+        dimension: ResourceDimension::Texture2D,
+        usage: Usage::Default,
+        bind_flags: BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET,
+        cpu_access_flags: CpuAccessFlags::NONE,
+        misc_flags: ResourceMiscFlags::NONE,
+        format,
+        extent: TextureExtent {
+            width: 16,
+            height: 16,
+            depth: 1,
+            mip_levels,
+            array_size,
+        },
+        sample_count: 1,
+        structure_byte_stride: 0,
+    }
+}
+
+fn buffer_info(byte_width: u32, structure_byte_stride: u32) -> ResourceInfo {
+    ResourceInfo {
+        dimension: ResourceDimension::Buffer,
+        format: Format::Unknown,
+        extent: TextureExtent {
+            width: byte_width,
+            height: 1,
+            depth: 1,
+            mip_levels: 1,
+            array_size: 1,
+        },
+        structure_byte_stride,
+        ..texture_info(Format::Unknown, 1, 1)
+    }
+}
+
+#[test]
+fn default_dimensions() {
+    let info = texture_info(Format::R8G8B8A8Unorm, 0, 1);
+    let srv = ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &info).unwrap();
+    assert_eq!(
+        srv.dimension,
+        ViewDimension::Texture2D {
+            mips: MipRange::new(0, 5)
+        }
+    );
+    let rtv = ViewDesc::default_for(ViewKind::RenderTarget, Format::Unknown, &info).unwrap();
+    assert_eq!(
+        rtv.dimension,
+        ViewDimension::Texture2D {
+            mips: MipRange::single(0)
+        }
+    );
+
+    let array = texture_info(Format::R8G8B8A8Unorm, 1, 3);
+    let rtv = ViewDesc::default_for(ViewKind::RenderTarget, Format::Unknown, &array).unwrap();
+    assert_eq!(
+        rtv.dimension,
+        ViewDimension::Texture2DArray {
+            mips: MipRange::single(0),
+            array: ArrayRange::new(0, 3),
+        }
+    );
+
+    let multisampled = ResourceInfo {
+        sample_count: 4,
+        ..texture_info(Format::R8G8B8A8Unorm, 1, 1)
+    };
+    let srv =
+        ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &multisampled).unwrap();
+    assert_eq!(srv.dimension, ViewDimension::Texture2DMS);
+}
+
+#[test]
+fn cube_defaults() {
+    let cube = ResourceInfo {
+        misc_flags: ResourceMiscFlags::TEXTURECUBE,
+        ..texture_info(Format::R8G8B8A8Unorm, 1, 12)
+    };
+    let srv = ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &cube).unwrap();
+    assert_eq!(
+        srv.dimension,
+        ViewDimension::TextureCubeArray {
+            mips: MipRange::new(0, 1),
+            first_face: 0,
+            num_cubes: 2,
+        }
+    );
+
+    // Render targets view the faces as an array.
+    let rtv = ViewDesc::default_for(ViewKind::RenderTarget, Format::Unknown, &cube).unwrap();
+    assert_eq!(
+        rtv.dimension,
+        ViewDimension::Texture2DArray {
+            mips: MipRange::single(0),
+            array: ArrayRange::new(0, 12),
+        }
+    );
+
+    let not_cube = texture_info(Format::R8G8B8A8Unorm, 1, 6);
+    let desc = ViewDesc {
+        format: Format::Unknown,
+        dimension: ViewDimension::TextureCube {
+            mips: MipRange::new(0, 1),
+        },
+    };
+    assert_eq!(
+        desc.validate(ViewKind::ShaderResource, &not_cube),
+        Err(ViewError::NotCube)
+    );
+    assert!(desc.validate(ViewKind::RenderTarget, &cube).is_err());
+
+    let desc = ViewDesc {
+        format: Format::Unknown,
+        dimension: ViewDimension::TextureCubeArray {
+            mips: MipRange::new(0, 1),
+            first_face: 0,
+            num_cubes: u32::MAX / 4,
+        },
+    };
+    assert_eq!(
+        desc.validate(ViewKind::ShaderResource, &cube),
+        Err(ViewError::TooManyCubes {
+            num_cubes: u32::MAX / 4
+        })
+    );
+}
+
+#[test]
+fn view_formats() {
+    let typeless = ResourceInfo {
+        bind_flags: BindFlags::SHADER_RESOURCE | BindFlags::DEPTH_STENCIL,
+        ..texture_info(Format::R32Typeless, 1, 1)
+    };
+    assert_eq!(
+        ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &typeless),
+        Err(ViewError::TypelessFormat {
+            format: Format::R32Typeless
+        })
+    );
+    let srv = ViewDesc::default_for(ViewKind::ShaderResource, Format::R32Float, &typeless);
+    assert_eq!(srv.map(|desc| desc.format), Ok(Format::R32Float));
+    assert!(ViewDesc::default_for(ViewKind::DepthStencil, Format::D32Float, &typeless).is_ok());
+    assert_eq!(
+        ViewDesc::default_for(ViewKind::ShaderResource, Format::D32Float, &typeless),
+        Err(ViewError::FormatAspect {
+            kind: ViewKind::ShaderResource,
+            format: Format::D32Float
+        })
+    );
+
+    let info = texture_info(Format::R8G8B8A8Unorm, 1, 1);
+    assert!(ViewDesc::default_for(ViewKind::ShaderResource, Format::R8G8B8A8Uint, &info).is_ok());
+    assert_eq!(
+        ViewDesc::default_for(ViewKind::ShaderResource, Format::R32Float, &info),
+        Err(ViewError::IncompatibleFormat {
+            format: Format::R32Float,
+            resource_format: Format::R8G8B8A8Unorm
+        })
+    );
+    assert_eq!(
+        ViewDesc::default_for(ViewKind::DepthStencil, Format::Unknown, &info),
+        Err(ViewError::MissingBindFlag {
+            kind: ViewKind::DepthStencil,
+            bind_flags: info.bind_flags
+        })
+    );
+}
+
+#[test]
+fn mip_and_array_ranges() {
+    let info = texture_info(Format::R8G8B8A8Unorm, 4, 2);
+    let srv = |mips, array| ViewDesc {
+        format: Format::Unknown,
+        dimension: ViewDimension::Texture2DArray { mips, array },
+    };
+    let all = ArrayRange::new(0, 2);
+    assert_eq!(
+        srv(MipRange::new(1, 3), all).validate(ViewKind::ShaderResource, &info),
+        Ok(())
+    );
+    assert_eq!(
+        srv(MipRange::new(2, 3), all).validate(ViewKind::ShaderResource, &info),
+        Err(ViewError::MipRange {
+            mips: MipRange::new(2, 3),
+            mip_count: 4
+        })
+    );
+    assert!(srv(MipRange::new(0, 0), all)
+        .validate(ViewKind::ShaderResource, &info)
+        .is_err());
+    assert!(srv(MipRange::new(0, 2), all)
+        .validate(ViewKind::RenderTarget, &info)
+        .is_err());
+    assert_eq!(
+        srv(MipRange::single(0), ArrayRange::new(1, 2)).validate(ViewKind::RenderTarget, &info),
+        Err(ViewError::ArrayRange {
+            range: ArrayRange::new(1, 2),
+            size: 2
+        })
+    );
+
+    let wrong = ViewDesc {
+        format: Format::Unknown,
+        dimension: ViewDimension::Texture3D {
+            mips: MipRange::single(0),
+            w: ArrayRange::new(0, 1),
+        },
+    };
+    assert!(wrong.validate(ViewKind::ShaderResource, &info).is_err());
+}
+
+#[test]
+fn buffer_views() {
+    let structured = buffer_info(64, 16);
+    let srv = ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &structured);
+    assert_eq!(
+        srv.map(|desc| desc.dimension),
+        Ok(ViewDimension::Buffer {
+            first_element: 0,
+            num_elements: 4
+        })
+    );
+
+    let raw = buffer_info(64, 0);
+    assert_eq!(
+        ViewDesc::default_for(ViewKind::ShaderResource, Format::Unknown, &raw),
+        Err(ViewError::UnknownElementSize)
+    );
+    let desc = ViewDesc {
+        format: Format::R32Float,
+        dimension: ViewDimension::Buffer {
+            first_element: 8,
+            num_elements: 9,
+        },
+    };
+    assert_eq!(
+        desc.validate(ViewKind::ShaderResource, &raw),
+        Err(ViewError::BufferRange {
+            first_element: 8,
+            num_elements: 9,
+            element_count: 16
+        })
+    );
+    assert!(desc.validate(ViewKind::DepthStencil, &raw).is_err());
+}
+
+#[cfg(windows)]
+#[test]
+fn create_texture_views() {
+    use direct3d11::device::Device;
+    use direct3d11::texture2d::Texture2D;
+
+    let (_, device, _) = Device::create().build().unwrap();
+    let texture = Texture2D::create(&device)
+        .with_size(16, 16)
+        .with_format(Format::R8G8B8A8Unorm)
+        .with_bind_flags(BindFlags::SHADER_RESOURCE | BindFlags::RENDER_TARGET)
+        .build()
+        .unwrap();
+
+    texture.create_srv(&device).unwrap();
+    texture.create_rtv(&device).unwrap();
+    match texture.create_dsv(&device) {
+        Err(ViewError::MissingBindFlag { .. }) => (),
+        result => panic!("unexpected {:?}", result.map(|_| ())),
+    }
+}